@@ -0,0 +1,32 @@
+package com.example.zkpapp
+
+import android.util.Log
+
+/**
+ * Issuer-signed credentials (see `credential.rs`).
+ *
+ * An issuer (bank, government) hands the holder a credential JSON: `{"schema","attributes":[{"name",
+ * "value","salt"}],"issuer":"<pk hex>","signature":"<hex>"}`. Keep it in secure storage, the salts
+ * are what keep unrevealed attributes private.
+ *
+ * [prove] (off the main thread) proves the issuer signature inside the circuit and reveals only the
+ * attribute names listed in `revealJson` (`["age"]`). [verify] returns `false` for an untrusted
+ * issuer or an invalid proof, and throws [ZkpException] for malformed input.
+ */
+object Credential {
+    private const val TAG = "Credential"
+
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e(TAG, "❌ Failed to load Rust library", e)
+        }
+    }
+
+    @JvmStatic
+    external fun prove(credentialJson: String, revealJson: String): String
+
+    @JvmStatic
+    external fun verify(presentationJson: String, trustedIssuersJson: String): Boolean
+}
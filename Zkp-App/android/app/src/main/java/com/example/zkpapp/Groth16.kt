@@ -0,0 +1,33 @@
+package com.example.zkpapp
+
+import android.util.Log
+
+/**
+ * Circom / snarkjs Groth16 on-device (see `groth16.rs` and `circom/`).
+ *
+ * [verify] takes the three files exactly as snarkjs wrote them and returns report JSON:
+ * `{"verified":true,"public_signals":["..."]}` or `{"verified":false,"reason":"...",...}`.
+ *
+ * [fullProve] is snarkjs `groth16 fullprove` without Node: give it the circuit's
+ * `_final.zkey` and `.wasm` (copied from assets to `filesDir`) plus `input.json`; it returns
+ * `{"proof":{...},"public_signals":[...]}`. Run it off the main thread.
+ *
+ * Both throw [ZkpException] for unreadable files or inputs the circuit rejects.
+ */
+object Groth16 {
+    private const val TAG = "Groth16"
+
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e(TAG, "❌ Failed to load Rust library", e)
+        }
+    }
+
+    @JvmStatic
+    external fun verify(vkJson: String, proofJson: String, publicJson: String): String
+
+    @JvmStatic
+    external fun fullProve(zkeyPath: String, wasmPath: String, inputJson: String): String
+}
@@ -9,11 +9,14 @@ import android.util.Log
 import android.view.View
 import android.widget.TextView
 import android.widget.Toast
+import androidx.appcompat.app.AlertDialog
 import androidx.appcompat.app.AppCompatActivity
 import androidx.lifecycle.lifecycleScope
 import com.example.zkpapp.auth.ZkAuthManager
 import com.google.zxing.integration.android.IntentIntegrator
 import kotlinx.coroutines.launch
+import kotlinx.coroutines.suspendCancellableCoroutine
+import kotlin.coroutines.resume
 
 class LoginActivity : AppCompatActivity() {
 
@@ -101,7 +104,7 @@ class LoginActivity : AppCompatActivity() {
         }
     }
 
-    private fun performZkLogin(sessionId: String) {
+    private fun performZkLogin(scannedQr: String) {
         statusText.text = "🦁 Generating Proof..."
         statusText.setTextColor(Color.parseColor("#FF9800")) // Orange
         statusText.textSize = 20f
@@ -109,7 +112,8 @@ class LoginActivity : AppCompatActivity() {
         lifecycleScope.launch {
             ZkAuthManager.startUniversalLogin(
                 context = this@LoginActivity,
-                sessionId = sessionId,
+                scannedQr = scannedQr,
+                confirmDomain = { domain -> confirmLoginDomain(domain) },
                 onStatus = { msg -> statusText.text = msg },
                 onSuccess = {
                     statusText.text = "✅ Login Approved!"
@@ -134,4 +138,16 @@ class LoginActivity : AppCompatActivity() {
             )
         }
     }
-}
\ No newline at end of file
+
+    /** Proof sirf is domain ke liye hoga; user ko pehle dikhao. */
+    private suspend fun confirmLoginDomain(domain: String): Boolean = suspendCancellableCoroutine { cont ->
+        val dialog = AlertDialog.Builder(this)
+            .setTitle("🔐 Log in to $domain?")
+            .setMessage("Only continue if you opened $domain yourself. The proof will be valid for this site only.")
+            .setPositiveButton("Log in") { _, _ -> if (cont.isActive) cont.resume(true) }
+            .setNegativeButton("Cancel") { _, _ -> if (cont.isActive) cont.resume(false) }
+            .setOnCancelListener { if (cont.isActive) cont.resume(false) }
+            .show()
+        cont.invokeOnCancellation { dialog.dismiss() }
+    }
+}
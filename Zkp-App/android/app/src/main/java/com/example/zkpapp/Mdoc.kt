@@ -0,0 +1,34 @@
+package com.example.zkpapp
+
+import android.util.Log
+
+/**
+ * ISO 18013-5 mobile driving licences (see `mdoc.rs`).
+ *
+ * [verify] checks an `IssuerSigned` structure or a `Document` wrapping one (base64 of the CBOR):
+ * COSE_Sign1 issuer signature, DS certificate (mdoc DS key usage) against the local IACA trust
+ * list (`{"version","iacas":[{"name","country","certificate"}]}`), docType, MSO validity and every
+ * disclosed element's digest. Returns the verified elements as JSON; throws [ZkpException] when
+ * any check fails. Verifiers holding a trust bundle use [ZkpEngine.verifyMdoc] instead, which takes
+ * the IACA roots from the bundle's CSCA list.
+ *
+ * To use the elements in proofs, the bank backend re-issues them as a signed [Credential]
+ * (`zkp-cli issue mdoc`), which then goes through the usual credential / policy proofs.
+ */
+object Mdoc {
+    private const val TAG = "Mdoc"
+
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e(TAG, "❌ Failed to load Rust library", e)
+        }
+    }
+
+    /** docType of a mobile driving licence. */
+    const val MDL_DOC_TYPE = "org.iso.18013.5.1.mDL"
+
+    @JvmStatic
+    external fun verify(issuerSignedB64: String, docType: String, iacaTrustListJson: String): String
+}
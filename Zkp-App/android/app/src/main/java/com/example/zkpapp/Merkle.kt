@@ -0,0 +1,33 @@
+package com.example.zkpapp
+
+import android.util.Log
+
+/**
+ * Anonymous registry membership (see `merkle.rs`): "my secret is one of the leaves under
+ * this root" without revealing which one.
+ *
+ * The registry stores `leaf_commitment(secret)` in a Poseidon Merkle tree (depth 1..32) and
+ * hands the member a path JSON: `{"index":5,"root":"<hex>","siblings":["<hex>",...]}`.
+ *
+ * [proveMembership] returns `{"depth","root","circuit_digest","proof"}`; run it off the main
+ * thread (first call per depth builds the circuit). [verifyMembership] returns `false` for a
+ * proof that is invalid or made against another root, and throws [ZkpException] for
+ * malformed input.
+ */
+object Merkle {
+    private const val TAG = "Merkle"
+
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e(TAG, "❌ Failed to load Rust library", e)
+        }
+    }
+
+    @JvmStatic
+    external fun proveMembership(secret: String, pathJson: String): String
+
+    @JvmStatic
+    external fun verifyMembership(proofJson: String, rootHex: String): Boolean
+}
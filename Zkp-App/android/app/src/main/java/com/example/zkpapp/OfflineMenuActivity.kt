@@ -89,9 +89,6 @@ class OfflineMenuActivity : AppCompatActivity() {
         private const val MODE_RANDOM = "RND"
     }
 
-    // Rust JNI Function
-    private external fun stringFromRust(): String
-
     // ═══════════════════════════════════════════════════════════
     // 📱 UI COMPONENTS
     // ═══════════════════════════════════════════════════════════
@@ -107,6 +104,7 @@ class OfflineMenuActivity : AppCompatActivity() {
     // ═══════════════════════════════════════════════════════════
     private var animationJob: Job? = null
     private var proofGenerationJob: Job? = null
+    @Volatile private var activeProofHandle = 0L
     
     private val isTransmitting = AtomicBoolean(false)
     private val isGeneratingProof = AtomicBoolean(false)
@@ -280,13 +278,25 @@ class OfflineMenuActivity : AppCompatActivity() {
             val startTime = System.currentTimeMillis()
             
             // Timeout protection
+            val handle = ProvingJobs.startBalanceProof { phase, percent ->
+                runOnUiThread {
+                    tvStatus.text = "🦁 ${ProvingJobs.phaseLabel(phase)}... $percent%"
+                }
+            }
+            activeProofHandle = handle
+
             val proofJob = async(Dispatchers.IO) {
-                stringFromRust()
+                try {
+                    ProvingJobs.await(handle)
+                } finally {
+                    activeProofHandle = 0L
+                }
             }
             
             val jsonResponse = withTimeoutOrNull(PROOF_GENERATION_TIMEOUT_MS) {
                 proofJob.await()
             } ?: run {
+                ProvingJobs.cancel(handle)
                 proofJob.cancel()
                 Log.e(TAG, "Proof generation timeout")
                 return@coroutineScope ProofGenerationResult.Failure(
@@ -700,6 +710,11 @@ class OfflineMenuActivity : AppCompatActivity() {
         
         proofGenerationJob?.cancel()
         proofGenerationJob = null
+
+        // User screen chhod gaya: Rust job ko bhi rok do
+        if (activeProofHandle != 0L) {
+            ProvingJobs.cancel(activeProofHandle)
+        }
         
         clearBitmapCache()
         
@@ -0,0 +1,60 @@
+package com.example.zkpapp
+
+import android.util.Log
+
+/**
+ * Receives phase updates from a Rust proving job (called on a Rust worker thread).
+ */
+fun interface ProofProgressListener {
+    fun onProgress(phase: Int, percent: Int)
+}
+
+/**
+ * Async proving over JNI (see `proving_jobs.rs`).
+ * start -> handle, then `await(handle)` on a background dispatcher; `cancel(handle)` from anywhere.
+ */
+object ProvingJobs {
+    private const val TAG = "ProvingJobs"
+
+    // Phase codes (must match `Phase` in proving_jobs.rs)
+    const val PHASE_CIRCUIT_LOAD = 0
+    const val PHASE_WITNESS = 1
+    /** Commitment + FRI: one plonky2 call, no progress or cancellation inside it. */
+    const val PHASE_PROVE = 2
+    const val PHASE_SERIALIZE = 3
+
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e(TAG, "❌ Failed to load Rust library", e)
+        }
+    }
+
+    /** Starts the offline balance proof; result of `await` is the QR chunk JSON array. */
+    @JvmStatic
+    external fun startBalanceProof(listener: ProofProgressListener): Long
+
+    /** Proves any identity request on a [ZkpEngine] handle; `await` returns the envelope JSON. */
+    @JvmStatic
+    external fun startProof(engineHandle: Long, requestJson: String, listener: ProofProgressListener): Long
+
+    /**
+     * Cancellation is only checked between phases: a job inside [PHASE_PROVE] runs to
+     * the end of that phase first, then `await` throws CANCELLED.
+     */
+    @JvmStatic
+    external fun cancel(handle: Long)
+
+    /** Blocks until the job ends. Throws [ZkpException] on failure. Frees the handle. */
+    @JvmStatic
+    external fun await(handle: Long): String
+
+    fun phaseLabel(phase: Int): String = when (phase) {
+        PHASE_CIRCUIT_LOAD -> "Loading Circuit"
+        PHASE_WITNESS -> "Generating Witness"
+        PHASE_PROVE -> "Proving"
+        PHASE_SERIALIZE -> "Serializing"
+        else -> "Working"
+    }
+}
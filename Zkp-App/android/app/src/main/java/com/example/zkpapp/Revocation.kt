@@ -0,0 +1,33 @@
+package com.example.zkpapp
+
+import android.util.Log
+
+/**
+ * "My credential is not revoked" (see `revocation.rs`).
+ *
+ * The issuer keeps a Poseidon sparse Merkle tree of revoked credential IDs and publishes a small
+ * root JSON, `{"version":7,"root":"<hex>","revoked":42}`, that verifiers can take offline (QR).
+ * Holders fetch an exclusion proof for their credential from the issuer (`{"key","root","value":null,
+ * "siblings":[...]}`) and turn it into a zero-knowledge proof with [proveNotRevoked] (off the main
+ * thread).
+ *
+ * [verifyNotRevoked] returns `false` for an invalid proof or one made against another (older) root,
+ * and throws [ZkpException] for malformed input or a credential that is already revoked.
+ */
+object Revocation {
+    private const val TAG = "Revocation"
+
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e(TAG, "❌ Failed to load Rust library", e)
+        }
+    }
+
+    @JvmStatic
+    external fun proveNotRevoked(credentialId: String, smtProofJson: String): String
+
+    @JvmStatic
+    external fun verifyNotRevoked(proofB64: String, rootJson: String): Boolean
+}
@@ -0,0 +1,35 @@
+package com.example.zkpapp
+
+import android.util.Log
+
+/**
+ * SD-JWT VCs (`<jwt>~<disclosure>~...~`, see `sdjwt.rs`).
+ *
+ * [verify] checks the issuer signature (ES256 / EdDSA) against a local issuer list
+ * (`{"version","issuers":[{"iss","jwk":{...}}]}`), `exp` / `nbf` and every disclosure digest, and
+ * returns the rebuilt claims as JSON. Throws [ZkpException] when any check fails.
+ *
+ * [provePredicate] keeps one integer claim hidden and proves a clause over it (`age >= 18`,
+ * `score in [1, 2, 3]`); the proof recomputes the disclosure's SHA-256 in-circuit. The verifier
+ * passes its own clause to [verifyPredicate], which returns the same JSON plus `proven`.
+ */
+object SdJwt {
+    private const val TAG = "SdJwt"
+
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e(TAG, "❌ Failed to load Rust library", e)
+        }
+    }
+
+    @JvmStatic
+    external fun verify(sdJwt: String, issuersJson: String): String
+
+    @JvmStatic
+    external fun provePredicate(sdJwt: String, clause: String): String
+
+    @JvmStatic
+    external fun verifyPredicate(presentationJson: String, clause: String, issuersJson: String): String
+}
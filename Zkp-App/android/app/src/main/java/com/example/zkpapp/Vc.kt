@@ -0,0 +1,36 @@
+package com.example.zkpapp
+
+import android.util.Log
+
+/**
+ * W3C Verifiable Presentations for partners (see `vc.rs`).
+ *
+ * [wrap] turns any presentation this app produces (proof envelope, credential presentation, policy
+ * presentation) into a VP whose `proof` is a `Plonky2Proof2026` carrying the circuit digest and the
+ * original proof. `policyJson` is only needed for policy presentations, pass `""` otherwise.
+ *
+ * [verifiedPresentation] verifies like `VerifierActivity.verifyProofFromRust` (which also accepts
+ * VPs) and returns the per-clause result as a VP that embeds the holder's proof, so a partner can
+ * re-check it. [canonical] gives the RDFC-1.0 (URDNA2015) canonical N-Quads for hashing or signing.
+ * Call off the main thread; malformed input throws [ZkpException].
+ */
+object Vc {
+    private const val TAG = "Vc"
+
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e(TAG, "❌ Failed to load Rust library", e)
+        }
+    }
+
+    @JvmStatic
+    external fun wrap(presentationJson: String, policyJson: String): String
+
+    @JvmStatic
+    external fun verifiedPresentation(payload: String, policyJson: String): String
+
+    @JvmStatic
+    external fun canonical(vpJson: String): String
+}
@@ -19,6 +19,8 @@ import com.journeyapps.barcodescanner.BarcodeCallback
 import com.journeyapps.barcodescanner.BarcodeResult
 import com.journeyapps.barcodescanner.DecoratedBarcodeView
 import kotlinx.coroutines.*
+import org.json.JSONArray
+import org.json.JSONObject
 import java.security.MessageDigest
 import java.util.concurrent.ConcurrentHashMap
 import java.util.concurrent.atomic.AtomicBoolean
@@ -73,13 +75,36 @@ class VerifierActivity : AppCompatActivity() {
         // UI Update throttling
         private const val MIN_UI_UPDATE_INTERVAL_MS = 100L
         
+        // Verifier rules (see rust/src/policy.rs); JSON or TOML
+        private const val POLICY_ASSET = "verifier_policy.json"
+        private const val DEFAULT_POLICY = """{"name":"offline-balance","clauses":["balance >= 10000"]}"""
+
+        // Trust bundle (see rust/src/trust_bundle.rs): pinned publisher keys ship with
+        // the app; the last installed bundle and its version survive restarts.
+        private const val PUBLISHERS_ASSET = "trust_bundle_publishers.json"
+        private const val BUNDLE_ASSET = "trust_bundle.b64"
+        private const val TRUST_PREFS = "verifier_trust"
+        private const val PREF_BUNDLE = "trust_bundle"
+        private const val PREF_MIN_BUNDLE_VERSION = "min_trust_bundle_version"
+        /** Base64 bundle or its QR frames (JSON array) handed over by another app/screen. */
+        const val EXTRA_TRUST_BUNDLE = "TRUST_BUNDLE"
+
         // Session Configuration
         private const val AUTO_RESET_DELAY_SUCCESS_MS = 5000L
         private const val AUTO_RESET_DELAY_FAILURE_MS = 3000L
     }
 
-    // Rust JNI Functions
-    private external fun verifyProofFromRust(proof: String): String
+    // Engine handle (ZkpEngine.kt): holds the installed trust bundle, returns PolicyReport JSON
+    @Volatile private var engine = 0L
+
+    private val policy: String by lazy {
+        try {
+            assets.open(POLICY_ASSET).bufferedReader().use { it.readText() }
+        } catch (e: Exception) {
+            Log.w(TAG, "No $POLICY_ASSET, using the default balance policy", e)
+            DEFAULT_POLICY
+        }
+    }
 
     // ═══════════════════════════════════════════════════════════
     // 📱 UI COMPONENTS
@@ -188,6 +213,7 @@ class VerifierActivity : AppCompatActivity() {
             barcodeView = findViewById(R.id.scannerView)
             statusText = findViewById(R.id.tvStatus)
             progressBar = findViewById(R.id.progressBar)
+            initializeEngine()
             
             updateStatus("🔍 Ready to Scan", Color.TRANSPARENT)
         } catch (e: Exception) {
@@ -197,6 +223,48 @@ class VerifierActivity : AppCompatActivity() {
         }
     }
 
+    /**
+     * Creates the verifier engine and installs the persisted bundle, then any newer
+     * one from assets or the launching intent. Rollbacks are refused by Rust.
+     */
+    private fun initializeEngine() {
+        val prefs = getSharedPreferences(TRUST_PREFS, Context.MODE_PRIVATE)
+        val publishers = try {
+            assets.open(PUBLISHERS_ASSET).bufferedReader().use { JSONArray(it.readText()) }
+        } catch (e: Exception) {
+            Log.w(TAG, "No $PUBLISHERS_ASSET, trust bundles can't be installed", e)
+            JSONArray()
+        }
+        val config = JSONObject()
+            .put("trust_bundle_publishers", publishers)
+            .put("min_trust_bundle_version", prefs.getLong(PREF_MIN_BUNDLE_VERSION, 0))
+        engine = ZkpEngine.createEngine(config.toString())
+
+        val bundled = try {
+            assets.open(BUNDLE_ASSET).bufferedReader().use { it.readText() }
+        } catch (e: Exception) {
+            null
+        }
+        listOfNotNull(prefs.getString(PREF_BUNDLE, null), bundled, intent.getStringExtra(EXTRA_TRUST_BUNDLE))
+            .forEach { installTrustBundle(it) }
+    }
+
+    /** Installs `bundle` and persists it with its version as the new rollback floor. */
+    private fun installTrustBundle(bundle: String) {
+        try {
+            val installed = JSONObject(ZkpEngine.installTrustBundle(engine, bundle))
+            val version = installed.getLong("version")
+            val prefs = getSharedPreferences(TRUST_PREFS, Context.MODE_PRIVATE)
+            prefs.edit()
+                .putString(PREF_BUNDLE, bundle)
+                .putLong(PREF_MIN_BUNDLE_VERSION, maxOf(version, prefs.getLong(PREF_MIN_BUNDLE_VERSION, 0)))
+                .apply()
+            Log.i(TAG, "🧳 Trust bundle version $version installed")
+        } catch (e: ZkpException) {
+            Log.w(TAG, "Trust bundle not installed: ${e.message}")
+        }
+    }
+
     private fun requestCameraPermissionIfNeeded() {
         if (ContextCompat.checkSelfPermission(this, Manifest.permission.CAMERA)
             != PackageManager.PERMISSION_GRANTED
@@ -244,6 +312,12 @@ class VerifierActivity : AppCompatActivity() {
         
         // Cancel all coroutines
         verificationScope.cancel()
+
+        // Running verifications keep their own reference (engine.rs)
+        if (engine != 0L) {
+            ZkpEngine.destroyEngine(engine)
+            engine = 0L
+        }
         
         // Release audio resources
         try {
@@ -505,7 +579,7 @@ class VerifierActivity : AppCompatActivity() {
             // Verify with timeout
             val verificationJob = async(Dispatchers.IO) {
                 val startTime = System.currentTimeMillis()
-                val report = verifyProofFromRust(fullProof)
+                val report = ZkpEngine.verifyWithPolicy(engine, fullProof, policy)
                 val elapsedTime = System.currentTimeMillis() - startTime
                 
                 Log.i(TAG, "Rust verification completed in ${elapsedTime}ms")
@@ -600,16 +674,25 @@ class VerifierActivity : AppCompatActivity() {
             is VerificationResult.Success -> {
                 Log.i(TAG, "✅ Verification SUCCESS (${result.verificationTimeMs}ms)")
                 
-                if (result.report.contains("Verified", ignoreCase = true)) {
+                val report = JSONObject(result.report)
+                val clauses = report.getJSONArray("clauses")
+                val lines = (0 until clauses.length()).joinToString("\n") { i ->
+                    val clause = clauses.getJSONObject(i)
+                    val mark = if (clause.getBoolean("passed")) "✅" else "❌"
+                    "$mark ${clause.getString("clause")}"
+                }
+
+                if (report.getBoolean("verified")) {
                     updateStatus(
-                        "✅ ${result.report}\n⏱️ Total: ${totalTime}ms",
+                        "✅ VERIFIED (${report.getString("policy")})\n$lines\n⏱️ Total: ${totalTime}ms",
                         Color.parseColor("#2E7D32")
                     )
                     triggerHapticFeedback(true)
                     delay(AUTO_RESET_DELAY_SUCCESS_MS)
                     resetSession("🔍 Ready to Scan")
                 } else {
-                    handleFailedVerification("Invalid proof format", ErrorCode.INVALID_PROOF)
+                    Log.w(TAG, "Policy not satisfied:\n$lines")
+                    handleFailedVerification(lines, ErrorCode.INVALID_PROOF)
                 }
             }
             
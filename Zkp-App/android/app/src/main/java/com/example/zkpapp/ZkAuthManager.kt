@@ -1,6 +1,7 @@
 package com.example.zkpapp.auth
 
 import android.content.Context
+import android.util.Base64
 import android.util.Log
 import com.example.zkpapp.IdentityStorage
 import com.example.zkpapp.NetworkUtils
@@ -11,6 +12,7 @@ import kotlinx.coroutines.Dispatchers
 import kotlinx.coroutines.withContext
 import okhttp3.OkHttpClient
 import okhttp3.Protocol
+import org.json.JSONObject
 import retrofit2.Retrofit
 import retrofit2.converter.gson.GsonConverterFactory
 import java.util.concurrent.TimeUnit
@@ -23,6 +25,41 @@ object ZkAuthManager {
     @Volatile
     private var running = false
 
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+            initRust()
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e("ZkAuthManager", "❌ Failed to load Rust library", e)
+        }
+    }
+
+    /** Sets up Rust logging (lib.rs). */
+    @JvmStatic
+    external fun initRust()
+
+    /** Identity request JSON (`{"kind":"nullifier",...}`) -> proof envelope JSON. Throws [ZkpException]. */
+    @JvmStatic
+    external fun generateZkpProof(identityJson: String): String
+
+    // 🎟️ Relay QR = signed challenge "zkc1.<base64url claims>.<sig>" (claims: sid, dom, exp).
+    // Poora token hi challenge hai; signature server check karta hai.
+    private data class LoginChallenge(val sessionId: String, val domain: String, val token: String)
+
+    private fun parseChallenge(qr: String): LoginChallenge {
+        val parts = qr.trim().split(".")
+        if (parts.size != 3 || parts[0] != "zkc1") {
+            throw Exception("❌ Invalid QR (not a login challenge)")
+        }
+        val claims = JSONObject(
+            String(Base64.decode(parts[1], Base64.URL_SAFE or Base64.NO_PADDING or Base64.NO_WRAP))
+        )
+        if (claims.optLong("exp") * 1000 < System.currentTimeMillis()) {
+            throw Exception("❌ QR Expired. Refresh the website.")
+        }
+        return LoginChallenge(claims.getString("sid"), claims.getString("dom"), qr.trim())
+    }
+
     private val api: RelayApi by lazy {
         val client = OkHttpClient.Builder()
             .protocols(listOf(Protocol.HTTP_1_1))
@@ -39,9 +76,17 @@ object ZkAuthManager {
             .create(RelayApi::class.java)
     }
 
+    /** Hostname-ish: letters, digits, dots, dashes, optional port. */
+    private val DOMAIN_PATTERN = Regex("^[A-Za-z0-9.-]{1,253}(:[0-9]{1,5})?$")
+
+    /**
+     * `confirmDomain` shows the QR's `dom` to the user; the proof is bound to that
+     * domain, so nothing is proven unless they accept it.
+     */
     suspend fun startUniversalLogin(
         context: Context,
-        sessionId: String,
+        scannedQr: String,
+        confirmDomain: suspend (String) -> Boolean,
         onStatus: (String) -> Unit,
         onSuccess: () -> Unit,
         onError: (String) -> Unit,
@@ -56,6 +101,18 @@ object ZkAuthManager {
                 return
             }
 
+            val challenge = parseChallenge(scannedQr)
+
+            // 🛡️ QR kisi bhi site ka ho sakta hai: user ko dikhao kis domain ke liye proof banega
+            if (!DOMAIN_PATTERN.matches(challenge.domain)) {
+                onError("❌ Invalid QR (bad domain)")
+                return
+            }
+            if (!confirmDomain(challenge.domain)) {
+                onError("🚫 Login to ${challenge.domain} cancelled")
+                return
+            }
+
             onStatus("🦁 Fetching Passport Identity...")
 
             // ⏱️ DAY 84: START BENCHMARK TIMER
@@ -71,13 +128,12 @@ object ZkAuthManager {
                 }
 
                 val realSecret = IdentityStorage.getSecret()
-                val realDomain = IdentityStorage.getDomain()
 
-                // Use Real Data for Zero Knowledge Proof
+                // Domain + challenge server ke token se: proof sirf isi website/session ke liye valid
                 val result = ZkAuth.safeGenerateNullifier(
                     secret = realSecret,
-                    domain = realDomain,
-                    challenge = sessionId
+                    domain = challenge.domain,
+                    challenge = challenge.token
                 )
                 
                 // Calculate Time immediately after proof generation
@@ -97,7 +153,7 @@ object ZkAuthManager {
 
             // 3. Upload to Server
             val response = withContext(Dispatchers.IO) {
-                api.uploadProof(ProofRequest(sessionId, proof))
+                api.uploadProof(ProofRequest(challenge.sessionId, proof))
             }
 
             if (response.isSuccessful) onSuccess()
@@ -114,6 +170,9 @@ object ZkAuthManager {
     private fun mapError(code: Int) = when (code) {
         401 -> "❌ Server Private"
         404 -> "❌ Session Expired"
+        409 -> "❌ Session Already Used"
+        413 -> "❌ Proof Too Large"
+        422 -> "⛔ Proof Rejected by Server (expired or wrong site?)"
         502 -> "❌ Invalid QR"
         else -> "❌ Server Error ($code)"
     }
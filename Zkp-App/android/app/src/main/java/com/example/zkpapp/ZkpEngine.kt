@@ -0,0 +1,57 @@
+package com.example.zkpapp
+
+import android.util.Log
+
+/**
+ * Long-lived Rust engine (see `engine.rs`). Create once, reuse the handle for every
+ * prove/verify call, and destroy it when done. Circuits stay warm between calls.
+ *
+ * Config JSON (all optional):
+ * `{"prover_threads":2,"warm_circuits":["balance"],"trusted_digests":[],"track_nullifiers":true,
+ * "trust_bundle_publishers":["<ed25519 hex>"],"min_trust_bundle_version":0}`
+ *
+ * Persist the last installed bundle version and pass it back as `min_trust_bundle_version`
+ * so an older bundle can't be installed after a restart.
+ */
+object ZkpEngine {
+    private const val TAG = "ZkpEngine"
+
+    init {
+        try {
+            System.loadLibrary("zkp_mobile")
+        } catch (e: UnsatisfiedLinkError) {
+            Log.e(TAG, "❌ Failed to load Rust library", e)
+        }
+    }
+
+    /** Returns an opaque handle. Throws [ZkpException] on bad config. */
+    @JvmStatic
+    external fun createEngine(configJson: String): Long
+
+    /** Frees the engine; running proving jobs keep it alive until they finish. */
+    @JvmStatic
+    external fun destroyEngine(handle: Long)
+
+    /** `requestJson` is an identity request (`{"kind":"balance",...}`); returns envelope JSON. */
+    @JvmStatic
+    external fun prove(handle: Long, requestJson: String): String
+
+    /** Returns a verification report JSON (`verified`, `reason`, `public_inputs`, ...). */
+    @JvmStatic
+    external fun verify(handle: Long, envelopeJson: String): String
+
+    /**
+     * Payload + policy (JSON/TOML), behind the same circuit digest allow-list as [verify]. The installed
+     * trust bundle narrows the policy: issuers it doesn't trust are dropped, its revocation root must match.
+     */
+    @JvmStatic
+    external fun verifyWithPolicy(handle: Long, payload: String, policy: String): String
+
+    /** `bundle` = base64 of the signed file, or its QR frames as a JSON array. Returns the installed bundle JSON. */
+    @JvmStatic
+    external fun installTrustBundle(handle: Long, bundle: String): String
+
+    /** Like [Mdoc.verify], with the installed trust bundle's CSCA certificates as the IACA roots. */
+    @JvmStatic
+    external fun verifyMdoc(handle: Long, issuerSignedB64: String, docType: String): String
+}
@@ -0,0 +1,24 @@
+package com.example.zkpapp
+
+/**
+ * Thrown by every Rust JNI function when it fails (see `error.rs` / `jni_bridge.rs`).
+ * `code` is stable across releases, so UI can switch on it instead of parsing text.
+ */
+class ZkpException(val code: Int, message: String) : Exception(message) {
+
+    companion object {
+        const val JNI = 100
+        const val INVALID_INPUT = 200
+        const val INVALID_ENCODING = 201
+        const val MALFORMED_JSON = 202
+        const val CORRUPT_PROOF = 300
+        const val PROOF_REJECTED = 301
+        const val CIRCUIT_BUILD = 400
+        const val PROVING_FAILED = 401
+        const val SERIALIZATION = 402
+        const val INTEGRITY_CHECK_FAILED = 500
+        const val SIGNATURE_INVALID = 501
+        const val CANCELLED = 600
+        const val PANIC = 900
+    }
+}
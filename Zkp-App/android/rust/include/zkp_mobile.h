@@ -0,0 +1,152 @@
+#ifndef ZKP_MOBILE_H
+#define ZKP_MOBILE_H
+
+/* Generated by cbindgen from src/capi.rs. Do not edit by hand. */
+
+#include <stdint.h>
+#include <stddef.h>
+
+#define ZKP_OK 0
+
+#define ZKP_ERR_INVALID_INPUT 200
+
+#define ZKP_ERR_INVALID_ENCODING 201
+
+#define ZKP_ERR_MALFORMED_JSON 202
+
+#define ZKP_ERR_CORRUPT_PROOF 300
+
+#define ZKP_ERR_PROOF_REJECTED 301
+
+#define ZKP_ERR_CIRCUIT_BUILD 400
+
+#define ZKP_ERR_PROVING_FAILED 401
+
+#define ZKP_ERR_SERIALIZATION 402
+
+#define ZKP_ERR_INTEGRITY_CHECK_FAILED 500
+
+#define ZKP_ERR_SIGNATURE_INVALID 501
+
+#define ZKP_ERR_CANCELLED 600
+
+#define ZKP_ERR_PANIC 900
+
+typedef struct ZkpEngine ZkpEngine;
+
+// Byte buffer handed to C. `data` is null only when `len` is 0.
+typedef struct ZkpBuffer {
+  uint8_t *data;
+  size_t len;
+} ZkpBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Library version, static NUL-terminated string.
+const char *zkp_version(void);
+
+// Creates an engine from `EngineConfig` JSON (empty = defaults).
+//
+// # Safety
+// `config_json` points to `config_len` bytes; `out_engine` is valid for writes.
+int32_t zkp_engine_new(const uint8_t *config_json,
+                       size_t config_len,
+                       const ZkpEngine **out_engine);
+
+// Releases an engine. Null is ignored.
+//
+// # Safety
+// `engine` came from `zkp_engine_new` and is not used afterwards.
+void zkp_engine_free(const ZkpEngine *engine);
+
+// `IdentityRequest` JSON -> `ProofEnvelope` JSON in `out_envelope`.
+//
+// # Safety
+// `engine` is live; `request_json` points to `request_len` bytes; `out_envelope` is valid for writes.
+int32_t zkp_engine_prove(const ZkpEngine *engine,
+                         const uint8_t *request_json,
+                         size_t request_len,
+                         ZkpBuffer *out_envelope);
+
+// `ProofEnvelope` JSON -> `VerificationReport` JSON in `out_report`.
+// A rejected proof is `ZKP_OK` with `"verified": false` in the report.
+//
+// # Safety
+// `engine` is live; `envelope_json` points to `envelope_len` bytes; `out_report` is valid for writes.
+int32_t zkp_engine_verify(const ZkpEngine *engine,
+                          const uint8_t *envelope_json,
+                          size_t envelope_len,
+                          ZkpBuffer *out_report);
+
+// Payload (balance proof, policy presentation or VP) + policy (JSON / TOML) ->
+// `PolicyReport` JSON in `out_report`. Same digest allow-list as `zkp_engine_verify`;
+// the installed trust bundle narrows the policy's issuers and revocation root.
+//
+// # Safety
+// `engine` is live; `payload` / `policy` point to their lengths in bytes; `out_report` is valid for writes.
+int32_t zkp_engine_verify_with_policy(const ZkpEngine *engine,
+                                      const uint8_t *payload,
+                                      size_t payload_len,
+                                      const uint8_t *policy,
+                                      size_t policy_len,
+                                      ZkpBuffer *out_report);
+
+// Installs a signed trust bundle (raw CBOR file) -> `TrustBundle` JSON in `out_bundle`.
+// Rollbacks, expired bundles and unknown publishers are errors.
+//
+// # Safety
+// `engine` is live; `bundle` points to `bundle_len` bytes; `out_bundle` is valid for writes.
+int32_t zkp_engine_install_trust_bundle(const ZkpEngine *engine,
+                                        const uint8_t *bundle,
+                                        size_t bundle_len,
+                                        ZkpBuffer *out_bundle);
+
+// `IssuerSigned` / `Document` CBOR -> `VerifiedMdoc` JSON in `out_mdoc`, with the
+// installed trust bundle's CSCA entries as the IACA roots (`mdoc` feature).
+//
+// # Safety
+// `engine` is live; `issuer_signed` / `doc_type` point to their lengths in bytes; `out_mdoc` is valid for writes.
+int32_t zkp_engine_verify_mdoc(const ZkpEngine *engine,
+                               const uint8_t *issuer_signed,
+                               size_t issuer_signed_len,
+                               const uint8_t *doc_type,
+                               size_t doc_type_len,
+                               ZkpBuffer *out_mdoc);
+
+// Raw base64 balance proof (the offline QR payload). `ZKP_OK` = valid,
+// `ZKP_ERR_PROOF_REJECTED` = invalid, anything else = unreadable input.
+//
+// # Safety
+// `proof_base64` points to `proof_len` bytes.
+int32_t zkp_verify_balance_proof(const uint8_t *proof_base64, size_t proof_len);
+
+// Engine-less policy check on the process-wide circuit cache (no trust bundle,
+// no digest allow-list): what the offline verifier screen runs.
+//
+// # Safety
+// `payload` / `policy` point to their lengths in bytes; `out_report` is valid for writes.
+int32_t zkp_verify_with_policy(const uint8_t *payload,
+                               size_t payload_len,
+                               const uint8_t *policy,
+                               size_t policy_len,
+                               ZkpBuffer *out_report);
+
+// Message of the last failed call on this thread (empty buffer if none).
+//
+// # Safety
+// `out_message` is valid for writes.
+int32_t zkp_last_error(ZkpBuffer *out_message);
+
+// Frees a buffer returned by this library. Empty buffers are ignored.
+//
+// # Safety
+// `buffer` came from this library and is freed only once.
+void zkp_buffer_free(ZkpBuffer buffer);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* ZKP_MOBILE_H */
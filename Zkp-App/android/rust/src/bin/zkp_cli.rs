@@ -0,0 +1,700 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🖥️ ZKP-CLI (QA tool: phone ke bina prove / verify)
+// Same circuits, same envelope JSON, same "i/n|data" QR frames as the app.
+// Har input FILE ya "-" (stdin) ho sakta hai; output stdout ya `-o FILE`.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use serde_json::json;
+
+use zkp_mobile::credential::Credential;
+use zkp_mobile::engine::{EngineConfig, ZkpEngine};
+use zkp_mobile::envelope::ProofEnvelope;
+use zkp_mobile::error::{ZkpError, ZkpResult};
+#[cfg(feature = "circom")]
+use zkp_mobile::circom::{witness::WitnessCalculator, wtns, CircomProver};
+#[cfg(feature = "groth16")]
+use zkp_mobile::groth16;
+use zkp_mobile::identity_request::IdentityRequest;
+use zkp_mobile::issuer::{self, BalanceClaim, Issuer, TrustedIssuers};
+#[cfg(feature = "mdoc")]
+use zkp_mobile::mdoc;
+use zkp_mobile::offline_identity;
+use zkp_mobile::passport_security::PassportData;
+use zkp_mobile::policy::{self, Policy};
+use zkp_mobile::proving_jobs::NoProgress;
+use zkp_mobile::qr_stream;
+use zkp_mobile::revocation::RevocationRoot;
+use zkp_mobile::trust_bundle::{self, CscaEntry, Publisher, TrustBundle};
+use zkp_mobile::vc::{self, VerifiablePresentation};
+#[cfg(feature = "qr-render")]
+use zkp_mobile::qr_image::{self, ErrorCorrection, QrRenderOptions};
+
+#[derive(Parser)]
+#[command(name = "zkp-cli", version, about = "Prove / verify ZKP identity proofs off-device")]
+struct Cli {
+    /// Write output here instead of stdout
+    #[arg(short, long, global = true)]
+    output: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a proof envelope
+    #[command(subcommand)]
+    Prove(ProveCommand),
+    /// Issuer side: keys, signed balance credentials, trusted issuer set
+    #[command(subcommand)]
+    Issue(IssueCommand),
+    /// W3C Verifiable Presentations (`Plonky2Proof2026` proof suite)
+    #[command(subcommand)]
+    Vc(VcCommand),
+    /// Signed offline trust bundles for verifier devices
+    #[command(subcommand)]
+    Bundle(BundleCommand),
+    /// Verify an envelope, a QR frame array, or a raw base64 balance proof
+    Verify {
+        /// Input file or "-" for stdin
+        #[arg(default_value = "-")]
+        input: String,
+        /// Check against a policy file (JSON / TOML) and report every clause
+        #[arg(long)]
+        policy: Option<String>,
+    },
+    /// Print envelope metadata without verifying
+    Inspect {
+        #[arg(default_value = "-")]
+        input: String,
+    },
+    /// Split a payload into QR frames (JSON array, same as the app)
+    QrSplit {
+        #[arg(default_value = "-")]
+        input: String,
+        #[arg(long, default_value_t = qr_stream::QR_CHUNK_SIZE)]
+        chunk_size: usize,
+    },
+    /// Join QR frames (JSON array or one frame per line) back into the payload
+    QrJoin {
+        #[arg(default_value = "-")]
+        input: String,
+    },
+    /// Verify a snarkjs Groth16 proof (Circom circuits: PIN, age, Merkle)
+    #[cfg(feature = "groth16")]
+    Groth16Verify {
+        /// verification_key.json
+        #[arg(long)]
+        vk: String,
+        /// proof.json
+        #[arg(long)]
+        proof: String,
+        /// public.json
+        #[arg(long)]
+        public: String,
+    },
+    /// Groth16-prove a Circom circuit from its snarkjs zkey (no Node needed)
+    #[cfg(feature = "circom")]
+    CircomProve {
+        /// <circuit>_final.zkey
+        #[arg(long)]
+        zkey: PathBuf,
+        /// <circuit>_js/<circuit>.wasm (with `input`), or use --wtns
+        #[arg(long, required_unless_present = "wtns")]
+        wasm: Option<PathBuf>,
+        /// Precomputed witness instead of --wasm + input
+        #[arg(long, conflicts_with = "wasm")]
+        wtns: Option<PathBuf>,
+        /// Check the witness against <circuit>.r1cs first (names the failing constraint)
+        #[arg(long)]
+        r1cs: Option<PathBuf>,
+        /// Also write snarkjs-style proof.json / public.json
+        #[arg(long)]
+        proof_out: Option<PathBuf>,
+        #[arg(long)]
+        public_out: Option<PathBuf>,
+        /// input.json (with --wasm)
+        #[arg(default_value = "-")]
+        input: String,
+    },
+    /// Render a payload (or a JSON array of frames) as QR images
+    #[cfg(feature = "qr-render")]
+    QrRender {
+        #[arg(default_value = "-")]
+        input: String,
+        /// Directory for png/svg (one file per frame), file for gif/apng
+        #[arg(long)]
+        out: PathBuf,
+        #[arg(long, value_enum, default_value_t = ImageFormat::Png)]
+        format: ImageFormat,
+        /// L, M, Q or H
+        #[arg(long, default_value = "M")]
+        ec_level: ErrorCorrection,
+        /// Fixed QR version 1-40 (default: smallest that fits)
+        #[arg(long)]
+        qr_version: Option<i16>,
+        #[arg(long, default_value_t = 8)]
+        module_px: u32,
+        #[arg(long)]
+        no_quiet_zone: bool,
+        #[arg(long, default_value_t = qr_stream::QR_CHUNK_SIZE)]
+        chunk_size: usize,
+        /// Per-frame delay for gif/apng
+        #[arg(long, default_value_t = 300)]
+        delay_ms: u32,
+    },
+    /// Decode QR images (png/apng/gif/jpeg, any order) back into the payload
+    #[cfg(feature = "qr-render")]
+    QrDecode {
+        #[arg(required = true)]
+        images: Vec<PathBuf>,
+    },
+}
+
+#[cfg(feature = "qr-render")]
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum ImageFormat {
+    Png,
+    Svg,
+    Gif,
+    Apng,
+}
+
+#[derive(Subcommand)]
+enum ProveCommand {
+    /// balance >= minimum, bound to Poseidon(balance)
+    Balance {
+        #[arg(long)]
+        balance: u64,
+    },
+    /// Poseidon(secret, domain, challenge) nullifier
+    Nullifier {
+        /// File holding the secret ("-" = stdin); never passed on the command line
+        #[arg(long)]
+        secret_file: String,
+        #[arg(long)]
+        domain: String,
+        #[arg(long)]
+        challenge: String,
+    },
+    /// DG1 commitment (PassportData JSON as produced by `PassportData.toRustJson()`)
+    Passport {
+        #[arg(default_value = "-")]
+        input: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum IssueCommand {
+    /// New issuer key file (contains the SECRET key; keep it off shared disks)
+    Keygen {
+        #[arg(long)]
+        issuer_id: String,
+    },
+    /// Sign a {holder_commitment, balance, expiry, nonce} credential
+    Balance {
+        /// Issuer key file from `issue keygen`
+        #[arg(long)]
+        key: String,
+        /// Decimal, from the holder's app (`holder_commitment(secret)`)
+        #[arg(long)]
+        holder_commitment: u64,
+        #[arg(long)]
+        balance: u64,
+        /// Expiry as unix seconds (default: now + --valid-days)
+        #[arg(long)]
+        expiry: Option<u64>,
+        #[arg(long, default_value_t = 30)]
+        valid_days: u64,
+        /// Fixed nonce (default: random)
+        #[arg(long)]
+        nonce: Option<u64>,
+        /// Print QR frames (JSON array) instead of the credential JSON
+        #[arg(long)]
+        qr: bool,
+    },
+    /// Public key set for the verifier app (no secrets): one or more key files
+    TrustSet {
+        /// Must be higher than the set the app already has
+        #[arg(long)]
+        version: u64,
+        #[arg(required = true)]
+        keys: Vec<String>,
+        #[arg(long)]
+        qr: bool,
+    },
+    /// Verify an mdoc (IssuerSigned CBOR) against an IACA trust list and
+    /// re-issue the picked elements as a credential signed by this issuer
+    #[cfg(feature = "mdoc")]
+    Mdoc {
+        #[arg(long)]
+        key: String,
+        /// IACA trust list JSON
+        #[arg(long)]
+        iaca: String,
+        /// docType the MSO (and the Document, if given) must carry
+        #[arg(long, default_value = mdoc::MDL_DOC_TYPE)]
+        doc_type: String,
+        /// elementIdentifier to carry over (repeat; `valid_until` is always added)
+        #[arg(long = "element", required = true)]
+        elements: Vec<String>,
+        #[arg(long)]
+        qr: bool,
+        /// IssuerSigned (or Document) CBOR file
+        input: PathBuf,
+    },
+    /// Check a credential (JSON or QR frames): signature, trusted issuer, expiry
+    Check {
+        /// Trusted issuer set from `issue trust-set`
+        #[arg(long)]
+        trusted: String,
+        #[arg(default_value = "-")]
+        input: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum VcCommand {
+    /// Wrap an envelope / credential / policy presentation as a VP
+    Wrap {
+        /// Needed for policy presentations
+        #[arg(long)]
+        policy: Option<String>,
+        #[arg(default_value = "-")]
+        input: String,
+    },
+    /// Verify against a policy and emit the result as a VP (embeds the proof)
+    Report {
+        #[arg(long)]
+        policy: String,
+        #[arg(default_value = "-")]
+        input: String,
+    },
+    /// RDFC-1.0 (URDNA2015) canonical N-Quads of a VP
+    Canonical {
+        #[arg(default_value = "-")]
+        input: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum BundleCommand {
+    /// New publisher key file (contains the SECRET key; keep it off shared disks)
+    Keygen {
+        #[arg(long)]
+        publisher: String,
+    },
+    /// Sign a bundle; prints base64 (or QR frames with --qr)
+    Sign {
+        /// Publisher key file from `bundle keygen`
+        #[arg(long)]
+        key: String,
+        /// Must be higher than the bundle verifiers already have
+        #[arg(long)]
+        version: u64,
+        #[arg(long, default_value_t = 30)]
+        valid_days: u64,
+        /// Trusted issuer set from `issue trust-set`
+        #[arg(long)]
+        issuers: Option<String>,
+        /// Published revocation root JSON
+        #[arg(long)]
+        revocation: Option<String>,
+        /// CSCA / IACA certificate PEM file (repeat); verifiers check mdoc DS certs against these
+        #[arg(long = "csca")]
+        csca: Vec<String>,
+        /// Accepted circuit digest, hex (repeat; none = verifier's own list)
+        #[arg(long = "digest")]
+        digests: Vec<String>,
+        #[arg(long)]
+        qr: bool,
+    },
+    /// Check the signature against pinned publishers and print the bundle
+    Inspect {
+        /// Publisher public key, hex (repeat)
+        #[arg(long = "publisher", required = true)]
+        publishers: Vec<String>,
+        /// base64 or QR frames
+        #[arg(default_value = "-")]
+        input: String,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli.command) {
+        Ok((text, ok)) => match write_output(cli.output.as_ref(), &text) {
+            Ok(()) if ok => ExitCode::SUCCESS,
+            Ok(()) => ExitCode::from(1),
+            Err(e) => fail(&e),
+        },
+        Err(e) => fail(&e),
+    }
+}
+
+fn fail(e: &ZkpError) -> ExitCode {
+    eprintln!("❌ [{}] {}", e.code(), e);
+    ExitCode::from(2)
+}
+
+/// Returns the output text and whether the command "passed" (for exit codes).
+fn run(command: Command) -> ZkpResult<(String, bool)> {
+    match command {
+        Command::Prove(prove) => {
+            let request = match prove {
+                ProveCommand::Balance { balance } => IdentityRequest::Balance { balance },
+                ProveCommand::Nullifier { secret_file, domain, challenge } => IdentityRequest::Nullifier {
+                    secret: read_input(&secret_file)?.trim_end_matches(['\r', '\n']).to_string(),
+                    domain,
+                    challenge,
+                },
+                ProveCommand::Passport { input } => {
+                    let data: PassportData = serde_json::from_str(&read_input(&input)?)?;
+                    IdentityRequest::Passport(data)
+                }
+            };
+            let envelope = engine()?.prove(&request, &mut NoProgress)?;
+            Ok((pretty(&envelope)?, true))
+        }
+
+        Command::Issue(command) => issue(command),
+
+        Command::Vc(command) => vc_command(command),
+
+        Command::Bundle(command) => bundle(command),
+
+        Command::Verify { input, policy: Some(policy) } => {
+            let policy = Policy::parse(&read_input(&policy)?)?;
+            let report = policy::verify_with_policy(&unwrap_frames(&read_input(&input)?)?, &policy, engine()?.circuits())?;
+            let ok = report.verified;
+            Ok((pretty(&report)?, ok))
+        }
+
+        Command::Verify { input, policy: None } => verify(&read_input(&input)?),
+
+        Command::Inspect { input } => {
+            let envelope = ProofEnvelope::from_json(&unwrap_frames(&read_input(&input)?)?)?;
+            let local = engine()?.circuits().digest(envelope.kind)?;
+            let report = json!({
+                "version": envelope.version,
+                "kind": envelope.kind,
+                "circuit_digest": envelope.circuit_digest,
+                "digest_matches_local_circuit": envelope.circuit_digest == local,
+                "public_inputs": envelope.public_inputs,
+                "proof_base64_len": envelope.proof.len(),
+            });
+            Ok((pretty(&report)?, true))
+        }
+
+        Command::QrSplit { input, chunk_size } => {
+            let frames = qr_stream::split(read_input(&input)?.trim(), chunk_size);
+            Ok((pretty(&frames)?, true))
+        }
+
+        Command::QrJoin { input } => Ok((join_frames(&read_input(&input)?)?, true)),
+
+        #[cfg(feature = "groth16")]
+        Command::Groth16Verify { vk, proof, public } => {
+            let report = groth16::check(&read_input(&vk)?, &read_input(&proof)?, &read_input(&public)?)?;
+            let ok = report.verified;
+            Ok((pretty(&report)?, ok))
+        }
+
+        #[cfg(feature = "circom")]
+        Command::CircomProve { zkey, wasm, wtns: wtns_file, r1cs, proof_out, public_out, input } => {
+            let mut prover = CircomProver::from_zkey(&read_bytes(&zkey)?)?;
+            if let Some(r1cs) = r1cs {
+                prover = prover.with_r1cs(&read_bytes(&r1cs)?)?;
+            }
+            let witness = match (wasm, wtns_file) {
+                (_, Some(path)) => wtns::read_wtns(&read_bytes(&path)?)?,
+                (Some(path), None) => WitnessCalculator::new(&read_bytes(&path)?)?.calculate(&read_input(&input)?)?,
+                (None, None) => unreachable!("clap requires --wasm or --wtns"),
+            };
+            let proof = prover.prove(&witness)?;
+
+            let io_err = |e: io::Error| ZkpError::Serialization(format!("write output: {}", e));
+            if let Some(path) = proof_out {
+                fs::write(path, pretty(&proof.proof)?).map_err(io_err)?;
+            }
+            if let Some(path) = public_out {
+                fs::write(path, pretty(&proof.public_signals)?).map_err(io_err)?;
+            }
+            Ok((pretty(&proof)?, true))
+        }
+
+        #[cfg(feature = "qr-render")]
+        Command::QrRender {
+            input,
+            out,
+            format,
+            ec_level,
+            qr_version,
+            module_px,
+            no_quiet_zone,
+            chunk_size,
+            delay_ms,
+        } => {
+            let raw = read_input(&input)?;
+            let frames: Vec<String> = if raw.trim_start().starts_with('[') {
+                serde_json::from_str(raw.trim())?
+            } else {
+                qr_stream::split(raw.trim(), chunk_size)
+            };
+            let opts = QrRenderOptions {
+                error_correction: ec_level,
+                version: qr_version,
+                module_px,
+                quiet_zone: !no_quiet_zone,
+            };
+            let files = render_frames(&frames, &opts, format, &out, delay_ms)?;
+            Ok((pretty(&json!({ "frames": frames.len(), "files": files }))?, true))
+        }
+
+        #[cfg(feature = "qr-render")]
+        Command::QrDecode { images } => {
+            let mut bytes = Vec::with_capacity(images.len());
+            for path in &images {
+                bytes.push(read_bytes(path)?);
+            }
+            Ok((qr_image::decode_payload(&bytes)?, true))
+        }
+    }
+}
+
+/// Writes the images and returns the paths written.
+#[cfg(feature = "qr-render")]
+fn render_frames(
+    frames: &[String],
+    opts: &QrRenderOptions,
+    format: ImageFormat,
+    out: &std::path::Path,
+    delay_ms: u32,
+) -> ZkpResult<Vec<String>> {
+    let io_err = |e: io::Error| ZkpError::Serialization(format!("{}: {}", out.display(), e));
+
+    let animated = match format {
+        ImageFormat::Gif => Some(qr_image::render_gif(frames, opts, delay_ms)?),
+        ImageFormat::Apng => Some(qr_image::render_apng(frames, opts, delay_ms)?),
+        ImageFormat::Png | ImageFormat::Svg => None,
+    };
+    if let Some(bytes) = animated {
+        fs::write(out, bytes).map_err(io_err)?;
+        return Ok(vec![out.display().to_string()]);
+    }
+
+    fs::create_dir_all(out).map_err(io_err)?;
+    let mut files = Vec::with_capacity(frames.len());
+    for (i, frame) in frames.iter().enumerate() {
+        let (name, bytes) = match format {
+            ImageFormat::Svg => (format!("frame-{:03}.svg", i + 1), qr_image::render_svg(frame, opts)?.into_bytes()),
+            _ => (format!("frame-{:03}.png", i + 1), qr_image::render_png(frame, opts)?),
+        };
+        let path = out.join(name);
+        fs::write(&path, bytes).map_err(io_err)?;
+        files.push(path.display().to_string());
+    }
+    Ok(files)
+}
+
+fn issue(command: IssueCommand) -> ZkpResult<(String, bool)> {
+    match command {
+        IssueCommand::Keygen { issuer_id } => Ok((pretty(&Issuer::generate(&issuer_id)?.to_key_file())?, true)),
+
+        IssueCommand::Balance { key, holder_commitment, balance, expiry, valid_days, nonce, qr } => {
+            let issuer = Issuer::from_key_file(&read_input(&key)?)?;
+            let expiry = expiry.unwrap_or_else(|| issuer::now_unix() + valid_days * 24 * 60 * 60);
+            let mut claim = BalanceClaim::new(holder_commitment, balance, expiry);
+            if let Some(nonce) = nonce {
+                claim.nonce = nonce;
+            }
+
+            let credential = issuer.issue_balance(&claim)?;
+            if qr {
+                Ok((pretty(&issuer::credential_qr_frames(&credential)?)?, true))
+            } else {
+                Ok((pretty(&credential)?, true))
+            }
+        }
+
+        IssueCommand::TrustSet { version, keys, qr } => {
+            let mut entries = Vec::with_capacity(keys.len());
+            for path in &keys {
+                entries.push(Issuer::from_key_file(&read_input(path)?)?.trusted_entry());
+            }
+            let set = TrustedIssuers::new(version, entries)?;
+            if qr {
+                Ok((pretty(&set.to_qr_frames()?)?, true))
+            } else {
+                Ok((pretty(&set)?, true))
+            }
+        }
+
+        #[cfg(feature = "mdoc")]
+        IssueCommand::Mdoc { key, iaca, doc_type, elements, qr, input } => {
+            let issuer = Issuer::from_key_file(&read_input(&key)?)?;
+            let trust = mdoc::IacaTrustList::from_json(&read_input(&iaca)?)?;
+            let verified = mdoc::verify_issuer_signed(&read_bytes(&input)?, &doc_type, &trust, issuer::now_unix())?;
+            let elements: Vec<&str> = elements.iter().map(String::as_str).collect();
+
+            let credential = mdoc::derive_credential(&verified, &elements, &issuer)?;
+            if qr {
+                Ok((pretty(&issuer::credential_qr_frames(&credential)?)?, true))
+            } else {
+                Ok((pretty(&credential)?, true))
+            }
+        }
+
+        IssueCommand::Check { trusted, input } => {
+            let trusted = TrustedIssuers::from_json(&unwrap_frames(&read_input(&trusted)?)?)?;
+            let credential = Credential::from_json(&unwrap_frames(&read_input(&input)?)?)?;
+            let (valid, claim, reason) = match issuer::check_balance_credential(&credential, &trusted, issuer::now_unix()) {
+                Ok(claim) => (true, Some(claim), None),
+                Err(ZkpError::ProofRejected(reason)) => (false, None, Some(reason)),
+                Err(e) => return Err(e),
+            };
+            let issuer_id = credential.issuer_key().ok().and_then(|pk| trusted.find(&pk).map(|i| i.issuer_id.clone()));
+            let report = json!({ "valid": valid, "issuer_id": issuer_id, "claim": claim, "reason": reason });
+            Ok((pretty(&report)?, valid))
+        }
+    }
+}
+
+fn bundle(command: BundleCommand) -> ZkpResult<(String, bool)> {
+    match command {
+        BundleCommand::Keygen { publisher } => Ok((pretty(&Publisher::generate(&publisher)?.to_key_file())?, true)),
+
+        BundleCommand::Sign { key, version, valid_days, issuers, revocation, csca, digests, qr } => {
+            let publisher = Publisher::from_key_file(&read_input(&key)?)?;
+            let now = issuer::now_unix();
+            let mut bundle = TrustBundle::new(version, now, now + valid_days * 24 * 60 * 60);
+            bundle.trusted_issuers =
+                issuers.map(|path| TrustedIssuers::from_json(&unwrap_frames(&read_input(&path)?)?)).transpose()?;
+            bundle.revocation_root =
+                revocation.map(|path| RevocationRoot::from_json(&unwrap_frames(&read_input(&path)?)?)).transpose()?;
+            for path in &csca {
+                let name = Path::new(path).file_stem().map_or_else(|| path.clone(), |s| s.to_string_lossy().into_owned());
+                bundle.csca.push(CscaEntry { name, country: None, certificate: read_input(path)?.trim().to_string() });
+            }
+            bundle.circuit_digests = digests;
+
+            let signed = publisher.sign(&bundle)?;
+            if qr {
+                Ok((pretty(&trust_bundle::to_qr_frames(&signed))?, true))
+            } else {
+                Ok((trust_bundle::encode_text(&signed), true))
+            }
+        }
+
+        BundleCommand::Inspect { publishers, input } => {
+            let keys = publishers.iter().map(|k| trust_bundle::publisher_key_from_hex(k)).collect::<ZkpResult<Vec<_>>>()?;
+            let bundle = trust_bundle::open(&trust_bundle::decode_text(&read_input(&input)?)?, &keys)?;
+            let expired = bundle.is_expired(issuer::now_unix());
+            Ok((pretty(&json!({ "expired": expired, "bundle": bundle }))?, !expired))
+        }
+    }
+}
+
+fn verify(raw: &str) -> ZkpResult<(String, bool)> {
+    let payload = unwrap_frames(raw)?;
+
+    // Envelope JSON vs. the app's raw base64 balance proof (VerifierActivity format)
+    if payload.trim_start().starts_with('{') {
+        let report = engine()?.verify(&ProofEnvelope::from_json(&payload)?)?;
+        let ok = report.verified;
+        Ok((pretty(&report)?, ok))
+    } else {
+        let (verified, reason) = match offline_identity::verify_balance_proof(&payload) {
+            Ok(()) => (true, None),
+            Err(ZkpError::ProofRejected(reason)) => (false, Some(reason)),
+            Err(e) => return Err(e),
+        };
+        let report = json!({ "kind": "balance", "verified": verified, "reason": reason });
+        Ok((pretty(&report)?, verified))
+    }
+}
+
+/// A JSON array / line list of QR frames becomes its payload; anything else passes through.
+fn vc_command(command: VcCommand) -> ZkpResult<(String, bool)> {
+    match command {
+        VcCommand::Wrap { policy, input } => {
+            let policy = policy.map(|p| read_input(&p)).transpose()?;
+            let vp = vc::wrap_json(&unwrap_frames(&read_input(&input)?)?, policy.as_deref())?;
+            Ok((pretty(&serde_json::from_str::<serde_json::Value>(&vp)?)?, true))
+        }
+
+        VcCommand::Report { policy, input } => {
+            let policy = Policy::parse(&read_input(&policy)?)?;
+            let payload = unwrap_frames(&read_input(&input)?)?;
+            let engine = engine()?;
+            let report = policy::verify_with_policy(&payload, &policy, engine.circuits())?;
+            let vp = vc::report_presentation(&payload, &report, engine.circuits())?;
+            Ok((pretty(&vp)?, report.verified))
+        }
+
+        VcCommand::Canonical { input } => {
+            let vp = VerifiablePresentation::from_json(&unwrap_frames(&read_input(&input)?)?)?;
+            Ok((vp.to_canonical_nquads()?, true))
+        }
+    }
+}
+
+fn unwrap_frames(raw: &str) -> ZkpResult<String> {
+    let trimmed = raw.trim();
+    let first_line = trimmed.lines().next().unwrap_or("");
+    if trimmed.starts_with('[') || qr_stream::parse_frame(first_line).is_ok() {
+        join_frames(trimmed)
+    } else {
+        Ok(trimmed.to_string())
+    }
+}
+
+fn join_frames(raw: &str) -> ZkpResult<String> {
+    let trimmed = raw.trim();
+    let frames: Vec<String> = if trimmed.starts_with('[') {
+        serde_json::from_str(trimmed)?
+    } else {
+        trimmed.lines().filter(|l| !l.trim().is_empty()).map(str::to_string).collect()
+    };
+    qr_stream::join(&frames)
+}
+
+fn engine() -> ZkpResult<ZkpEngine> {
+    // CLI har verify me fresh process hai: replay tracking ka koi matlab nahi
+    ZkpEngine::new(EngineConfig { track_nullifiers: false, ..EngineConfig::default() })
+}
+
+fn read_input(path: &str) -> ZkpResult<String> {
+    let io_err = |e: io::Error| ZkpError::InvalidInput(format!("{}: {}", path, e));
+    if path == "-" {
+        let mut buf = String::new();
+        io::stdin().read_to_string(&mut buf).map_err(io_err)?;
+        Ok(buf)
+    } else {
+        fs::read_to_string(path).map_err(io_err)
+    }
+}
+
+#[cfg(any(feature = "circom", feature = "qr-render", feature = "mdoc"))]
+fn read_bytes(path: &std::path::Path) -> ZkpResult<Vec<u8>> {
+    fs::read(path).map_err(|e| ZkpError::InvalidInput(format!("{}: {}", path.display(), e)))
+}
+
+fn write_output(path: Option<&PathBuf>, text: &str) -> ZkpResult<()> {
+    let io_err = |e: io::Error| ZkpError::Serialization(format!("write output: {}", e));
+    match path {
+        Some(p) => fs::write(p, format!("{}\n", text)).map_err(io_err),
+        None => writeln!(io::stdout(), "{}", text).map_err(io_err),
+    }
+}
+
+fn pretty<T: serde::Serialize>(value: &T) -> ZkpResult<String> {
+    serde_json::to_string_pretty(value).map_err(|e| ZkpError::Serialization(e.to_string()))
+}
@@ -0,0 +1,377 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔌 C ABI (feature = "capi")
+// iOS, desktop, Python aur Linux kiosks ke liye stable `extern "C"` API.
+// JNI bhi isi ka ek consumer hai (`jni` feature `capi` on karta hai): Kotlin
+// wale engine / policy exports neeche ke `call_json` se yahi functions bulate
+// hain, to dono ka JSON aur error codes ek hi jagah se aate hain. Plain C types:
+//   • opaque `ZkpEngine*` handles (same Arc handles as the JNI `jlong`)
+//   • `ZkpBuffer` byte buffers, owned by Rust, freed with `zkp_buffer_free`
+//   • `int32_t` status: 0 = OK, otherwise `ZkpError::code()`;
+//     `zkp_last_error` gives the message for the calling thread
+// Header: include/zkp_mobile.h (cbindgen --config cbindgen.toml).
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::cell::RefCell;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use crate::engine::{borrow_handle, into_handle, release_handle, CircuitCache, ZkpEngine};
+use crate::error::{ZkpError, ZkpResult};
+use crate::offline_identity;
+use crate::proving_jobs::NoProgress;
+
+// Status codes: mirror `ZkpError::code()` (error.rs), never renumber.
+pub const ZKP_OK: i32 = 0;
+pub const ZKP_ERR_INVALID_INPUT: i32 = 200;
+pub const ZKP_ERR_INVALID_ENCODING: i32 = 201;
+pub const ZKP_ERR_MALFORMED_JSON: i32 = 202;
+pub const ZKP_ERR_CORRUPT_PROOF: i32 = 300;
+pub const ZKP_ERR_PROOF_REJECTED: i32 = 301;
+pub const ZKP_ERR_CIRCUIT_BUILD: i32 = 400;
+pub const ZKP_ERR_PROVING_FAILED: i32 = 401;
+pub const ZKP_ERR_SERIALIZATION: i32 = 402;
+pub const ZKP_ERR_INTEGRITY_CHECK_FAILED: i32 = 500;
+pub const ZKP_ERR_SIGNATURE_INVALID: i32 = 501;
+pub const ZKP_ERR_CANCELLED: i32 = 600;
+pub const ZKP_ERR_PANIC: i32 = 900;
+
+/// Byte buffer handed to C. `data` is null only when `len` is 0.
+#[repr(C)]
+pub struct ZkpBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl ZkpBuffer {
+    fn empty() -> Self {
+        Self { data: ptr::null_mut(), len: 0 }
+    }
+
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        let mut boxed = bytes.into_boxed_slice();
+        let buffer = Self { data: boxed.as_mut_ptr(), len: boxed.len() };
+        std::mem::forget(boxed);
+        buffer
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<ZkpError>> = const { RefCell::new(None) };
+}
+
+/// Panic barrier + error-code conversion for every export.
+fn ffi_guard<F>(body: F) -> i32
+where
+    F: FnOnce() -> ZkpResult<()>,
+{
+    let err = match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => return ZKP_OK,
+        Ok(Err(e)) => e,
+        Err(payload) => ZkpError::from_panic(payload.as_ref()),
+    };
+    let code = err.code();
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = Some(err));
+    code
+}
+
+/// # Safety
+/// `data` must point to `len` readable bytes (or be null with `len == 0`).
+unsafe fn read_bytes<'a>(data: *const u8, len: usize, what: &str) -> ZkpResult<&'a [u8]> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if data.is_null() {
+        return Err(ZkpError::InvalidInput(format!("{} is null", what)));
+    }
+    Ok(std::slice::from_raw_parts(data, len))
+}
+
+/// # Safety
+/// Same as `read_bytes`.
+unsafe fn read_utf8<'a>(data: *const u8, len: usize, what: &str) -> ZkpResult<&'a str> {
+    std::str::from_utf8(read_bytes(data, len, what)?)
+        .map_err(|_| ZkpError::InvalidEncoding(format!("{} is not UTF-8", what)))
+}
+
+/// # Safety
+/// `out` must be null or valid for writes.
+unsafe fn write_out<T>(out: *mut T, value: T) -> ZkpResult<()> {
+    if out.is_null() {
+        return Err(ZkpError::InvalidInput("output pointer is null".into()));
+    }
+    out.write(value);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📤 EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Library version, static NUL-terminated string.
+#[no_mangle]
+pub extern "C" fn zkp_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
+}
+
+/// Creates an engine from `EngineConfig` JSON (empty = defaults).
+///
+/// # Safety
+/// `config_json` points to `config_len` bytes; `out_engine` is valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_engine_new(
+    config_json: *const u8,
+    config_len: usize,
+    out_engine: *mut *const ZkpEngine,
+) -> i32 {
+    ffi_guard(|| {
+        let config = read_utf8(config_json, config_len, "config_json")?;
+        let engine = ZkpEngine::from_json(config)?;
+        write_out(out_engine, into_handle(engine))
+    })
+}
+
+/// Releases an engine. Null is ignored.
+///
+/// # Safety
+/// `engine` came from `zkp_engine_new` and is not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_engine_free(engine: *const ZkpEngine) {
+    let _ = ffi_guard(|| {
+        release_handle(engine);
+        Ok(())
+    });
+}
+
+/// `IdentityRequest` JSON -> `ProofEnvelope` JSON in `out_envelope`.
+///
+/// # Safety
+/// `engine` is live; `request_json` points to `request_len` bytes; `out_envelope` is valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_engine_prove(
+    engine: *const ZkpEngine,
+    request_json: *const u8,
+    request_len: usize,
+    out_envelope: *mut ZkpBuffer,
+) -> i32 {
+    ffi_guard(|| {
+        let engine = borrow_handle(engine)?;
+        let request = read_utf8(request_json, request_len, "request_json")?;
+        let envelope = engine.prove_json(request, &mut NoProgress)?;
+        write_out(out_envelope, ZkpBuffer::from_vec(envelope.into_bytes()))
+    })
+}
+
+/// `ProofEnvelope` JSON -> `VerificationReport` JSON in `out_report`.
+/// A rejected proof is `ZKP_OK` with `"verified": false` in the report.
+///
+/// # Safety
+/// `engine` is live; `envelope_json` points to `envelope_len` bytes; `out_report` is valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_engine_verify(
+    engine: *const ZkpEngine,
+    envelope_json: *const u8,
+    envelope_len: usize,
+    out_report: *mut ZkpBuffer,
+) -> i32 {
+    ffi_guard(|| {
+        let engine = borrow_handle(engine)?;
+        let envelope = read_utf8(envelope_json, envelope_len, "envelope_json")?;
+        let report = engine.verify_json(envelope)?;
+        write_out(out_report, ZkpBuffer::from_vec(report.into_bytes()))
+    })
+}
+
+/// Payload (balance proof, policy presentation or VP) + policy (JSON / TOML) ->
+/// `PolicyReport` JSON in `out_report`. Same digest allow-list as `zkp_engine_verify`;
+/// the installed trust bundle narrows the policy's issuers and revocation root.
+///
+/// # Safety
+/// `engine` is live; `payload` / `policy` point to their lengths in bytes; `out_report` is valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_engine_verify_with_policy(
+    engine: *const ZkpEngine,
+    payload: *const u8,
+    payload_len: usize,
+    policy: *const u8,
+    policy_len: usize,
+    out_report: *mut ZkpBuffer,
+) -> i32 {
+    ffi_guard(|| {
+        let engine = borrow_handle(engine)?;
+        let payload = read_utf8(payload, payload_len, "payload")?;
+        let report = engine.verify_with_policy_json(payload, read_utf8(policy, policy_len, "policy")?)?;
+        write_out(out_report, ZkpBuffer::from_vec(report.into_bytes()))
+    })
+}
+
+/// Installs a signed trust bundle (raw CBOR file) -> `TrustBundle` JSON in `out_bundle`.
+/// Rollbacks, expired bundles and unknown publishers are errors.
+///
+/// # Safety
+/// `engine` is live; `bundle` points to `bundle_len` bytes; `out_bundle` is valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_engine_install_trust_bundle(
+    engine: *const ZkpEngine,
+    bundle: *const u8,
+    bundle_len: usize,
+    out_bundle: *mut ZkpBuffer,
+) -> i32 {
+    ffi_guard(|| {
+        let engine = borrow_handle(engine)?;
+        let installed = engine.install_trust_bundle(read_bytes(bundle, bundle_len, "bundle")?)?;
+        write_out(out_bundle, ZkpBuffer::from_vec(installed.to_json()?.into_bytes()))
+    })
+}
+
+/// `IssuerSigned` / `Document` CBOR -> `VerifiedMdoc` JSON in `out_mdoc`, with the
+/// installed trust bundle's CSCA entries as the IACA roots (`mdoc` feature).
+///
+/// # Safety
+/// `engine` is live; `issuer_signed` / `doc_type` point to their lengths in bytes; `out_mdoc` is valid for writes.
+#[cfg(feature = "mdoc")]
+#[no_mangle]
+pub unsafe extern "C" fn zkp_engine_verify_mdoc(
+    engine: *const ZkpEngine,
+    issuer_signed: *const u8,
+    issuer_signed_len: usize,
+    doc_type: *const u8,
+    doc_type_len: usize,
+    out_mdoc: *mut ZkpBuffer,
+) -> i32 {
+    ffi_guard(|| {
+        let engine = borrow_handle(engine)?;
+        let bytes = read_bytes(issuer_signed, issuer_signed_len, "issuer_signed")?;
+        let mdoc = engine.verify_mdoc(bytes, read_utf8(doc_type, doc_type_len, "doc_type")?)?;
+        let json = serde_json::to_string(&mdoc).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+        write_out(out_mdoc, ZkpBuffer::from_vec(json.into_bytes()))
+    })
+}
+
+/// Raw base64 balance proof (the offline QR payload). `ZKP_OK` = valid,
+/// `ZKP_ERR_PROOF_REJECTED` = invalid, anything else = unreadable input.
+///
+/// # Safety
+/// `proof_base64` points to `proof_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_verify_balance_proof(proof_base64: *const u8, proof_len: usize) -> i32 {
+    ffi_guard(|| {
+        let proof = read_utf8(proof_base64, proof_len, "proof_base64")?;
+        offline_identity::verify_balance_proof(proof)
+    })
+}
+
+/// Engine-less policy check on the process-wide circuit cache (no trust bundle,
+/// no digest allow-list): what the offline verifier screen runs.
+///
+/// # Safety
+/// `payload` / `policy` point to their lengths in bytes; `out_report` is valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_verify_with_policy(
+    payload: *const u8,
+    payload_len: usize,
+    policy: *const u8,
+    policy_len: usize,
+    out_report: *mut ZkpBuffer,
+) -> i32 {
+    ffi_guard(|| {
+        let payload = read_utf8(payload, payload_len, "payload")?;
+        let policy = read_utf8(policy, policy_len, "policy")?;
+        let report = crate::policy::verify_with_policy_json(payload, policy, CircuitCache::shared())?;
+        write_out(out_report, ZkpBuffer::from_vec(report.into_bytes()))
+    })
+}
+
+/// Message of the last failed call on this thread (empty buffer if none).
+///
+/// # Safety
+/// `out_message` is valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_last_error(out_message: *mut ZkpBuffer) -> i32 {
+    if out_message.is_null() {
+        return ZKP_ERR_INVALID_INPUT;
+    }
+    let message = LAST_ERROR.with(|slot| slot.borrow().as_ref().map(|e| e.to_string()));
+    out_message.write(message.map_or_else(ZkpBuffer::empty, |m| ZkpBuffer::from_vec(m.into_bytes())));
+    ZKP_OK
+}
+
+/// Frees a buffer returned by this library. Empty buffers are ignored.
+///
+/// # Safety
+/// `buffer` came from this library and is freed only once.
+#[no_mangle]
+pub unsafe extern "C" fn zkp_buffer_free(buffer: ZkpBuffer) {
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(buffer.data, buffer.len)));
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔁 RUST-SIDE CONSUMER (JNI)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Status of an export back into the error it recorded on this thread.
+#[cfg(feature = "jni")]
+pub(crate) fn status_result(status: i32) -> ZkpResult<()> {
+    if status == ZKP_OK {
+        return Ok(());
+    }
+    Err(LAST_ERROR
+        .with(|slot| slot.borrow_mut().take())
+        .unwrap_or_else(|| ZkpError::InvalidInput(format!("C ABI call failed with status {}", status))))
+}
+
+/// Runs one buffer-returning export and takes its output as a `String`.
+#[cfg(feature = "jni")]
+pub(crate) fn call_json<F>(call: F) -> ZkpResult<String>
+where
+    F: FnOnce(*mut ZkpBuffer) -> i32,
+{
+    let mut out = ZkpBuffer::empty();
+    status_result(call(&mut out))?;
+    // SAFETY: export ne `out` abhi likha hai; copy karke ek hi baar free
+    let bytes = unsafe {
+        let bytes = read_bytes(out.data, out.len, "output").map(<[u8]>::to_vec);
+        zkp_buffer_free(out);
+        bytes
+    }?;
+    String::from_utf8(bytes).map_err(|_| ZkpError::InvalidEncoding("C ABI output is not UTF-8".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_are_codes_plus_a_thread_local_message() {
+        let config = b"{not json";
+        let mut engine = ptr::null();
+        // SAFETY: inputs outlive the calls, out slots are locals
+        unsafe {
+            assert_eq!(zkp_engine_new(config.as_ptr(), config.len(), &mut engine), ZKP_ERR_MALFORMED_JSON);
+            assert!(engine.is_null());
+
+            let mut message = ZkpBuffer::empty();
+            assert_eq!(zkp_last_error(&mut message), ZKP_OK);
+            assert!(message.len > 0);
+            zkp_buffer_free(message);
+        }
+    }
+
+    #[cfg(feature = "jni")]
+    #[test]
+    fn rust_consumer_gets_the_recorded_error_back() {
+        let policy = b"{\"name\": \"x\", \"clauses\": []}";
+        // SAFETY: null engine is rejected before any dereference
+        let err = call_json(|out| unsafe {
+            zkp_engine_verify_with_policy(ptr::null(), ptr::null(), 0, policy.as_ptr(), policy.len(), out)
+        })
+        .unwrap_err();
+        assert_eq!(err.code(), ZKP_ERR_INVALID_INPUT);
+        assert!(err.to_string().contains("null"));
+
+        // Consumed: the next failure starts from a clean slot
+        assert!(LAST_ERROR.with(|slot| slot.borrow().is_none()));
+    }
+}
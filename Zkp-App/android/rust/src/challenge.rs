@@ -0,0 +1,293 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🎟️ LOGIN CHALLENGES (verifier side)
+// Server ek signed, expiring challenge token deta hai (QR me yahi jaata hai):
+//     zkc1.<base64url(claims JSON)>.<base64url(HMAC-SHA256)>
+// App token ko `challenge` aur claims ke `dom` ko `domain` bana kar nullifier
+// prove karta hai. Upload par `verify_login` check karta hai ki proof ke
+// PI_DOMAIN / PI_CHALLENGE isi session ke token se bane hain.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::time::Duration;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::engine::CircuitCache;
+use crate::error::{ZkpError, ZkpResult};
+use crate::zk_auth::open_nullifier_proof;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const TOKEN_PREFIX: &str = "zkc1";
+/// Shorter keys are rejected by `ChallengeIssuer::new`.
+pub const MIN_KEY_BYTES: usize = 32;
+
+/// What the token commits to. Field names are short: it all goes into a QR.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeClaims {
+    #[serde(rename = "sid")]
+    pub session_id: String,
+    #[serde(rename = "dom")]
+    pub domain: String,
+    /// Unix seconds.
+    #[serde(rename = "exp")]
+    pub expires_at: u64,
+    pub nonce: String,
+}
+
+/// Why a login was refused. `as_str` is the stable reason code stored on the session.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectReason {
+    MalformedChallenge,
+    BadSignature,
+    ChallengeExpired,
+    SessionMismatch,
+    DomainMismatch,
+    ChallengeMismatch,
+    MalformedProof,
+    InvalidProof,
+}
+
+impl RejectReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::MalformedChallenge => "malformed_challenge",
+            Self::BadSignature => "bad_signature",
+            Self::ChallengeExpired => "challenge_expired",
+            Self::SessionMismatch => "session_mismatch",
+            Self::DomainMismatch => "domain_mismatch",
+            Self::ChallengeMismatch => "challenge_mismatch",
+            Self::MalformedProof => "malformed_proof",
+            Self::InvalidProof => "invalid_proof",
+        }
+    }
+}
+
+/// Result of `verify_login`. A refused login is a normal outcome, not an `Err`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoginOutcome {
+    Accepted { nullifier: u64, domain: String },
+    Rejected { reason: RejectReason, detail: String },
+}
+
+impl LoginOutcome {
+    fn rejected(reason: RejectReason, detail: impl Into<String>) -> Self {
+        Self::Rejected { reason, detail: detail.into() }
+    }
+}
+
+pub struct ChallengeIssuer {
+    key: Vec<u8>,
+    domain: String,
+    ttl: Duration,
+}
+
+impl ChallengeIssuer {
+    pub fn new(key: &[u8], domain: impl Into<String>, ttl: Duration) -> ZkpResult<Self> {
+        if key.len() < MIN_KEY_BYTES {
+            return Err(ZkpError::InvalidInput(format!("challenge key must be at least {} bytes", MIN_KEY_BYTES)));
+        }
+        let domain = domain.into();
+        if domain.trim().is_empty() {
+            return Err(ZkpError::InvalidInput("challenge domain must not be empty".into()));
+        }
+        Ok(Self { key: key.to_vec(), domain, ttl })
+    }
+
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    fn mac(&self, signed: &str) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.key).expect("HMAC accepts any key length");
+        mac.update(signed.as_bytes());
+        mac
+    }
+
+    /// New token for `session_id`, valid until `now + ttl`.
+    pub fn issue(&self, session_id: &str, now: u64) -> ZkpResult<(String, ChallengeClaims)> {
+        let mut nonce = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let expires_at = now
+            .checked_add(self.ttl.as_secs())
+            .ok_or_else(|| ZkpError::InvalidInput("challenge ttl overflows the expiry time".into()))?;
+        let claims = ChallengeClaims {
+            session_id: session_id.to_string(),
+            domain: self.domain.clone(),
+            expires_at,
+            nonce: hex::encode(nonce),
+        };
+        let payload = serde_json::to_vec(&claims).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+
+        let signed = format!("{}.{}", TOKEN_PREFIX, URL_SAFE_NO_PAD.encode(payload));
+        let tag = URL_SAFE_NO_PAD.encode(self.mac(&signed).finalize().into_bytes());
+        Ok((format!("{}.{}", signed, tag), claims))
+    }
+
+    /// Checks signature and expiry (signature first: unsigned claims are never trusted).
+    pub fn open(&self, token: &str, now: u64) -> Result<ChallengeClaims, (RejectReason, String)> {
+        let malformed = |what: &str| (RejectReason::MalformedChallenge, what.to_string());
+
+        let (signed, tag) = token.trim().rsplit_once('.').ok_or_else(|| malformed("missing signature"))?;
+        let (prefix, payload) = signed.split_once('.').ok_or_else(|| malformed("missing payload"))?;
+        if prefix != TOKEN_PREFIX {
+            return Err(malformed("unknown token version"));
+        }
+
+        let tag = URL_SAFE_NO_PAD.decode(tag).map_err(|_| malformed("signature is not base64url"))?;
+        self.mac(signed)
+            .verify_slice(&tag)
+            .map_err(|_| (RejectReason::BadSignature, "challenge signature does not verify".to_string()))?;
+
+        let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| malformed("payload is not base64url"))?;
+        let claims: ChallengeClaims = serde_json::from_slice(&payload).map_err(|_| malformed("payload is not claims JSON"))?;
+
+        if now >= claims.expires_at {
+            return Err((RejectReason::ChallengeExpired, format!("expired {}s ago", now - claims.expires_at)));
+        }
+        Ok(claims)
+    }
+
+    /// Full upload check for `session_id`, whose issued token is `token`:
+    /// token valid & fresh, proof valid, proof's PI_DOMAIN / PI_CHALLENGE match.
+    pub fn verify_login(
+        &self,
+        proof_data: &str,
+        token: &str,
+        session_id: &str,
+        circuits: &CircuitCache,
+        now: u64,
+    ) -> ZkpResult<LoginOutcome> {
+        let claims = match self.open(token, now) {
+            Ok(claims) => claims,
+            Err((reason, detail)) => return Ok(LoginOutcome::rejected(reason, detail)),
+        };
+        if claims.session_id != session_id {
+            return Ok(LoginOutcome::rejected(RejectReason::SessionMismatch, "token was issued for another session"));
+        }
+        if claims.domain != self.domain {
+            return Ok(LoginOutcome::rejected(RejectReason::DomainMismatch, "token was issued for another domain"));
+        }
+
+        let public = match open_nullifier_proof(proof_data, circuits) {
+            Ok(public) => public,
+            Err(ZkpError::ProofRejected(detail)) => {
+                return Ok(LoginOutcome::rejected(RejectReason::InvalidProof, detail))
+            }
+            Err(e @ (ZkpError::InvalidInput(_)
+            | ZkpError::InvalidEncoding(_)
+            | ZkpError::MalformedJson(_)
+            | ZkpError::CorruptProof(_))) => {
+                return Ok(LoginOutcome::rejected(RejectReason::MalformedProof, e.to_string()))
+            }
+            Err(e) => return Err(e),
+        };
+
+        if !public.commits_to_domain(&claims.domain) {
+            return Ok(LoginOutcome::rejected(RejectReason::DomainMismatch, "proof was made for another domain"));
+        }
+        if !public.commits_to_challenge(token) {
+            return Ok(LoginOutcome::rejected(RejectReason::ChallengeMismatch, "proof was made for another challenge"));
+        }
+
+        Ok(LoginOutcome::Accepted { nullifier: public.nullifier, domain: claims.domain })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zk_auth::prove_nullifier;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn issuer(domain: &str) -> ChallengeIssuer {
+        ChallengeIssuer::new(&[7; MIN_KEY_BYTES], domain, Duration::from_secs(300)).unwrap()
+    }
+
+    fn reason(result: Result<ChallengeClaims, (RejectReason, String)>) -> RejectReason {
+        result.unwrap_err().0
+    }
+
+    fn rejected(outcome: LoginOutcome) -> RejectReason {
+        match outcome {
+            LoginOutcome::Rejected { reason, .. } => reason,
+            accepted => panic!("expected a reject, got {:?}", accepted),
+        }
+    }
+
+    #[test]
+    fn issue_open_round_trip_and_expiry() {
+        let issuer = issuer("example.com");
+        let (token, claims) = issuer.issue("s1", NOW).unwrap();
+        assert!(token.starts_with("zkc1."));
+        assert_eq!(claims.expires_at, NOW + 300);
+        assert_eq!(issuer.open(&token, claims.expires_at - 1).unwrap(), claims);
+        assert_eq!(reason(issuer.open(&token, claims.expires_at)), RejectReason::ChallengeExpired);
+    }
+
+    #[test]
+    fn tampered_or_foreign_tokens_are_rejected() {
+        let issuer = issuer("example.com");
+        let (token, _) = issuer.issue("s1", NOW).unwrap();
+        let (_, payload, tag) = {
+            let mut parts = token.split('.');
+            (parts.next().unwrap(), parts.next().unwrap(), parts.next().unwrap())
+        };
+
+        // Doosre session ke claims, purana tag
+        let (other, _) = issuer.issue("s2", NOW).unwrap();
+        let other_payload = other.split('.').nth(1).unwrap();
+        assert_eq!(reason(issuer.open(&format!("zkc1.{}.{}", other_payload, tag), NOW)), RejectReason::BadSignature);
+        assert_eq!(reason(issuer.open(&format!("zkc2.{}.{}", payload, tag), NOW)), RejectReason::MalformedChallenge);
+        assert_eq!(reason(issuer.open(&format!("zkc1.{}", payload), NOW)), RejectReason::MalformedChallenge);
+
+        let other_key = ChallengeIssuer::new(&[8; MIN_KEY_BYTES], "example.com", Duration::from_secs(300)).unwrap();
+        assert_eq!(reason(other_key.open(&token, NOW)), RejectReason::BadSignature);
+    }
+
+    #[test]
+    fn ttl_overflow_is_an_error() {
+        let issuer = ChallengeIssuer::new(&[7; MIN_KEY_BYTES], "example.com", Duration::from_secs(u64::MAX)).unwrap();
+        assert!(matches!(issuer.issue("s1", NOW), Err(ZkpError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn session_and_domain_must_match() {
+        let circuits = CircuitCache::shared();
+        let relay = issuer("example.com");
+        let (token, _) = relay.issue("s1", NOW).unwrap();
+        assert_eq!(rejected(relay.verify_login("x|y", &token, "s2", circuits, NOW).unwrap()), RejectReason::SessionMismatch);
+
+        // Same key, doosra domain: token valid hai par is relay ka nahi
+        let (foreign, _) = issuer("evil.example").issue("s1", NOW).unwrap();
+        assert_eq!(rejected(relay.verify_login("x|y", &foreign, "s1", circuits, NOW).unwrap()), RejectReason::DomainMismatch);
+
+        let proof = prove_nullifier("secret", "evil.example", &token).unwrap();
+        assert_eq!(rejected(relay.verify_login(&proof, &token, "s1", circuits, NOW).unwrap()), RejectReason::DomainMismatch);
+    }
+
+    #[test]
+    fn proof_must_commit_to_this_token() {
+        let circuits = CircuitCache::shared();
+        let relay = issuer("example.com");
+        let (token, _) = relay.issue("s1", NOW).unwrap();
+        let (other, _) = relay.issue("s1", NOW).unwrap();
+
+        let proof = prove_nullifier("secret", "example.com", &other).unwrap();
+        assert_eq!(rejected(relay.verify_login(&proof, &token, "s1", circuits, NOW).unwrap()), RejectReason::ChallengeMismatch);
+
+        let proof = prove_nullifier("secret", "example.com", &token).unwrap();
+        match relay.verify_login(&proof, &token, "s1", circuits, NOW).unwrap() {
+            LoginOutcome::Accepted { domain, .. } => assert_eq!(domain, "example.com"),
+            rejected => panic!("{:?}", rejected),
+        }
+        assert_eq!(rejected(relay.verify_login(&proof, &token, "s1", circuits, NOW + 300).unwrap()), RejectReason::ChallengeExpired);
+        assert_eq!(rejected(relay.verify_login("not a proof", &token, "s1", circuits, NOW).unwrap()), RejectReason::MalformedProof);
+    }
+}
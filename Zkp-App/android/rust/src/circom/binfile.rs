@@ -0,0 +1,119 @@
+// iden3 "binfile" container (r1cs / wtns / zkey sab isi me):
+//   magic[4] | version u32 | n_sections u32 | { id u32 | len u64 | bytes }*
+// Sab integers little-endian.
+
+use std::collections::HashMap;
+
+use ark_bn254::Fr;
+use ark_ff::{BigInteger256, PrimeField};
+
+use crate::error::{ZkpError, ZkpResult};
+
+pub(crate) struct BinFile<'a> {
+    kind: &'static str,
+    pub(crate) version: u32,
+    sections: HashMap<u32, &'a [u8]>,
+}
+
+impl<'a> BinFile<'a> {
+    pub(crate) fn parse(bytes: &'a [u8], magic: &'static str) -> ZkpResult<Self> {
+        let mut r = Reader::new(bytes, magic);
+        if r.take(4)? != magic.as_bytes() {
+            return Err(ZkpError::InvalidInput(format!("not a .{} file (bad magic)", magic)));
+        }
+        let version = r.u32()?;
+        let count = r.u32()?;
+
+        // Duplicate ids nahi hone chahiye; pehla wala rakhte hain (snarkjs jaisa)
+        let mut sections = HashMap::new();
+        for _ in 0..count {
+            let id = r.u32()?;
+            let len = usize::try_from(r.u64()?).map_err(|_| r.error("section too large"))?;
+            sections.entry(id).or_insert(r.take(len)?);
+        }
+        Ok(Self { kind: magic, version, sections })
+    }
+
+    pub(crate) fn section(&self, id: u32) -> ZkpResult<Reader<'a>> {
+        self.sections
+            .get(&id)
+            .map(|bytes| Reader::new(bytes, self.kind))
+            .ok_or_else(|| ZkpError::InvalidInput(format!("{}: missing section {}", self.kind, id)))
+    }
+}
+
+/// Bounds-checked cursor over one section.
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    kind: &'static str,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8], kind: &'static str) -> Self {
+        Self { bytes, pos: 0, kind }
+    }
+
+    pub(crate) fn error(&self, what: &str) -> ZkpError {
+        ZkpError::InvalidInput(format!("{}: {} (offset {})", self.kind, what, self.pos))
+    }
+
+    /// Bytes left in this section.
+    pub(crate) fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> ZkpResult<&'a [u8]> {
+        let end = self.pos.checked_add(len).filter(|&end| end <= self.bytes.len());
+        let end = end.ok_or_else(|| self.error("unexpected end of file"))?;
+        let out = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    pub(crate) fn u32(&mut self) -> ZkpResult<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().expect("4 bytes")))
+    }
+
+    pub(crate) fn u64(&mut self) -> ZkpResult<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().expect("8 bytes")))
+    }
+
+    pub(crate) fn usize(&mut self) -> ZkpResult<usize> {
+        Ok(self.u32()? as usize)
+    }
+
+    /// 32-byte little-endian integer, exactly as stored.
+    pub(crate) fn bigint(&mut self) -> ZkpResult<BigInteger256> {
+        let bytes = self.take(32)?;
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().expect("8 bytes"));
+        }
+        Ok(BigInteger256::new(limbs))
+    }
+
+    /// Canonical (non-Montgomery) scalar, as in .r1cs / .wtns.
+    pub(crate) fn fr(&mut self) -> ZkpResult<Fr> {
+        let value = self.bigint()?;
+        Fr::from_bigint(value).ok_or_else(|| self.error("value is not below the BN254 scalar modulus"))
+    }
+
+    /// `n8 | prime` header shared by r1cs and wtns; only BN254 (circom default) is supported.
+    pub(crate) fn expect_bn254_prime(&mut self) -> ZkpResult<()> {
+        let n8 = self.u32()?;
+        if n8 != 32 || self.bigint()? != Fr::MODULUS {
+            return Err(self.error("field is not BN254 (compile the circuit with --prime bn128)"));
+        }
+        Ok(())
+    }
+}
+
+/// Canonical scalar -> 32 LE bytes (for writing .wtns).
+pub(crate) fn fr_bytes(value: &Fr) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for (chunk, limb) in out.chunks_exact_mut(8).zip(value.into_bigint().0) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    out
+}
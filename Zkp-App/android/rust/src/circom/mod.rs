@@ -0,0 +1,234 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ⭕ CIRCOM PROVER (feature = "circom")
+// PIN / age / identity / Merkle Circom circuits ka Groth16 proof seedha phone par,
+// bina Node/snarkjs ke. Inputs wahi files jo circom + snarkjs banate hain:
+//   • <circuit>_final.zkey        proving key (snarkjs groth16 setup + contribute)
+//   • <circuit>_js/<circuit>.wasm witness calculator (ya pehle se bana .wtns)
+//   • <circuit>.r1cs              optional: witness ko proving se pehle check karo
+// Output snarkjs jaisa hi proof.json + public.json, jo groth16.rs (aur snarkjs,
+// aur day18/verifier.sol) verify karte hain.
+// ═══════════════════════════════════════════════════════════════════════════
+
+mod binfile;
+mod qap;
+pub mod r1cs;
+pub mod witness;
+pub mod wtns;
+pub mod zkey;
+
+use ark_bn254::{Bn254, Fr};
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::Groth16;
+use num_bigint::BigUint;
+use serde::Serialize;
+
+use crate::error::{ZkpError, ZkpResult};
+use crate::groth16::{Groth16Verifier, SnarkjsProof, SnarkjsVerifyingKey};
+use self::qap::CircomReduction;
+use self::r1cs::R1cs;
+use self::witness::WitnessCalculator;
+use self::zkey::Zkey;
+
+/// `proof.json` + `public.json`, together.
+#[derive(Serialize, Debug, Clone)]
+pub struct CircomProof {
+    pub proof: SnarkjsProof,
+    pub public_signals: Vec<String>,
+}
+
+/// One Circom circuit ready to prove. Parse once; proving reuses the key.
+pub struct CircomProver {
+    zkey: Zkey,
+    verifier: Groth16Verifier,
+    r1cs: Option<R1cs>,
+}
+
+impl CircomProver {
+    pub fn from_zkey(zkey_bytes: &[u8]) -> ZkpResult<Self> {
+        let zkey = Zkey::from_bytes(zkey_bytes)?;
+        let verifier = Groth16Verifier::from_ark(zkey.verifying_key());
+        Ok(Self { zkey, verifier, r1cs: None })
+    }
+
+    /// Adds the circuit's `.r1cs` so bad witnesses fail with the constraint number.
+    pub fn with_r1cs(mut self, r1cs_bytes: &[u8]) -> ZkpResult<Self> {
+        let r1cs = R1cs::from_bytes(r1cs_bytes)?;
+        if r1cs.n_wires != self.zkey.n_vars || r1cs.n_public() != self.zkey.n_public {
+            return Err(ZkpError::InvalidInput("r1cs does not belong to this zkey".into()));
+        }
+        self.r1cs = Some(r1cs);
+        Ok(self)
+    }
+
+    /// The key snarkjs would export as `verification_key.json`.
+    pub fn verification_key(&self) -> SnarkjsVerifyingKey {
+        SnarkjsVerifyingKey::from_ark(self.zkey.verifying_key())
+    }
+
+    /// Full witness (`witness[0]` = 1, from a `.wtns` or `WitnessCalculator`) -> proof.
+    pub fn prove(&self, witness: &[Fr]) -> ZkpResult<CircomProof> {
+        if witness.len() != self.zkey.n_vars {
+            return Err(ZkpError::InvalidInput(format!(
+                "witness has {} values, zkey expects {}",
+                witness.len(),
+                self.zkey.n_vars
+            )));
+        }
+        if let Some(r1cs) = &self.r1cs {
+            r1cs.check_witness(witness)?;
+        }
+
+        let mut rng = rand::rngs::OsRng;
+        let (r, s) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+        let matrices = &self.zkey.matrices;
+        let proof = Groth16::<Bn254, CircomReduction>::create_proof_with_reduction_and_matrices(
+            &self.zkey.pk,
+            r,
+            s,
+            matrices,
+            matrices.num_instance_variables,
+            matrices.num_constraints,
+            witness,
+        )?;
+
+        let proof = SnarkjsProof::from_ark(&proof);
+        let public_signals: Vec<String> = witness[1..=self.zkey.n_public]
+            .iter()
+            .map(|v| BigUint::from(v.into_bigint()).to_string())
+            .collect();
+
+        // Bina r1cs ke galat witness bhi "proof" ban jaata hai: bahar jaane se pehle khud check
+        match self.verifier.verify(&proof, &public_signals) {
+            Ok(()) => Ok(CircomProof { proof, public_signals }),
+            Err(ZkpError::ProofRejected(_)) => {
+                Err(ZkpError::ProvingFailed("witness does not satisfy the circuit (proof failed self-check)".into()))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// `input.json` + circuit wasm -> proof (the whole snarkjs `fullprove`).
+    pub fn prove_input(&self, calculator: &mut WitnessCalculator, input_json: &str) -> ZkpResult<CircomProof> {
+        let witness = calculator.calculate(input_json)?;
+        self.prove(&witness)
+    }
+}
+
+/// One-shot `fullprove` for JNI / CLI: returns `{"proof": {...}, "public_signals": [...]}`.
+pub fn full_prove_json(zkey_bytes: &[u8], wasm_bytes: &[u8], input_json: &str) -> ZkpResult<String> {
+    let prover = CircomProver::from_zkey(zkey_bytes)?;
+    let mut calculator = WitnessCalculator::new(wasm_bytes)?;
+    let proof = prover.prove_input(&mut calculator, input_json)?;
+    serde_json::to_string(&proof).map_err(|e| ZkpError::Serialization(e.to_string()))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::jstring;
+    use log::info;
+
+    use crate::error::ZkpError;
+    use crate::jni_bridge::{guard, new_string, read_string};
+
+    /// Paths, not bytes: Kotlin copies the circuit assets to `filesDir` once.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Groth16_fullProve(
+        mut env: JNIEnv,
+        _class: JClass,
+        zkey_path: JString,
+        wasm_path: JString,
+        input_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let read = |path: &str| std::fs::read(path).map_err(|e| ZkpError::InvalidInput(format!("{}: {}", path, e)));
+            let zkey = read(&read_string(env, &zkey_path, "zkey_path")?)?;
+            let wasm = read(&read_string(env, &wasm_path, "wasm_path")?)?;
+            // ⚠️ input me PIN / DOB jaise secrets hain: log mat karna
+            let input = read_string(env, &input_json, "input_json")?;
+
+            let proof = super::full_prove_json(&zkey, &wasm, &input)?;
+            info!("✅ Circom Groth16 proof generated");
+            new_string(env, proof)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(day: &str, file: &str) -> Vec<u8> {
+        let path = format!("{}/../../../{}/{}", env!("CARGO_MANIFEST_DIR"), day, file);
+        std::fs::read(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+    }
+
+    const CIRCUITS: [(&str, &str); 2] = [("day14", "pin_check"), ("day18", "merkle")];
+
+    #[test]
+    fn snarkjs_files_agree_and_witness_satisfies_constraints() {
+        for (day, name) in CIRCUITS {
+            let r1cs = R1cs::from_bytes(&fixture(day, &format!("{}.r1cs", name))).unwrap();
+            let zkey = Zkey::from_bytes(&fixture(day, &format!("{}_final.zkey", name))).unwrap();
+            let witness = wtns::read_wtns(&fixture(day, "witness.wtns")).unwrap();
+            assert_eq!(wtns::write_wtns(&witness), fixture(day, "witness.wtns"));
+
+            // max_constraint - n_public sahi ginti deta hai
+            assert_eq!(zkey.matrices.num_constraints, r1cs.constraints.len(), "{}", day);
+            assert_eq!((zkey.n_vars, zkey.n_public), (r1cs.n_wires, r1cs.n_public()));
+            r1cs.check_witness(&witness).unwrap();
+
+            // zkey ki A/B rows r1cs ki A/B se match
+            let eval = |row: &[(Fr, usize)]| row.iter().fold(Fr::from(0u64), |acc, (c, w)| acc + *c * witness[*w]);
+            let lc = |lc: &r1cs::LinearCombination| lc.iter().fold(Fr::from(0u64), |acc, (w, c)| acc + *c * witness[*w]);
+            for (i, constraint) in r1cs.constraints.iter().enumerate() {
+                assert_eq!(eval(&zkey.matrices.a[i]), lc(&constraint.a), "{} A row {}", day, i);
+                assert_eq!(eval(&zkey.matrices.b[i]), lc(&constraint.b), "{} B row {}", day, i);
+            }
+
+            let mut broken = witness.clone();
+            let last = broken.len() - 1;
+            broken[last] += Fr::from(1u64);
+            assert!(r1cs.check_witness(&broken).is_err());
+        }
+    }
+
+    #[test]
+    fn proof_from_fixture_witness_verifies_with_snarkjs_key() {
+        let (day, name) = CIRCUITS[0];
+        let prover = CircomProver::from_zkey(&fixture(day, &format!("{}_final.zkey", name)))
+            .unwrap()
+            .with_r1cs(&fixture(day, &format!("{}.r1cs", name)))
+            .unwrap();
+        let witness = wtns::read_wtns(&fixture(day, "witness.wtns")).unwrap();
+        let proof = prover.prove(&witness).unwrap();
+
+        let public: Vec<String> = serde_json::from_slice(&fixture(day, "public.json")).unwrap();
+        assert_eq!(proof.public_signals, public);
+        let vk = Groth16Verifier::from_json(std::str::from_utf8(&fixture(day, "verification_key.json")).unwrap()).unwrap();
+        vk.verify(&proof.proof, &proof.public_signals).unwrap();
+    }
+
+    #[test]
+    fn zkey_domain_size_must_match_h_section() {
+        let mut bytes = fixture("day14", "pin_check_final.zkey");
+        // Section 2 dhoondo: magic | version | count, phir { id | len u64 | bytes }*
+        let mut at = 12;
+        while u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap()) != 2 {
+            let len = u64::from_le_bytes(bytes[at + 4..at + 12].try_into().unwrap()) as usize;
+            at += 12 + len;
+        }
+        // n8q | q | n8r | r | n_vars | n_public | domain_size
+        let domain = at + 12 + 4 + 32 + 4 + 32 + 4 + 4;
+        bytes[domain..domain + 4].copy_from_slice(&(1u32 << 30).to_le_bytes());
+        let err = Zkey::from_bytes(&bytes).unwrap_err();
+        assert!(err.to_string().contains("domain size"), "{}", err);
+    }
+}
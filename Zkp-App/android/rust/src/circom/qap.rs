@@ -0,0 +1,69 @@
+// snarkjs ka R1CS -> QAP witness map (ark-circom `CircomReduction` jaisa).
+// arkworks H ko (AB - C) / Z se nikalta hai; snarkjs ki zkey me H points double
+// domain ke odd Lagrange basis me hain, isliye yahan (AB - C) ko coset
+// (omega_2n * domain) par evaluate karte hain. Galat reduction = valid-looking
+// proof jo verify nahi hoga.
+
+use ark_ff::PrimeField;
+use ark_groth16::r1cs_to_qap::{evaluate_constraint, LibsnarkReduction, R1CSToQAP};
+use ark_poly::EvaluationDomain;
+use ark_relations::r1cs::{ConstraintMatrices, ConstraintSystemRef, SynthesisError};
+
+pub(crate) struct CircomReduction;
+
+impl R1CSToQAP for CircomReduction {
+    #[allow(clippy::type_complexity)]
+    fn instance_map_with_evaluation<F: PrimeField, D: EvaluationDomain<F>>(
+        cs: ConstraintSystemRef<F>,
+        t: &F,
+    ) -> Result<(Vec<F>, Vec<F>, Vec<F>, F, usize, usize), SynthesisError> {
+        // Sirf setup me use hota hai; hum snarkjs ki zkey use karte hain
+        LibsnarkReduction::instance_map_with_evaluation::<F, D>(cs, t)
+    }
+
+    fn witness_map_from_matrices<F: PrimeField, D: EvaluationDomain<F>>(
+        matrices: &ConstraintMatrices<F>,
+        num_inputs: usize,
+        num_constraints: usize,
+        full_assignment: &[F],
+    ) -> Result<Vec<F>, SynthesisError> {
+        let domain = D::new(num_constraints + num_inputs).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let size = domain.size();
+
+        let mut a = vec![F::zero(); size];
+        let mut b = vec![F::zero(); size];
+        let mut c = vec![F::zero(); size];
+        for (i, (a_row, b_row)) in matrices.a.iter().zip(&matrices.b).enumerate().take(num_constraints) {
+            a[i] = evaluate_constraint(a_row, full_assignment);
+            b[i] = evaluate_constraint(b_row, full_assignment);
+            c[i] = a[i] * b[i];
+        }
+        // Public inputs ke extra rows: A[n + i] = w[i]
+        a[num_constraints..num_constraints + num_inputs].copy_from_slice(&full_assignment[..num_inputs]);
+
+        let omega_2n = D::new(2 * size).ok_or(SynthesisError::PolynomialDegreeTooLarge)?.element(1);
+        let to_coset = |values: &mut Vec<F>| {
+            domain.ifft_in_place(values);
+            D::distribute_powers_and_mul_by_const(values, omega_2n, F::one());
+            domain.fft_in_place(values);
+        };
+        to_coset(&mut a);
+        to_coset(&mut b);
+        to_coset(&mut c);
+
+        Ok(a.iter().zip(&b).zip(&c).map(|((a, b), c)| *a * b - c).collect())
+    }
+
+    fn h_query_scalars<F: PrimeField, D: EvaluationDomain<F>>(
+        max_power: usize,
+        t: F,
+        _: F,
+        delta_inverse: F,
+    ) -> Result<Vec<F>, SynthesisError> {
+        // Setup-only (zkey already has H); kept consistent with the witness map above
+        let mut scalars: Vec<F> = (0..2 * max_power + 1).map(|i| delta_inverse * t.pow([i as u64])).collect();
+        let domain = D::new(scalars.len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        domain.ifft_in_place(&mut scalars);
+        Ok(scalars.into_iter().skip(1).step_by(2).collect())
+    }
+}
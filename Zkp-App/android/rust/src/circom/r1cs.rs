@@ -0,0 +1,117 @@
+// `.r1cs` (circom --r1cs), version 1:
+//   1 header:      n8 | prime | n_wires | n_pub_out | n_pub_in | n_prv_in | n_labels u64 | n_constraints
+//   2 constraints: har constraint A, B, C = { n u32 | (wire u32, coeff)* }
+//   3 wire2label:  u64 per wire (optional)
+// Prover ko iski zaroorat nahi (zkey me matrices hain); yeh witness ko proving se
+// pehle check karne ke liye hai, taaki "witness galat hai" ka saaf error mile.
+
+use ark_bn254::Fr;
+use ark_ff::Zero;
+
+use super::binfile::{BinFile, Reader};
+use crate::error::{ZkpError, ZkpResult};
+
+/// Sparse row: (wire index, coefficient).
+pub type LinearCombination = Vec<(usize, Fr)>;
+
+/// One `A * B = C` constraint.
+#[derive(Debug, Clone)]
+pub struct Constraint {
+    pub a: LinearCombination,
+    pub b: LinearCombination,
+    pub c: LinearCombination,
+}
+
+/// A parsed circom constraint system (BN254 only).
+#[derive(Debug, Clone)]
+pub struct R1cs {
+    pub n_wires: usize,
+    pub n_pub_out: usize,
+    pub n_pub_in: usize,
+    pub n_prv_in: usize,
+    pub n_labels: u64,
+    pub constraints: Vec<Constraint>,
+    /// Empty when the file has no wire2label section.
+    pub wire_to_label: Vec<u64>,
+}
+
+impl R1cs {
+    pub fn from_bytes(bytes: &[u8]) -> ZkpResult<Self> {
+        let file = BinFile::parse(bytes, "r1cs")?;
+        if file.version != 1 {
+            return Err(ZkpError::InvalidInput(format!("r1cs: unsupported version {}", file.version)));
+        }
+
+        let mut header = file.section(1)?;
+        header.expect_bn254_prime()?;
+        let n_wires = header.usize()?;
+        let n_pub_out = header.usize()?;
+        let n_pub_in = header.usize()?;
+        let n_prv_in = header.usize()?;
+        let n_labels = header.u64()?;
+        let n_constraints = header.usize()?;
+        // Wire 0 is the constant 1; inputs/outputs must fit after it
+        let n_signals = n_pub_out.saturating_add(n_pub_in).saturating_add(n_prv_in);
+        if n_wires == 0 || n_signals >= n_wires {
+            return Err(ZkpError::InvalidInput(format!(
+                "r1cs: {} wires can't hold the constant wire plus {} signals",
+                n_wires, n_signals
+            )));
+        }
+
+        let mut body = file.section(2)?;
+        let mut constraints = Vec::with_capacity(n_constraints.min(1 << 20));
+        for _ in 0..n_constraints {
+            constraints.push(Constraint {
+                a: read_lc(&mut body, n_wires)?,
+                b: read_lc(&mut body, n_wires)?,
+                c: read_lc(&mut body, n_wires)?,
+            });
+        }
+
+        let wire_to_label = match file.section(3) {
+            Ok(mut labels) => (0..n_wires).map(|_| labels.u64()).collect::<ZkpResult<_>>()?,
+            Err(_) => Vec::new(),
+        };
+
+        Ok(Self { n_wires, n_pub_out, n_pub_in, n_prv_in, n_labels, constraints, wire_to_label })
+    }
+
+    /// Public signals = outputs + public inputs (wires 1..=n_public).
+    pub fn n_public(&self) -> usize {
+        self.n_pub_out + self.n_pub_in
+    }
+
+    /// Checks every constraint; the error names the first one that fails.
+    pub fn check_witness(&self, witness: &[Fr]) -> ZkpResult<()> {
+        if witness.len() != self.n_wires {
+            return Err(ZkpError::InvalidInput(format!(
+                "witness has {} values, circuit has {} wires",
+                witness.len(),
+                self.n_wires
+            )));
+        }
+        if witness[0] != Fr::from(1u64) {
+            return Err(ZkpError::InvalidInput("witness[0] must be 1".into()));
+        }
+
+        let eval = |lc: &LinearCombination| lc.iter().fold(Fr::zero(), |acc, (wire, coeff)| acc + *coeff * witness[*wire]);
+        match self.constraints.iter().position(|c| eval(&c.a) * eval(&c.b) != eval(&c.c)) {
+            None => Ok(()),
+            Some(i) => Err(ZkpError::ProvingFailed(format!("witness does not satisfy constraint #{}", i))),
+        }
+    }
+}
+
+fn read_lc(r: &mut Reader<'_>, n_wires: usize) -> ZkpResult<LinearCombination> {
+    let n = r.usize()?;
+    let mut lc = Vec::with_capacity(n.min(n_wires));
+    for _ in 0..n {
+        let wire = r.usize()?;
+        if wire >= n_wires {
+            return Err(r.error("wire index out of range"));
+        }
+        lc.push((wire, r.fr()?));
+    }
+    Ok(lc)
+}
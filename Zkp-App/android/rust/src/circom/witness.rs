@@ -0,0 +1,268 @@
+// Circom witness calculator (`<circuit>_js/<circuit>.wasm`) bina Node / JS ke.
+// circom 2.x ka generated wasm `wasmi` (pure-Rust interpreter, NDK safe) me chalta
+// hai; yeh witness_calculator.js ka Rust port hai:
+//   • input JSON -> "a.b[1]" style flat names -> FNV-1a hash -> setInputSignal
+//   • values shared RW memory se 32-bit LE limbs me aate/jaate hain
+//   • `runtime.exceptionHandler` = circuit assert fail -> ProvingFailed
+
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
+use num_bigint::{BigInt, BigUint, Sign};
+use serde_json::Value;
+use wasmi::{AsContextMut, Caller, Engine, Extern, Instance, Linker, Module, Store};
+
+use crate::error::{ZkpError, ZkpResult};
+
+/// Circom 2.x runtime error codes (exceptionHandler).
+fn exception_message(code: i32) -> &'static str {
+    match code {
+        1 => "signal not found",
+        2 => "too many signals set",
+        3 => "signal already set",
+        4 => "assert failed",
+        5 => "not enough memory",
+        6 => "input signal array access exceeds the size",
+        _ => "unknown error",
+    }
+}
+
+fn wasm_err(e: impl std::fmt::Display) -> ZkpError {
+    ZkpError::InvalidInput(format!("circuit wasm: {}", e))
+}
+
+#[derive(Default)]
+struct Host {
+    errors: String,
+}
+
+/// A loaded circuit wasm; reuse it for many witnesses.
+pub struct WitnessCalculator {
+    store: Store<Host>,
+    instance: Instance,
+    n32: usize,
+    witness_size: usize,
+}
+
+impl WitnessCalculator {
+    pub fn new(wasm: &[u8]) -> ZkpResult<Self> {
+        let engine = Engine::default();
+        let module = Module::new(&engine, wasm).map_err(wasm_err)?;
+        let mut store = Store::new(&engine, Host::default());
+        let mut linker = Linker::<Host>::new(&engine);
+
+        linker
+            .func_wrap("runtime", "exceptionHandler", |caller: Caller<'_, Host>, code: i32| -> Result<(), wasmi::Error> {
+                let detail = caller.data().errors.trim();
+                let message = if detail.is_empty() {
+                    exception_message(code).to_string()
+                } else {
+                    format!("{}: {}", exception_message(code), detail)
+                };
+                Err(wasmi::Error::new(message))
+            })
+            .map_err(wasm_err)?;
+        linker
+            .func_wrap("runtime", "printErrorMessage", |mut caller: Caller<'_, Host>| {
+                let message = read_message(&mut caller);
+                caller.data_mut().errors.push_str(&message);
+                caller.data_mut().errors.push('\n');
+            })
+            .map_err(wasm_err)?;
+        // log() output: debug only, circuit ka result isse nahi badalta
+        linker
+            .func_wrap("runtime", "writeBufferMessage", |mut caller: Caller<'_, Host>| {
+                let message = read_message(&mut caller);
+                log::debug!("circom: {}", message);
+            })
+            .map_err(wasm_err)?;
+        linker.func_wrap("runtime", "showSharedRWMemory", |_: Caller<'_, Host>| {}).map_err(wasm_err)?;
+
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .and_then(|pre| pre.start(&mut store))
+            .map_err(wasm_err)?;
+
+        let mut calc = Self { store, instance, n32: 0, witness_size: 0 };
+        let version = calc.call0("getVersion")?;
+        if version != 2 {
+            return Err(ZkpError::InvalidInput(format!("circuit wasm: circom version {} (need 2.x)", version)));
+        }
+        calc.n32 = calc.call0("getFieldNumLen32")? as usize;
+        calc.call("getRawPrime", &[])?;
+        if calc.n32 != 8 || calc.read_shared()? != BigUint::from(Fr::MODULUS) {
+            return Err(ZkpError::InvalidInput("circuit wasm: field is not BN254 (compile with --prime bn128)".into()));
+        }
+        calc.witness_size = calc.call0("getWitnessSize")? as usize;
+        Ok(calc)
+    }
+
+    /// Number of values `calculate` returns (`witness[0]` = 1).
+    pub fn witness_size(&self) -> usize {
+        self.witness_size
+    }
+
+    /// `input.json` (same format as snarkjs) -> full witness.
+    pub fn calculate(&mut self, input_json: &str) -> ZkpResult<Vec<Fr>> {
+        let input: Value = serde_json::from_str(input_json)?;
+        let mut signals = Vec::new();
+        flatten("", &input, &mut signals)?;
+
+        self.store.data_mut().errors.clear();
+        self.call("init", &[wasmi::Val::I32(0)])?;
+
+        let mut set = 0usize;
+        for (name, values) in &signals {
+            let hash = fnv1a(name);
+            let (msb, lsb) = ((hash >> 32) as u32 as i32, hash as u32 as i32);
+            let size = self.call1("getInputSignalSize", &[msb.into(), lsb.into()])?;
+            if size < 0 {
+                return Err(ZkpError::InvalidInput(format!("input signal '{}' not found", name)));
+            }
+            if values.len() != size as usize {
+                return Err(ZkpError::InvalidInput(format!(
+                    "input signal '{}' needs {} values, got {}",
+                    name,
+                    size,
+                    values.len()
+                )));
+            }
+            for (i, value) in values.iter().enumerate() {
+                self.write_shared(value)?;
+                self.call("setInputSignal", &[msb.into(), lsb.into(), (i as i32).into()])?;
+                set += 1;
+            }
+        }
+
+        let expected = self.call0("getInputSize")? as usize;
+        if set < expected {
+            return Err(ZkpError::InvalidInput(format!("only {} of {} input values set", set, expected)));
+        }
+
+        (0..self.witness_size)
+            .map(|i| {
+                self.call("getWitness", &[(i as i32).into()])?;
+                let value = self.read_shared()?;
+                Ok(Fr::from_le_bytes_mod_order(&value.to_bytes_le()))
+            })
+            .collect()
+    }
+
+    fn call(&mut self, name: &str, args: &[wasmi::Val]) -> ZkpResult<Option<i32>> {
+        let func = self
+            .instance
+            .get_func(&self.store, name)
+            .ok_or_else(|| ZkpError::InvalidInput(format!("circuit wasm: missing export '{}'", name)))?;
+        let mut out = [wasmi::Val::I32(0)];
+        let results = if func.ty(&self.store).results().is_empty() { &mut out[..0] } else { &mut out[..] };
+        func.call(&mut self.store, args, results)
+            .map_err(|e| ZkpError::ProvingFailed(format!("witness: {}", e)))?;
+        Ok(results.first().and_then(|v| v.i32()))
+    }
+
+    fn call0(&mut self, name: &str) -> ZkpResult<i32> {
+        self.call1(name, &[])
+    }
+
+    fn call1(&mut self, name: &str, args: &[wasmi::Val]) -> ZkpResult<i32> {
+        self.call(name, args)?
+            .ok_or_else(|| ZkpError::InvalidInput(format!("circuit wasm: '{}' returns nothing", name)))
+    }
+
+    fn read_shared(&mut self) -> ZkpResult<BigUint> {
+        let mut limbs = Vec::with_capacity(self.n32);
+        for j in 0..self.n32 {
+            limbs.push(self.call1("readSharedRWMemory", &[(j as i32).into()])? as u32);
+        }
+        Ok(BigUint::new(limbs))
+    }
+
+    fn write_shared(&mut self, value: &Fr) -> ZkpResult<()> {
+        let bytes = value.into_bigint().to_bytes_le();
+        for (j, chunk) in bytes.chunks(4).take(self.n32).enumerate() {
+            let word = u32::from_le_bytes(chunk.try_into().expect("4 bytes"));
+            self.call("writeSharedRWMemory", &[(j as i32).into(), (word as i32).into()])?;
+        }
+        Ok(())
+    }
+}
+
+/// Drains `getMessageChar` until NUL (called from inside host functions).
+fn read_message(caller: &mut Caller<'_, Host>) -> String {
+    let Some(Extern::Func(func)) = caller.get_export("getMessageChar") else {
+        return String::new();
+    };
+    let Ok(func) = func.typed::<(), i32>(caller.as_context_mut()) else {
+        return String::new();
+    };
+    let mut message = String::new();
+    while let Ok(c) = func.call(caller.as_context_mut(), ()) {
+        if c == 0 {
+            break;
+        }
+        message.push(char::from(c as u8));
+    }
+    message
+}
+
+/// 64-bit FNV-1a, the hash circom uses for signal names.
+fn fnv1a(name: &str) -> u64 {
+    name.bytes().fold(0xCBF2_9CE4_8422_2325u64, |hash, b| (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01B3))
+}
+
+/// witness_calculator.js `qualify_input`: nested objects -> "a.b", arrays of
+/// objects -> "a[i]", arrays of numbers stay one (flattened) signal.
+fn flatten(prefix: &str, value: &Value, out: &mut Vec<(String, Vec<Fr>)>) -> ZkpResult<()> {
+    match value {
+        Value::Object(map) => {
+            for (key, inner) in map {
+                let name = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten(&name, inner, out)?;
+            }
+        }
+        Value::Array(items) if leaves(value).first().is_some_and(|v| v.is_object()) => {
+            for (i, inner) in items.iter().enumerate() {
+                flatten(&format!("{}[{}]", prefix, i), inner, out)?;
+            }
+        }
+        _ => {
+            let values = leaves(value).into_iter().map(|v| scalar(prefix, v)).collect::<ZkpResult<_>>()?;
+            out.push((prefix.to_string(), values));
+        }
+    }
+    Ok(())
+}
+
+fn leaves(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().flat_map(leaves).collect(),
+        other => vec![other],
+    }
+}
+
+/// JS `BigInt(v) mod p`: decimal / "0x" hex strings, integers, booleans; negatives wrap.
+fn scalar(name: &str, value: &Value) -> ZkpResult<Fr> {
+    let bad = || ZkpError::InvalidInput(format!("input signal '{}': {} is not an integer", name, value));
+    let n: BigInt = match value {
+        Value::String(s) => {
+            let s = s.trim();
+            let (negative, digits) = s.strip_prefix('-').map_or((false, s), |rest| (true, rest));
+            let parsed = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+                Some(hex) => BigInt::parse_bytes(hex.as_bytes(), 16),
+                None => BigInt::parse_bytes(digits.as_bytes(), 10),
+            };
+            let n = parsed.ok_or_else(bad)?;
+            if negative { -n } else { n }
+        }
+        Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => BigInt::from(i),
+            (None, Some(u)) => BigInt::from(u),
+            _ => return Err(bad()),
+        },
+        Value::Bool(b) => BigInt::from(*b as u8),
+        _ => return Err(bad()),
+    };
+
+    let (sign, magnitude) = n.into_parts();
+    let value = Fr::from_le_bytes_mod_order(&magnitude.to_bytes_le());
+    Ok(if sign == Sign::Minus { -value } else { value })
+}
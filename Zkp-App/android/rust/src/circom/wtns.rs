@@ -0,0 +1,47 @@
+// `.wtns` (snarkjs wtns calculate / generate_witness.js), version 2:
+//   1 header: n8 | prime | n_witness
+//   2 values: n_witness * n8 bytes, canonical little-endian
+
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use super::binfile::{fr_bytes, BinFile};
+use crate::error::{ZkpError, ZkpResult};
+
+/// Reads a witness file; `witness[0]` is always the constant 1.
+pub fn read_wtns(bytes: &[u8]) -> ZkpResult<Vec<Fr>> {
+    let file = BinFile::parse(bytes, "wtns")?;
+    if file.version != 2 {
+        return Err(ZkpError::InvalidInput(format!("wtns: unsupported version {}", file.version)));
+    }
+
+    let mut header = file.section(1)?;
+    header.expect_bn254_prime()?;
+    let n = header.usize()?;
+
+    let mut values = file.section(2)?;
+    (0..n).map(|_| values.fr()).collect()
+}
+
+/// Writes the same format back (byte-identical to snarkjs for BN254).
+pub fn write_wtns(witness: &[Fr]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(12 + 12 + 40 + 12 + witness.len() * 32);
+    out.extend_from_slice(b"wtns");
+    out.extend_from_slice(&2u32.to_le_bytes());
+    out.extend_from_slice(&2u32.to_le_bytes());
+
+    out.extend_from_slice(&1u32.to_le_bytes());
+    out.extend_from_slice(&(4u64 + 32 + 4).to_le_bytes());
+    out.extend_from_slice(&32u32.to_le_bytes());
+    for limb in Fr::MODULUS.0 {
+        out.extend_from_slice(&limb.to_le_bytes());
+    }
+    out.extend_from_slice(&(witness.len() as u32).to_le_bytes());
+
+    out.extend_from_slice(&2u32.to_le_bytes());
+    out.extend_from_slice(&(witness.len() as u64 * 32).to_le_bytes());
+    for value in witness {
+        out.extend_from_slice(&fr_bytes(value));
+    }
+    out
+}
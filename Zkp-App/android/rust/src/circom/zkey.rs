@@ -0,0 +1,190 @@
+// snarkjs Groth16 `.zkey` -> arkworks ProvingKey + A/B matrices.
+//   1 header (prover = 1 groth16)    5 A points   (G1 * n_vars)
+//   2 groth16 header + vk points     6 B1 points  (G1 * n_vars)
+//   3 IC (G1 * (n_public + 1))       7 B2 points  (G2 * n_vars)
+//   4 A/B coefficients               8 C points   (G1 * private vars)
+//                                    9 H points   (G1 * domain_size)
+// Points Montgomery form me stored hain (new_unchecked, no R multiply); coefficients
+// double-Montgomery (x * R^2), isliye do baar unwrap. Same as ark-circom.
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger256, PrimeField, Zero};
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_relations::r1cs::ConstraintMatrices;
+
+use super::binfile::{BinFile, Reader};
+use crate::error::ZkpResult;
+
+/// Uncompressed G1 point: x | y, 32 bytes each.
+const G1_BYTES: usize = 64;
+
+/// Everything the prover needs from a `.zkey`.
+pub struct Zkey {
+    pub(crate) pk: ProvingKey<Bn254>,
+    pub(crate) matrices: ConstraintMatrices<Fr>,
+    pub n_vars: usize,
+    pub n_public: usize,
+    pub domain_size: usize,
+}
+
+impl Zkey {
+    pub fn from_bytes(bytes: &[u8]) -> ZkpResult<Self> {
+        let file = BinFile::parse(bytes, "zkey")?;
+
+        let mut header = file.section(1)?;
+        if header.u32()? != 1 {
+            return Err(header.error("not a Groth16 zkey"));
+        }
+
+        let mut groth = file.section(2)?;
+        expect_modulus(&mut groth, Fq::MODULUS, "base field is not BN254")?;
+        expect_modulus(&mut groth, Fr::MODULUS, "scalar field is not BN254")?;
+        let n_vars = groth.usize()?;
+        let n_public = groth.usize()?;
+        let domain_size = groth.usize()?;
+        if n_public >= n_vars || !domain_size.is_power_of_two() {
+            return Err(groth.error("inconsistent header"));
+        }
+        // domain_size file se aata hai: H section (G1 * domain_size) utna bada hona chahiye,
+        // warna koi bhi header se GBs allocate karwa deta
+        if domain_size.checked_mul(G1_BYTES) != Some(file.section(9)?.remaining()) {
+            return Err(groth.error("domain size does not match the H section"));
+        }
+        let alpha_g1 = g1(&mut groth)?;
+        let beta_g1 = g1(&mut groth)?;
+        let beta_g2 = g2(&mut groth)?;
+        let gamma_g2 = g2(&mut groth)?;
+        let delta_g1 = g1(&mut groth)?;
+        let delta_g2 = g2(&mut groth)?;
+
+        let vk = VerifyingKey { alpha_g1, beta_g2, gamma_g2, delta_g2, gamma_abc_g1: g1_vec(&file, 3, n_public + 1)? };
+        let pk = ProvingKey {
+            vk,
+            beta_g1,
+            delta_g1,
+            a_query: g1_vec(&file, 5, n_vars)?,
+            b_g1_query: g1_vec(&file, 6, n_vars)?,
+            b_g2_query: g2_vec(&file, 7, n_vars)?,
+            l_query: g1_vec(&file, 8, n_vars - n_public - 1)?,
+            h_query: g1_vec(&file, 9, domain_size)?,
+        };
+
+        let matrices = read_matrices(&file, n_vars, n_public, domain_size)?;
+        Ok(Self { pk, matrices, n_vars, n_public, domain_size })
+    }
+
+    /// The verifying key embedded in the zkey (same as `verification_key.json`).
+    pub fn verifying_key(&self) -> &VerifyingKey<Bn254> {
+        &self.pk.vk
+    }
+}
+
+/// Section 4: `n | (matrix u32, constraint u32, signal u32, coeff)*`. snarkjs yahan
+/// public inputs ke liye `n_public + 1` extra A-rows add karta hai; arkworks ka
+/// reduction unhe khud add karta hai, isliye truncate.
+fn read_matrices(file: &BinFile<'_>, n_vars: usize, n_public: usize, domain_size: usize) -> ZkpResult<ConstraintMatrices<Fr>> {
+    let mut r = file.section(4)?;
+    let n_coeffs = r.usize()?;
+
+    // Rows jitni zaroorat ho utni hi badhti hain
+    let mut rows: [Vec<Vec<(Fr, usize)>>; 2] = [Vec::new(), Vec::new()];
+    let mut max_constraint = 0;
+    for _ in 0..n_coeffs {
+        let matrix = r.usize()?;
+        let constraint = r.usize()?;
+        let signal = r.usize()?;
+        let bits = r.bigint()?;
+        if matrix > 1 || constraint >= domain_size || signal >= n_vars || bits >= Fr::MODULUS {
+            return Err(r.error("coefficient out of range"));
+        }
+        let value = Fr::new_unchecked(Fr::new_unchecked(bits).into_bigint());
+        max_constraint = max_constraint.max(constraint);
+        let matrix = &mut rows[matrix];
+        if matrix.len() <= constraint {
+            matrix.resize(constraint + 1, Vec::new());
+        }
+        matrix[constraint].push((value, signal));
+    }
+
+    let num_constraints = max_constraint
+        .checked_sub(n_public)
+        .ok_or_else(|| r.error("fewer constraints than public inputs"))?;
+    let [mut a, mut b] = rows;
+    a.resize(num_constraints, Vec::new());
+    b.resize(num_constraints, Vec::new());
+
+    Ok(ConstraintMatrices {
+        num_instance_variables: n_public + 1,
+        num_witness_variables: n_vars - n_public,
+        num_constraints,
+        a_num_non_zero: a.iter().map(Vec::len).sum(),
+        b_num_non_zero: b.iter().map(Vec::len).sum(),
+        c_num_non_zero: 0,
+        a,
+        b,
+        c: Vec::new(),
+    })
+}
+
+fn expect_modulus(r: &mut Reader<'_>, modulus: BigInteger256, what: &str) -> ZkpResult<()> {
+    if r.u32()? != 32 || r.bigint()? != modulus {
+        return Err(r.error(what));
+    }
+    Ok(())
+}
+
+fn fq(r: &mut Reader<'_>) -> ZkpResult<Fq> {
+    let bits = r.bigint()?;
+    if bits >= Fq::MODULUS {
+        return Err(r.error("coordinate out of range"));
+    }
+    Ok(Fq::new_unchecked(bits))
+}
+
+/// (0, 0) = infinity; otherwise the point must be on the curve (BN254 G1 cofactor = 1).
+fn g1(r: &mut Reader<'_>) -> ZkpResult<G1Affine> {
+    let (x, y) = (fq(r)?, fq(r)?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+    let point = G1Affine::new_unchecked(x, y);
+    if !point.is_on_curve() {
+        return Err(r.error("G1 point not on curve"));
+    }
+    Ok(point)
+}
+
+fn g2(r: &mut Reader<'_>) -> ZkpResult<G2Affine> {
+    let x = Fq2::new(fq(r)?, fq(r)?);
+    let y = Fq2::new(fq(r)?, fq(r)?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2Affine::zero());
+    }
+    let point = G2Affine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(r.error("invalid G2 point"));
+    }
+    Ok(point)
+}
+
+fn g1_vec(file: &BinFile<'_>, id: u32, n: usize) -> ZkpResult<Vec<G1Affine>> {
+    let mut r = file.section(id)?;
+    (0..n).map(|_| g1(&mut r)).collect()
+}
+
+fn g2_vec(file: &BinFile<'_>, id: u32, n: usize) -> ZkpResult<Vec<G2Affine>> {
+    let mut r = file.section(id)?;
+    (0..n).map(|_| g2(&mut r)).collect()
+}
+
+impl std::fmt::Debug for Zkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Zkey")
+            .field("n_vars", &self.n_vars)
+            .field("n_public", &self.n_public)
+            .field("domain_size", &self.domain_size)
+            .field("num_constraints", &self.matrices.num_constraints)
+            .finish()
+    }
+}
@@ -0,0 +1,526 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🪪 ISSUER-SIGNED CREDENTIALS
+// Issuer (bank / govt) attributes ko sign karta hai, holder unke baare me proof
+// banata hai. Ab balance "prover ka chuna hua hash" nahi, issuer-certified hai.
+//   • attribute commitment = Poseidon(name_hash, value, salt[4])  (salt private)
+//   • signed message       = Poseidon(schema_hash || 8 commitments)
+//                            (khaali slot = zero hash)
+//   • signature            = Schnorr over ecgfp5 (schnorr.rs)
+// Circuit me signature verify hota hai, issuer pk public input hai, aur holder
+// chune hue attributes hi reveal karta hai (selective disclosure). Baaki
+// attributes + salts + signature proof ke andar private rehte hain.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::{BTreeMap, HashSet};
+
+use base64::{Engine as _, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+
+use plonky2::field::types::{Field, PrimeField64};
+use plonky2::hash::hash_types::{HashOut, HashOutTarget};
+use plonky2::hash::poseidon::PoseidonHash;
+use plonky2::iop::target::{BoolTarget, Target};
+use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::circuit_data::{CircuitConfig, CircuitData};
+use plonky2::plonk::config::{GenericConfig, Hasher, PoseidonGoldilocksConfig};
+use plonky2::plonk::proof::ProofWithPublicInputs;
+
+use crate::ecgfp5::gadgets::{add_virtual_affine, AffinePointTarget};
+use crate::engine::CircuitCache;
+use crate::envelope::digest_hex;
+use crate::error::{ZkpError, ZkpResult};
+use crate::merkle::hex_hash;
+use crate::schnorr::{schnorr_gadget, set_public_key_target, PublicKey, SecretKey, Signature, SignatureTargets};
+use crate::zk_auth::hash_to_field;
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// Attribute slots per credential (fixed, so there is one circuit).
+pub const MAX_ATTRIBUTES: usize = 8;
+
+// Public input order (DO NOT CHANGE):
+// issuer pk (x[5], y[5]), schema hash, phir har slot: [revealed, name_hash, value]
+const PI_ISSUER: usize = 0;
+const PI_SCHEMA: usize = 10;
+const PI_SLOTS: usize = 11;
+const PI_SLOT_WIDTH: usize = 3;
+
+/// Numeric form of a text attribute (nationality, document type, ...).
+pub fn text_value(text: &str) -> u64 {
+    hash_to_field(text).to_canonical_u64()
+}
+
+pub(crate) fn field_value(name: &str, value: u64) -> ZkpResult<F> {
+    let element = F::from_noncanonical_u64(value);
+    if element.to_canonical_u64() != value {
+        return Err(ZkpError::InvalidInput(format!("attribute {} does not fit the field", name)));
+    }
+    Ok(element)
+}
+
+fn random_salt() -> HashOut<F> {
+    HashOut { elements: std::array::from_fn(|_| F::from_noncanonical_u64(rand::random())) }
+}
+
+/// One signed attribute. The salt keeps small values (age, balance) from being
+/// brute-forced out of the commitment.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Attribute {
+    pub name: String,
+    pub value: u64,
+    #[serde(with = "hex_hash")]
+    pub salt: HashOut<F>,
+}
+
+impl Attribute {
+    pub fn new(name: &str, value: u64) -> Self {
+        Self { name: name.to_string(), value, salt: random_salt() }
+    }
+
+    pub fn commitment(&self) -> ZkpResult<HashOut<F>> {
+        let mut inputs = vec![hash_to_field(&self.name), field_value(&self.name, self.value)?];
+        inputs.extend(self.salt.elements);
+        Ok(PoseidonHash::hash_no_pad(&inputs))
+    }
+}
+
+/// Transport form. JSON: `{"schema", "attributes": [{"name","value","salt"}],
+/// "issuer": pk hex, "signature": hex}`. Holder ke paas rehta hai (salts secret hain).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Credential {
+    pub schema: String,
+    pub attributes: Vec<Attribute>,
+    pub issuer: String,
+    pub signature: String,
+}
+
+impl Credential {
+    /// Issuer side: salts every attribute and signs the commitments.
+    pub fn issue(schema: &str, attributes: &[(&str, u64)], issuer: &SecretKey) -> ZkpResult<Self> {
+        let attributes: Vec<Attribute> = attributes.iter().map(|(name, value)| Attribute::new(name, *value)).collect();
+        let mut credential = Self {
+            schema: schema.to_string(),
+            attributes,
+            issuer: issuer.public_key().to_hex(),
+            signature: String::new(),
+        };
+        credential.signature = issuer.sign(&credential.message()?).to_hex();
+        Ok(credential)
+    }
+
+    /// The digest the issuer signs. Checks the attribute list shape too.
+    pub fn message(&self) -> ZkpResult<HashOut<F>> {
+        if self.attributes.len() > MAX_ATTRIBUTES {
+            return Err(ZkpError::InvalidInput(format!("at most {} attributes, got {}", MAX_ATTRIBUTES, self.attributes.len())));
+        }
+        let mut names = HashSet::new();
+        for attribute in &self.attributes {
+            if attribute.name.is_empty() || !names.insert(attribute.name.as_str()) {
+                return Err(ZkpError::InvalidInput(format!("attribute name {:?} is empty or repeated", attribute.name)));
+            }
+        }
+
+        let mut inputs = vec![hash_to_field(&self.schema)];
+        for slot in 0..MAX_ATTRIBUTES {
+            let commitment = match self.attributes.get(slot) {
+                Some(attribute) => attribute.commitment()?,
+                None => HashOut::ZERO,
+            };
+            inputs.extend(commitment.elements);
+        }
+        Ok(PoseidonHash::hash_no_pad(&inputs))
+    }
+
+    /// Key of this credential in a `RevocationRegistry`: Poseidon(message), so a
+    /// circuit can derive it from the signed attributes.
+    pub fn revocation_key(&self) -> ZkpResult<HashOut<F>> {
+        Ok(PoseidonHash::hash_no_pad(&self.message()?.elements))
+    }
+
+    pub fn issuer_key(&self) -> ZkpResult<PublicKey> {
+        PublicKey::from_hex(&self.issuer)
+    }
+
+    pub fn verify_signature(&self) -> ZkpResult<()> {
+        let signature = Signature::from_hex(&self.signature)?;
+        if !self.issuer_key()?.verify(&self.message()?, &signature) {
+            return Err(ZkpError::InvalidInput("credential signature does not verify".into()));
+        }
+        Ok(())
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|a| a.name == name)
+    }
+
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧠 CREDENTIAL GADGET + CIRCUIT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// One attribute slot inside the circuit. `value` is what later gadgets
+/// (range checks, policies) constrain.
+pub(crate) struct AttributeTargets {
+    pub(crate) present: BoolTarget,
+    pub(crate) name: Target,
+    pub(crate) value: Target,
+    salt: HashOutTarget,
+}
+
+/// "Issuer `issuer` signed a credential of `schema` with these slots".
+pub(crate) struct CredentialTargets {
+    pub(crate) issuer: AffinePointTarget,
+    pub(crate) schema: Target,
+    pub(crate) slots: Vec<AttributeTargets>,
+    /// The signed digest (`Credential::message`).
+    pub(crate) message: HashOutTarget,
+    signature: SignatureTargets,
+}
+
+impl CredentialTargets {
+    /// Fills issuer, slots and signature; the credential must already verify natively.
+    pub(crate) fn set_witness(&self, pw: &mut PartialWitness<F>, credential: &Credential) -> ZkpResult<()> {
+        credential.verify_signature()?;
+        set_public_key_target(pw, &self.issuer, &credential.issuer_key()?);
+        pw.set_target(self.schema, hash_to_field(&credential.schema));
+
+        for (slot, targets) in self.slots.iter().enumerate() {
+            match credential.attributes.get(slot) {
+                Some(attribute) => {
+                    pw.set_bool_target(targets.present, true);
+                    pw.set_target(targets.name, hash_to_field(&attribute.name));
+                    pw.set_target(targets.value, field_value(&attribute.name, attribute.value)?);
+                    pw.set_hash_target(targets.salt, attribute.salt);
+                }
+                None => {
+                    pw.set_bool_target(targets.present, false);
+                    pw.set_target(targets.name, F::ZERO);
+                    pw.set_target(targets.value, F::ZERO);
+                    pw.set_hash_target(targets.salt, HashOut::ZERO);
+                }
+            }
+        }
+        self.signature.set_witness(pw, &Signature::from_hex(&credential.signature)?);
+        Ok(())
+    }
+}
+
+/// Registers nothing public: callers decide what to expose. The issuer key
+/// MUST end up pinned by the verifier (see `schnorr_gadget`).
+pub(crate) fn credential_gadget(builder: &mut CircuitBuilder<F, D>) -> CredentialTargets {
+    let issuer = add_virtual_affine(builder);
+    let schema = builder.add_virtual_target();
+
+    let mut message_inputs = vec![schema];
+    let slots: Vec<AttributeTargets> = (0..MAX_ATTRIBUTES)
+        .map(|_| {
+            let present = builder.add_virtual_bool_target_safe();
+            let name = builder.add_virtual_target();
+            let value = builder.add_virtual_target();
+            let salt = builder.add_virtual_hash();
+
+            let mut inputs = vec![name, value];
+            inputs.extend(salt.elements);
+            let commitment = builder.hash_n_to_hash_no_pad::<PoseidonHash>(inputs);
+            // Khaali slot ka commitment zero hash hona chahiye (native `message` jaisa)
+            message_inputs.extend(commitment.elements.map(|c| builder.mul(present.target, c)));
+
+            AttributeTargets { present, name, value, salt }
+        })
+        .collect();
+
+    let message = builder.hash_n_to_hash_no_pad::<PoseidonHash>(message_inputs);
+    let signature = schnorr_gadget(builder, &issuer, message);
+
+    CredentialTargets { issuer, schema, slots, message, signature }
+}
+
+pub(crate) struct CredentialCircuit {
+    pub(crate) data: CircuitData<F, C, D>,
+    credential: CredentialTargets,
+    reveal: Vec<BoolTarget>,
+}
+
+impl CredentialCircuit {
+    pub(crate) fn build() -> Self {
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let credential = credential_gadget(&mut builder);
+        builder.register_public_inputs(&credential.issuer.elements());
+        builder.register_public_inputs(&[credential.schema]);
+
+        let mut reveal = Vec::with_capacity(MAX_ATTRIBUTES);
+        for slot in &credential.slots {
+            let revealed = builder.add_virtual_bool_target_safe();
+            // Sirf maujood attribute hi reveal ho sakta hai
+            let allowed = builder.and(revealed, slot.present);
+            builder.connect(allowed.target, revealed.target);
+
+            let name = builder.mul(revealed.target, slot.name);
+            let value = builder.mul(revealed.target, slot.value);
+            builder.register_public_inputs(&[revealed.target, name, value]);
+            reveal.push(revealed);
+        }
+
+        Self { data: builder.build::<C>(), credential, reveal }
+    }
+
+    pub(crate) fn prove(&self, credential: &Credential, reveal: &[&str]) -> ZkpResult<ProofWithPublicInputs<F, C, D>> {
+        for name in reveal {
+            if credential.attribute(name).is_none() {
+                return Err(ZkpError::InvalidInput(format!("credential has no attribute {:?}", name)));
+            }
+        }
+
+        let mut pw = PartialWitness::new();
+        self.credential.set_witness(&mut pw, credential)?;
+        for (slot, target) in self.reveal.iter().enumerate() {
+            let revealed = credential.attributes.get(slot).is_some_and(|a| reveal.contains(&a.name.as_str()));
+            pw.set_bool_target(*target, revealed);
+        }
+
+        self.data
+            .prove(pw)
+            .map_err(|e| ZkpError::ProvingFailed(e.to_string()))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📤 PRESENTATION (holder -> verifier)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// JSON: `{"issuer", "schema", "disclosed": {name: value}, "circuit_digest", "proof": base64}`.
+/// `issuer` / `schema` / `disclosed` are labels; verification re-reads them from the proof.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CredentialPresentation {
+    pub issuer: String,
+    pub schema: String,
+    pub disclosed: BTreeMap<String, u64>,
+    pub circuit_digest: String,
+    pub proof: String,
+}
+
+impl CredentialPresentation {
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+}
+
+/// Holder side: proves the credential is issuer-signed, revealing only `reveal`.
+pub fn prove_credential(credential: &Credential, reveal: &[&str], circuits: &CircuitCache) -> ZkpResult<CredentialPresentation> {
+    let circuit = circuits.credential();
+    let proof = circuit.prove(credential, reveal)?;
+
+    let disclosed = credential
+        .attributes
+        .iter()
+        .filter(|a| reveal.contains(&a.name.as_str()))
+        .map(|a| (a.name.clone(), a.value))
+        .collect();
+    let bytes = bincode::serialize(&proof).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+    Ok(CredentialPresentation {
+        issuer: credential.issuer.clone(),
+        schema: credential.schema.clone(),
+        disclosed,
+        circuit_digest: digest_hex(&circuit.data),
+        proof: general_purpose::STANDARD.encode(bytes),
+    })
+}
+
+/// Verifier side: issuer must be in `trusted_issuers` (pk hex), and the labels
+/// must match the proof's public inputs exactly. Returns the issuer key.
+pub fn verify_presentation(
+    presentation: &CredentialPresentation,
+    trusted_issuers: &[PublicKey],
+    circuits: &CircuitCache,
+) -> ZkpResult<PublicKey> {
+    let issuer = PublicKey::from_hex(&presentation.issuer)?;
+    if !trusted_issuers.contains(&issuer) {
+        return Err(ZkpError::ProofRejected("issuer is not trusted".into()));
+    }
+
+    let circuit = circuits.credential();
+    if presentation.circuit_digest != digest_hex(&circuit.data) {
+        return Err(ZkpError::ProofRejected("circuit digest mismatch".into()));
+    }
+
+    let bytes = general_purpose::STANDARD.decode(presentation.proof.trim())?;
+    let proof: ProofWithPublicInputs<F, C, D> =
+        bincode::deserialize(&bytes).map_err(|e| ZkpError::CorruptProof(e.to_string()))?;
+    let inputs = &proof.public_inputs;
+    if inputs.len() != PI_SLOTS + PI_SLOT_WIDTH * MAX_ATTRIBUTES {
+        return Err(ZkpError::CorruptProof("credential public inputs missing".into()));
+    }
+
+    if inputs[PI_ISSUER..PI_SCHEMA] != issuer.elements()[..] {
+        return Err(ZkpError::ProofRejected("proof is for a different issuer".into()));
+    }
+    if inputs[PI_SCHEMA] != hash_to_field(&presentation.schema) {
+        return Err(ZkpError::ProofRejected("proof is for a different schema".into()));
+    }
+
+    // Har revealed slot ek disclosed label se match ho, aur koi label bacha na rahe
+    let mut expected: BTreeMap<u64, (F, &str)> = BTreeMap::new();
+    for (name, value) in &presentation.disclosed {
+        expected.insert(hash_to_field(name).to_canonical_u64(), (field_value(name, *value)?, name.as_str()));
+    }
+    for slot in inputs[PI_SLOTS..].chunks(PI_SLOT_WIDTH) {
+        if slot[0] == F::ZERO {
+            continue;
+        }
+        match expected.remove(&slot[1].to_canonical_u64()) {
+            Some((value, _)) if value == slot[2] => {}
+            Some((_, name)) => return Err(ZkpError::ProofRejected(format!("disclosed {} does not match the proof", name))),
+            None => return Err(ZkpError::ProofRejected("proof reveals an unlabelled attribute".into())),
+        }
+    }
+    if let Some((_, name)) = expected.values().next() {
+        return Err(ZkpError::ProofRejected(format!("{} is not revealed by the proof", name)));
+    }
+
+    circuit
+        .data
+        .verify(proof)
+        .map_err(|e| ZkpError::ProofRejected(format!("{:?}", e)))?;
+    Ok(issuer)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::{jboolean, jstring, JNI_FALSE, JNI_TRUE};
+    use log::info;
+
+    use crate::engine::CircuitCache;
+    use crate::error::{ZkpError, ZkpResult};
+    use crate::jni_bridge::{guard, new_string, read_string};
+    use crate::schnorr::PublicKey;
+
+    /// `revealJson` = `["age", ...]`; returns the presentation JSON.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Credential_prove(
+        mut env: JNIEnv,
+        _class: JClass,
+        credential_json: JString,
+        reveal_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            // ⚠️ Credential me salts hain: log mat karna
+            let credential = super::Credential::from_json(&read_string(env, &credential_json, "credential_json")?)?;
+            let reveal: Vec<String> = serde_json::from_str(&read_string(env, &reveal_json, "reveal_json")?)?;
+            let reveal: Vec<&str> = reveal.iter().map(String::as_str).collect();
+
+            let presentation = super::prove_credential(&credential, &reveal, CircuitCache::shared())?;
+            info!("✅ Credential proof generated ({} disclosed)", presentation.disclosed.len());
+            new_string(env, presentation.to_json()?)
+        })
+    }
+
+    /// `trustedIssuersJson` = `["<pk hex>", ...]`.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Credential_verify(
+        mut env: JNIEnv,
+        _class: JClass,
+        presentation_json: JString,
+        trusted_issuers_json: JString,
+    ) -> jboolean {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let presentation =
+                super::CredentialPresentation::from_json(&read_string(env, &presentation_json, "presentation_json")?)?;
+            let trusted: Vec<String> =
+                serde_json::from_str(&read_string(env, &trusted_issuers_json, "trusted_issuers_json")?)?;
+            let trusted = trusted.iter().map(|pk| PublicKey::from_hex(pk)).collect::<ZkpResult<Vec<_>>>()?;
+
+            match super::verify_presentation(&presentation, &trusted, CircuitCache::shared()) {
+                Ok(_) => Ok(JNI_TRUE),
+                Err(ZkpError::ProofRejected(reason)) => {
+                    info!("❌ Credential rejected: {}", reason);
+                    Ok(JNI_FALSE)
+                }
+                Err(e) => Err(e),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issued(issuer: &SecretKey) -> Credential {
+        Credential::issue("student-id", &[("age", 21), ("university", text_value("IIT Delhi"))], issuer).unwrap()
+    }
+
+    #[test]
+    fn prove_verify_round_trip() {
+        let circuits = CircuitCache::default();
+        let issuer = SecretKey::generate();
+        let credential = issued(&issuer);
+        credential.verify_signature().unwrap();
+
+        let presentation = prove_credential(&credential, &["age"], &circuits).unwrap();
+        assert_eq!(presentation.disclosed.get("age"), Some(&21));
+        assert!(!presentation.disclosed.contains_key("university"));
+        let json = presentation.to_json().unwrap();
+        let parsed = CredentialPresentation::from_json(&json).unwrap();
+        assert_eq!(verify_presentation(&parsed, &[issuer.public_key()], &circuits).unwrap(), issuer.public_key());
+    }
+
+    #[test]
+    fn tampered_presentations_are_rejected() {
+        let circuits = CircuitCache::default();
+        let issuer = SecretKey::generate();
+        let presentation = prove_credential(&issued(&issuer), &["age"], &circuits).unwrap();
+        let trusted = [issuer.public_key()];
+
+        let untrusted = verify_presentation(&presentation, &[SecretKey::generate().public_key()], &circuits);
+        assert!(matches!(untrusted, Err(ZkpError::ProofRejected(_))));
+
+        let mut lied = presentation.clone();
+        lied.disclosed.insert("age".into(), 30);
+        assert!(matches!(verify_presentation(&lied, &trusted, &circuits), Err(ZkpError::ProofRejected(_))));
+
+        let mut hidden = presentation.clone();
+        hidden.disclosed.clear();
+        assert!(matches!(verify_presentation(&hidden, &trusted, &circuits), Err(ZkpError::ProofRejected(_))));
+
+        let mut relabelled = presentation;
+        relabelled.schema = "employee-id".into();
+        assert!(matches!(verify_presentation(&relabelled, &trusted, &circuits), Err(ZkpError::ProofRejected(_))));
+    }
+
+    #[test]
+    fn forged_signature_cannot_prove() {
+        let circuits = CircuitCache::default();
+        let issuer = SecretKey::generate();
+        let mut credential = issued(&issuer);
+        credential.attributes[0].value = 99;
+        assert!(credential.verify_signature().is_err());
+        assert!(prove_credential(&credential, &["age"], &circuits).is_err());
+    }
+}
@@ -0,0 +1,240 @@
+// ecgfp5 circuit me: GF(p^5) element = 5 Goldilocks targets, point = projective
+// (X, Y, Z). Addition wahi `rcb_add` hai jo native code chalata hai, bas
+// `CircuitArith` ke through: ek GF(p^5) mul = 25 `arithmetic` ops.
+
+use plonky2::field::types::Field;
+use plonky2::iop::target::{BoolTarget, Target};
+use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+
+use super::{curve, rcb_add, AffinePoint, CurveArith, Gfp5, Point, F};
+
+const D: usize = 2;
+
+pub(crate) type Gfp5Target = [Target; 5];
+
+/// Projective point in the circuit.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PointTarget {
+    pub(crate) x: Gfp5Target,
+    pub(crate) y: Gfp5Target,
+    pub(crate) z: Gfp5Target,
+}
+
+/// Affine point in the circuit (keys, signature `R`).
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AffinePointTarget {
+    pub(crate) x: Gfp5Target,
+    pub(crate) y: Gfp5Target,
+}
+
+impl AffinePointTarget {
+    /// `x || y`, the order used for public inputs and hashing.
+    pub(crate) fn elements(&self) -> Vec<Target> {
+        [self.x, self.y].concat()
+    }
+}
+
+struct CircuitArith<'a> {
+    builder: &'a mut CircuitBuilder<F, D>,
+}
+
+impl CircuitArith<'_> {
+    fn mul_const(&mut self, c: Gfp5, a: Gfp5Target) -> Gfp5Target {
+        let three = F::from_canonical_u64(3);
+        let mut out = [self.builder.zero(); 5];
+        for (i, coeff) in c.0.iter().enumerate().filter(|(_, c)| !c.is_zero()) {
+            for (j, limb) in a.iter().enumerate() {
+                let (k, factor) = if i + j < 5 { (i + j, *coeff) } else { (i + j - 5, three * *coeff) };
+                out[k] = self.builder.mul_const_add(factor, *limb, out[k]);
+            }
+        }
+        out
+    }
+}
+
+impl CurveArith for CircuitArith<'_> {
+    type E = Gfp5Target;
+
+    fn add(&mut self, a: Gfp5Target, b: Gfp5Target) -> Gfp5Target {
+        std::array::from_fn(|i| self.builder.add(a[i], b[i]))
+    }
+
+    fn sub(&mut self, a: Gfp5Target, b: Gfp5Target) -> Gfp5Target {
+        std::array::from_fn(|i| self.builder.sub(a[i], b[i]))
+    }
+
+    /// Schoolbook with z^5 = 3, each term one `arithmetic` op.
+    fn mul(&mut self, a: Gfp5Target, b: Gfp5Target) -> Gfp5Target {
+        let three = F::from_canonical_u64(3);
+        let mut out = [self.builder.zero(); 5];
+        for (i, &ai) in a.iter().enumerate() {
+            for (j, &bj) in b.iter().enumerate() {
+                let (k, factor) = if i + j < 5 { (i + j, F::ONE) } else { (i + j - 5, three) };
+                out[k] = self.builder.arithmetic(factor, F::ONE, ai, bj, out[k]);
+            }
+        }
+        out
+    }
+
+    fn mul_a(&mut self, a: Gfp5Target) -> Gfp5Target {
+        self.mul_const(curve().a, a)
+    }
+
+    fn mul_b3(&mut self, a: Gfp5Target) -> Gfp5Target {
+        self.mul_const(curve().b3, a)
+    }
+}
+
+fn constant_gfp5(builder: &mut CircuitBuilder<F, D>, value: Gfp5) -> Gfp5Target {
+    value.0.map(|x| builder.constant(x))
+}
+
+pub(crate) fn add_virtual_affine(builder: &mut CircuitBuilder<F, D>) -> AffinePointTarget {
+    AffinePointTarget {
+        x: std::array::from_fn(|_| builder.add_virtual_target()),
+        y: std::array::from_fn(|_| builder.add_virtual_target()),
+    }
+}
+
+pub(crate) fn constant_point(builder: &mut CircuitBuilder<F, D>, point: &Point) -> PointTarget {
+    PointTarget {
+        x: constant_gfp5(builder, point.x),
+        y: constant_gfp5(builder, point.y),
+        z: constant_gfp5(builder, point.z),
+    }
+}
+
+pub(crate) fn affine_to_point(builder: &mut CircuitBuilder<F, D>, point: &AffinePointTarget) -> PointTarget {
+    PointTarget { x: point.x, y: point.y, z: constant_gfp5(builder, Gfp5::ONE) }
+}
+
+pub(crate) fn curve_add(builder: &mut CircuitBuilder<F, D>, p: PointTarget, q: PointTarget) -> PointTarget {
+    let [x, y, z] = rcb_add(&mut CircuitArith { builder }, [p.x, p.y, p.z], [q.x, q.y, q.z]);
+    PointTarget { x, y, z }
+}
+
+pub(crate) fn curve_neg(builder: &mut CircuitBuilder<F, D>, p: PointTarget) -> PointTarget {
+    PointTarget { x: p.x, y: p.y.map(|t| builder.neg(t)), z: p.z }
+}
+
+fn select_point(builder: &mut CircuitBuilder<F, D>, bit: BoolTarget, p: PointTarget, q: PointTarget) -> PointTarget {
+    let mut pick = |a: Gfp5Target, b: Gfp5Target| -> Gfp5Target { std::array::from_fn(|i| builder.select(bit, a[i], b[i])) };
+    PointTarget { x: pick(p.x, q.x), y: pick(p.y, q.y), z: pick(p.z, q.z) }
+}
+
+/// `a * P + b * Q` (Shamir's trick; bits little-endian, missing bits = 0).
+/// P, Q must be in the prime-order subgroup (constants or pinned public keys):
+/// then complete formulas have no exceptional cases.
+pub(crate) fn double_scalar_mul(
+    builder: &mut CircuitBuilder<F, D>,
+    a_bits: &[BoolTarget],
+    p: PointTarget,
+    b_bits: &[BoolTarget],
+    q: PointTarget,
+) -> PointTarget {
+    let identity = constant_point(builder, &Point::IDENTITY);
+    let p_plus_q = curve_add(builder, p, q);
+
+    let mut acc = identity;
+    for i in (0..a_bits.len().max(b_bits.len())).rev() {
+        acc = curve_add(builder, acc, acc);
+        let entry = match (a_bits.get(i), b_bits.get(i)) {
+            (Some(a), Some(b)) => {
+                let if_a = select_point(builder, *b, p_plus_q, p);
+                let if_not_a = select_point(builder, *b, q, identity);
+                select_point(builder, *a, if_a, if_not_a)
+            }
+            (Some(a), None) => select_point(builder, *a, p, identity),
+            (None, Some(b)) => select_point(builder, *b, q, identity),
+            (None, None) => unreachable!("loop bound"),
+        };
+        acc = curve_add(builder, acc, entry);
+    }
+    acc
+}
+
+/// `p == expected` with `p` projective: X = x Z and Y = y Z. The identity
+/// (Z = 0, Y != 0) can never pass, so no separate Z != 0 check is needed.
+pub(crate) fn connect_affine(builder: &mut CircuitBuilder<F, D>, p: PointTarget, expected: &AffinePointTarget) {
+    let mut arith = CircuitArith { builder };
+    let x = arith.mul(expected.x, p.z);
+    let y = arith.mul(expected.y, p.z);
+    for (a, b) in x.into_iter().zip(p.x).chain(y.into_iter().zip(p.y)) {
+        builder.connect(a, b);
+    }
+}
+
+pub(crate) fn set_affine_target(pw: &mut PartialWitness<F>, target: &AffinePointTarget, point: &AffinePoint) {
+    for (t, v) in target.x.into_iter().zip(point.x.0).chain(target.y.into_iter().zip(point.y.0)) {
+        pw.set_target(t, v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use plonky2::plonk::circuit_data::CircuitConfig;
+    use plonky2::plonk::config::PoseidonGoldilocksConfig;
+
+    use super::*;
+    use crate::ecgfp5::{Scalar, SCALAR_BITS};
+    use crate::test_util::proves;
+
+    type C = PoseidonGoldilocksConfig;
+
+    /// Circuit for `a * P + b * Q == expected`, P/Q constants, bits and expected as witness.
+    fn scalar_mul_proves(p: &Point, q: &Point, a: &Scalar, b: &Scalar, expected: &AffinePoint) -> bool {
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let a_bits: Vec<BoolTarget> = (0..SCALAR_BITS).map(|_| builder.add_virtual_bool_target_safe()).collect();
+        let b_bits: Vec<BoolTarget> = (0..SCALAR_BITS).map(|_| builder.add_virtual_bool_target_safe()).collect();
+        let p_target = constant_point(&mut builder, p);
+        let q_target = constant_point(&mut builder, q);
+        let out = double_scalar_mul(&mut builder, &a_bits, p_target, &b_bits, q_target);
+        let expected_target = add_virtual_affine(&mut builder);
+        connect_affine(&mut builder, out, &expected_target);
+        let data = builder.build::<C>();
+
+        let mut pw = PartialWitness::new();
+        for (target, bit) in a_bits.iter().zip(a.bits_le()).chain(b_bits.iter().zip(b.bits_le())) {
+            pw.set_bool_target(*target, bit);
+        }
+        set_affine_target(&mut pw, &expected_target, expected);
+        proves(&data, pw)
+    }
+
+    #[test]
+    fn circuit_add_matches_native() {
+        let g = Point::generator();
+        let p = g.mul(&BigUint::from(5u32));
+        let q = g.mul(&BigUint::from(11u32));
+
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let p_target = constant_point(&mut builder, &p);
+        let q_target = constant_point(&mut builder, &q);
+        let sum = curve_add(&mut builder, p_target, q_target);
+        let doubled = curve_add(&mut builder, p_target, p_target);
+        let expected_sum = add_virtual_affine(&mut builder);
+        let expected_double = add_virtual_affine(&mut builder);
+        connect_affine(&mut builder, sum, &expected_sum);
+        connect_affine(&mut builder, doubled, &expected_double);
+        let data = builder.build::<C>();
+
+        let mut pw = PartialWitness::new();
+        set_affine_target(&mut pw, &expected_sum, &(p + q).to_affine().unwrap());
+        set_affine_target(&mut pw, &expected_double, &p.double().to_affine().unwrap());
+        assert!(proves(&data, pw));
+    }
+
+    #[test]
+    fn circuit_double_scalar_mul_matches_native() {
+        let g = Point::generator();
+        let q = g.mul(&BigUint::from(1234u32));
+        let (a, b) = (Scalar::random(), Scalar::random());
+        let expected = (g.mul(&a.0) + q.mul(&b.0)).to_affine().unwrap();
+        assert!(scalar_mul_proves(&g, &q, &a, &b, &expected));
+
+        let wrong = (g.mul(&a.0) + q.mul(&b.0) + g).to_affine().unwrap();
+        assert!(!scalar_mul_proves(&g, &q, &a, &b, &wrong));
+    }
+}
@@ -0,0 +1,521 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔺 ECGFP5 (Pornin 2022) - Goldilocks ke upar ka curve
+// Base field GF(p^5) = GF(p)[z] / (z^5 - 3), p = Goldilocks. Isliye point
+// arithmetic plonky2 circuit me native Goldilocks ops se hi ho jaata hai
+// (koi non-native 256-bit math nahi). Group order 2n, n ~ 2^319 prime.
+//   • model: short Weierstrass y^2 = x^3 + A x + B, jo paper ke double-odd
+//     y^2 = x(x^2 + 2x + 263z) ka isomorphic roop hai (x_w = x + 2/3)
+//   • formulas: Renes-Costello-Batina complete projective addition, ek hi
+//     generic code native aur circuit dono ke liye (`CurveArith`)
+//   • points hamesha order-n subgroup me; wahan formulas exception-free hain
+// Signatures schnorr.rs me; yahan sirf field + curve + scalars.
+// ═══════════════════════════════════════════════════════════════════════════
+
+pub mod gadgets;
+
+use std::ops::{Add, Mul, Neg, Sub};
+use std::sync::OnceLock;
+
+use num_bigint::BigUint;
+use plonky2::field::goldilocks_field::GoldilocksField;
+use plonky2::field::types::{Field, PrimeField64};
+
+use crate::error::{ZkpError, ZkpResult};
+
+type F = GoldilocksField;
+
+const P: u64 = 0xFFFF_FFFF_0000_0001;
+
+/// Prime order of the subgroup we use (the curve has 2n points).
+const ORDER_N: &str = "1067993516717146951041484916571792702745057740581727230159139685185762082554198619328292418486241";
+
+/// Scalars fit in this many bits (n < 2^319).
+pub const SCALAR_BITS: usize = 320;
+
+pub fn order() -> &'static BigUint {
+    static N: OnceLock<BigUint> = OnceLock::new();
+    N.get_or_init(|| ORDER_N.parse().expect("valid constant"))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧮 GF(p^5)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// `c0 + c1 z + ... + c4 z^4`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Gfp5(pub [F; 5]);
+
+impl Gfp5 {
+    pub const ZERO: Self = Self([F::ZERO; 5]);
+    pub const ONE: Self = Self([F::ONE, F::ZERO, F::ZERO, F::ZERO, F::ZERO]);
+
+    pub fn from_base(x: F) -> Self {
+        Self([x, F::ZERO, F::ZERO, F::ZERO, F::ZERO])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// Square-and-multiply; not constant time (public exponents only).
+    pub fn pow(&self, exponent: &BigUint) -> Self {
+        let mut result = Self::ONE;
+        for i in (0..exponent.bits()).rev() {
+            result = result.square();
+            if exponent.bit(i) {
+                result = result * *self;
+            }
+        }
+        result
+    }
+
+    /// `None` for zero. Fermat: a^(p^5 - 2).
+    pub fn inverse(&self) -> Option<Self> {
+        static EXP: OnceLock<BigUint> = OnceLock::new();
+        if self.is_zero() {
+            return None;
+        }
+        let exponent = EXP.get_or_init(|| BigUint::from(P).pow(5) - 2u32);
+        Some(self.pow(exponent))
+    }
+
+    pub fn to_u64s(&self) -> [u64; 5] {
+        self.0.map(|x| x.to_canonical_u64())
+    }
+
+    pub fn from_u64s(limbs: [u64; 5]) -> Option<Self> {
+        if limbs.iter().any(|&x| x >= P) {
+            return None;
+        }
+        Some(Self(limbs.map(F::from_canonical_u64)))
+    }
+
+    /// `if choice { b } else { a }` via a mask on the raw limbs, no branch.
+    fn select(a: Self, b: Self, choice: u64) -> Self {
+        let mask = std::hint::black_box(choice.wrapping_neg());
+        Self(std::array::from_fn(|i| GoldilocksField(a.0[i].0 ^ (mask & (a.0[i].0 ^ b.0[i].0)))))
+    }
+}
+
+impl Add for Gfp5 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl Sub for Gfp5 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl Neg for Gfp5 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(self.0.map(|x| -x))
+    }
+}
+
+impl Mul for Gfp5 {
+    type Output = Self;
+    /// Schoolbook; z^5 = 3 folds the high half back.
+    fn mul(self, rhs: Self) -> Self {
+        let three = F::from_canonical_u64(3);
+        let mut out = [F::ZERO; 5];
+        for i in 0..5 {
+            for j in 0..5 {
+                let product = self.0[i] * rhs.0[j];
+                if i + j < 5 {
+                    out[i + j] += product;
+                } else {
+                    out[i + j - 5] += three * product;
+                }
+            }
+        }
+        Self(out)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📐 CURVE CONSTANTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// `A = 263z - 4/3` and `3B = 16/9 - 526z` (Weierstrass model of a = 2, b = 263z).
+pub(crate) struct CurveConsts {
+    pub(crate) a: Gfp5,
+    pub(crate) b3: Gfp5,
+}
+
+pub(crate) fn curve() -> &'static CurveConsts {
+    static CONSTS: OnceLock<CurveConsts> = OnceLock::new();
+    CONSTS.get_or_init(|| {
+        let int = F::from_canonical_u64;
+        let a = Gfp5([-(int(4) * int(3).inverse()), int(263), F::ZERO, F::ZERO, F::ZERO]);
+        let b3 = Gfp5([int(16) * int(9).inverse(), -int(526), F::ZERO, F::ZERO, F::ZERO]);
+        CurveConsts { a, b3 }
+    })
+}
+
+/// Generator of the order-n subgroup: 2 * (0, sqrt(B)), root with the smaller
+/// limbs (c0 first). Fixed here so nobody has to trust a sqrt routine.
+const GENERATOR_X: [u64; 5] = [
+    0x0037_8069_D09F_40EB,
+    0x8025_8A4B_BE0F_2673,
+    0xCF63_36DD_95C3_0193,
+    0x10CB_0A49_16C1_70D6,
+    0x28AA_834C_6CD2_4942,
+];
+const GENERATOR_Y: [u64; 5] = [
+    0xC1F2_6CB1_37F6_4507,
+    0x2A67_811D_85C4_D934,
+    0x51E5_1C99_3801_E20E,
+    0x0012_139B_233C_B5EA,
+    0x4D12_AB55_2237_94DD,
+];
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔁 GENERIC FORMULAS (native + circuit)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// GF(p^5) operations the point formulas need; `E` = value or circuit target.
+pub(crate) trait CurveArith {
+    type E: Copy;
+    fn add(&mut self, a: Self::E, b: Self::E) -> Self::E;
+    fn sub(&mut self, a: Self::E, b: Self::E) -> Self::E;
+    fn mul(&mut self, a: Self::E, b: Self::E) -> Self::E;
+    fn mul_a(&mut self, a: Self::E) -> Self::E;
+    fn mul_b3(&mut self, a: Self::E) -> Self::E;
+}
+
+/// Renes-Costello-Batina 2015, Algorithm 1 (complete addition, any `a`).
+/// Doubling = `rcb_add(p, p)`.
+pub(crate) fn rcb_add<A: CurveArith>(ar: &mut A, p: [A::E; 3], q: [A::E; 3]) -> [A::E; 3] {
+    let [x1, y1, z1] = p;
+    let [x2, y2, z2] = q;
+
+    let mut t0 = ar.mul(x1, x2);
+    let mut t1 = ar.mul(y1, y2);
+    let mut t2 = ar.mul(z1, z2);
+    let mut t3 = ar.add(x1, y1);
+    let mut t4 = ar.add(x2, y2);
+    t3 = ar.mul(t3, t4);
+    t4 = ar.add(t0, t1);
+    t3 = ar.sub(t3, t4);
+    t4 = ar.add(x1, z1);
+    let mut t5 = ar.add(x2, z2);
+    t4 = ar.mul(t4, t5);
+    t5 = ar.add(t0, t2);
+    t4 = ar.sub(t4, t5);
+    t5 = ar.add(y1, z1);
+    let mut x3 = ar.add(y2, z2);
+    t5 = ar.mul(t5, x3);
+    x3 = ar.add(t1, t2);
+    t5 = ar.sub(t5, x3);
+    let mut z3 = ar.mul_a(t4);
+    x3 = ar.mul_b3(t2);
+    z3 = ar.add(x3, z3);
+    x3 = ar.sub(t1, z3);
+    z3 = ar.add(t1, z3);
+    let mut y3 = ar.mul(x3, z3);
+    t1 = ar.add(t0, t0);
+    t1 = ar.add(t1, t0);
+    t2 = ar.mul_a(t2);
+    t4 = ar.mul_b3(t4);
+    t1 = ar.add(t1, t2);
+    t2 = ar.sub(t0, t2);
+    t2 = ar.mul_a(t2);
+    t4 = ar.add(t4, t2);
+    t0 = ar.mul(t1, t4);
+    y3 = ar.add(y3, t0);
+    t0 = ar.mul(t5, t4);
+    x3 = ar.mul(t3, x3);
+    x3 = ar.sub(x3, t0);
+    t0 = ar.mul(t3, t1);
+    z3 = ar.mul(t5, z3);
+    z3 = ar.add(z3, t0);
+
+    [x3, y3, z3]
+}
+
+struct NativeArith;
+
+impl CurveArith for NativeArith {
+    type E = Gfp5;
+    fn add(&mut self, a: Gfp5, b: Gfp5) -> Gfp5 {
+        a + b
+    }
+    fn sub(&mut self, a: Gfp5, b: Gfp5) -> Gfp5 {
+        a - b
+    }
+    fn mul(&mut self, a: Gfp5, b: Gfp5) -> Gfp5 {
+        a * b
+    }
+    fn mul_a(&mut self, a: Gfp5) -> Gfp5 {
+        curve().a * a
+    }
+    fn mul_b3(&mut self, a: Gfp5) -> Gfp5 {
+        curve().b3 * a
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📍 POINTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Projective `(X : Y : Z)`; identity = `(0 : 1 : 0)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Point {
+    pub(crate) x: Gfp5,
+    pub(crate) y: Gfp5,
+    pub(crate) z: Gfp5,
+}
+
+/// Affine point, the form keys and signatures are stored in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AffinePoint {
+    pub x: Gfp5,
+    pub y: Gfp5,
+}
+
+impl Point {
+    pub const IDENTITY: Self = Self { x: Gfp5::ZERO, y: Gfp5::ONE, z: Gfp5::ZERO };
+
+    pub fn generator() -> Self {
+        let x = Gfp5::from_u64s(GENERATOR_X).expect("canonical constant");
+        let y = Gfp5::from_u64s(GENERATOR_Y).expect("canonical constant");
+        AffinePoint { x, y }.into()
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    pub fn double(&self) -> Self {
+        *self + *self
+    }
+
+    /// Double-and-add, MSB first (not constant time; verify / public data only).
+    pub fn mul(&self, k: &BigUint) -> Self {
+        let mut acc = Self::IDENTITY;
+        for i in (0..k.bits()).rev() {
+            acc = acc.double();
+            if k.bit(i) {
+                acc = acc + *self;
+            }
+        }
+        acc
+    }
+
+    /// For secret scalars: always `SCALAR_BITS` double+add rounds, and the
+    /// result is picked with a mask select instead of a branch on the key bit.
+    /// The `BigUint` reduction in `Scalar` itself is not hardened.
+    pub(crate) fn mul_secret(&self, k: &BigUint) -> Self {
+        let mut limbs = [0u64; SCALAR_BITS / 64];
+        for (limb, digit) in limbs.iter_mut().zip(k.iter_u64_digits()) {
+            *limb = digit;
+        }
+        let mut acc = Self::IDENTITY;
+        for i in (0..SCALAR_BITS).rev() {
+            acc = acc.double();
+            let sum = acc + *self;
+            let bit = (limbs[i / 64] >> (i % 64)) & 1;
+            acc = Self {
+                x: Gfp5::select(acc.x, sum.x, bit),
+                y: Gfp5::select(acc.y, sum.y, bit),
+                z: Gfp5::select(acc.z, sum.z, bit),
+            };
+        }
+        acc
+    }
+
+    pub fn to_affine(&self) -> Option<AffinePoint> {
+        let z_inv = self.z.inverse()?;
+        Some(AffinePoint { x: self.x * z_inv, y: self.y * z_inv })
+    }
+
+    pub(crate) fn coords(&self) -> [Gfp5; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let [x, y, z] = rcb_add(&mut NativeArith, self.coords(), rhs.coords());
+        Self { x, y, z }
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self { x: self.x, y: -self.y, z: self.z }
+    }
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+    }
+}
+
+impl Eq for Point {}
+
+impl From<AffinePoint> for Point {
+    fn from(p: AffinePoint) -> Self {
+        Self { x: p.x, y: p.y, z: Gfp5::ONE }
+    }
+}
+
+impl AffinePoint {
+    pub fn is_on_curve(&self) -> bool {
+        let consts = curve();
+        // 3B * 1/3 = B
+        let b = consts.b3 * Gfp5::from_base(F::from_canonical_u64(3).inverse());
+        self.y.square() == self.x.square() * self.x + consts.a * self.x + b
+    }
+
+    /// On the curve and in the order-n subgroup (keys from outside must pass this).
+    pub fn validate(&self) -> ZkpResult<()> {
+        if !self.is_on_curve() {
+            return Err(ZkpError::InvalidInput("point is not on ecgfp5".into()));
+        }
+        if !Point::from(*self).mul(order()).is_identity() {
+            return Err(ZkpError::InvalidInput("point is not in the prime-order subgroup".into()));
+        }
+        Ok(())
+    }
+
+    /// 80 bytes: x then y, each 5 little-endian `u64`s.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.x.to_u64s().iter().chain(self.y.to_u64s().iter()).flat_map(|l| l.to_le_bytes()).collect()
+    }
+
+    /// Parses and validates (curve + subgroup).
+    pub fn from_bytes(bytes: &[u8]) -> ZkpResult<Self> {
+        if bytes.len() != 80 {
+            return Err(ZkpError::InvalidInput(format!("ecgfp5 point must be 80 bytes, got {}", bytes.len())));
+        }
+        let limbs: Vec<u64> = bytes.chunks(8).map(|c| u64::from_le_bytes(c.try_into().expect("8 bytes"))).collect();
+        let coord = |at: usize| {
+            Gfp5::from_u64s(limbs[at..at + 5].try_into().expect("5 limbs"))
+                .ok_or_else(|| ZkpError::InvalidInput("ecgfp5 coordinate is not canonical".into()))
+        };
+        let point = Self { x: coord(0)?, y: coord(5)? };
+        point.validate()?;
+        Ok(point)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔢 SCALARS (mod n)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Scalar in `[0, n)`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Scalar(pub(crate) BigUint);
+
+impl Scalar {
+    /// Uniform in `[1, n)` (64 random bytes, bias ~2^-190).
+    pub fn random() -> Self {
+        use rand::RngCore;
+        loop {
+            let mut wide = [0u8; 64];
+            rand::rngs::OsRng.fill_bytes(&mut wide);
+            let k = BigUint::from_bytes_le(&wide) % order();
+            if k.bits() != 0 {
+                return Self(k);
+            }
+        }
+    }
+
+    pub fn from_biguint(value: &BigUint) -> Self {
+        Self(value % order())
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self((&self.0 + &other.0) % order())
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        Self((&self.0 * &other.0) % order())
+    }
+
+    /// 40 bytes little-endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.0.to_bytes_le();
+        bytes.resize(SCALAR_BITS / 8, 0);
+        bytes
+    }
+
+    /// Rejects non-canonical (>= n) encodings.
+    pub fn from_bytes(bytes: &[u8]) -> ZkpResult<Self> {
+        if bytes.len() != SCALAR_BITS / 8 {
+            return Err(ZkpError::InvalidInput(format!("ecgfp5 scalar must be {} bytes", SCALAR_BITS / 8)));
+        }
+        let value = BigUint::from_bytes_le(bytes);
+        if &value >= order() {
+            return Err(ZkpError::InvalidInput("ecgfp5 scalar is not reduced".into()));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn bits_le(&self) -> Vec<bool> {
+        (0..SCALAR_BITS as u64).map(|i| self.0.bit(i)).collect()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.bits() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generator_is_on_curve_with_prime_order() {
+        let g = Point::generator();
+        let affine = g.to_affine().unwrap();
+        assert!(affine.is_on_curve());
+        assert!(affine.validate().is_ok());
+        assert!(g.mul(order()).is_identity());
+        assert!(!g.mul(&(order() - 1u32)).is_identity());
+    }
+
+    #[test]
+    fn mul_secret_matches_mul() {
+        let g = Point::generator();
+        for k in [BigUint::from(0u32), BigUint::from(1u32), BigUint::from(0xDEAD_BEEFu64), order() - 1u32] {
+            assert_eq!(g.mul_secret(&k), g.mul(&k));
+        }
+        let k = Scalar::random();
+        assert_eq!(g.mul_secret(&k.0), g.mul(&k.0));
+    }
+
+    #[test]
+    fn addition_laws() {
+        let g = Point::generator();
+        let p = g.mul(&BigUint::from(7u32));
+        assert_eq!(p + Point::IDENTITY, p);
+        assert!((p + (-p)).is_identity());
+        assert_eq!(p.double(), g.mul(&BigUint::from(14u32)));
+        assert_eq!(p + g, g.mul(&BigUint::from(8u32)));
+    }
+
+    #[test]
+    fn point_bytes_round_trip_and_reject_off_curve() {
+        let p = Point::generator().mul(&BigUint::from(42u32)).to_affine().unwrap();
+        assert_eq!(AffinePoint::from_bytes(&p.to_bytes()).unwrap(), p);
+
+        let mut bytes = p.to_bytes();
+        bytes[0] ^= 1;
+        assert!(AffinePoint::from_bytes(&bytes).is_err());
+    }
+}
@@ -0,0 +1,718 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🏭 ZKP ENGINE (long-lived handle)
+// Ek baar `createEngine(configJson)` karo, phir har prove/verify usi handle
+// par: circuits cached rehte hain, thread pool fixed hai, trust store aur
+// nullifier state engine ke andar. `destroyEngine` sab free kar deta hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
+
+#[cfg(feature = "mdoc")]
+use base64::{Engine as _, engine::general_purpose};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use plonky2::field::types::PrimeField64;
+use plonky2::plonk::circuit_data::CircuitData;
+use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+use crate::credential::CredentialCircuit;
+use crate::envelope::{digest_hex, ProofEnvelope, ProofKind};
+use crate::error::{ZkpError, ZkpResult};
+use crate::identity_request::{prove_request, IdentityRequest};
+use crate::issuer::{now_unix, TrustedIssuers};
+#[cfg(feature = "mdoc")]
+use crate::mdoc::{self, IacaTrustList, VerifiedMdoc};
+use crate::merkle::MembershipCircuit;
+use crate::offline_identity::IdentityCircuit;
+use crate::passport_security::PassportCircuit;
+use crate::policy::{self, CircuitSelection, Policy, PolicyCircuit, PolicyCircuitSpec, PolicyDocument, PolicyReport};
+use crate::proving_jobs::ProgressSink;
+use crate::revocation::NonRevocationCircuit;
+use crate::schnorr::PublicKey;
+#[cfg(feature = "sd-jwt")]
+use crate::sdjwt::{SdJwtPredicateCircuit, SdJwtPredicateSpec};
+use crate::trust_bundle::{self, TrustBundle, TrustStore};
+use crate::zk_auth::NullifierCircuit;
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// Index of the nullifier in a nullifier proof's public inputs (see zk_auth.rs).
+const PI_NULLIFIER: usize = 2;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧠 CIRCUIT CACHE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Lazily built circuits. Building is the slow part, so each is built once.
+#[derive(Default)]
+pub struct CircuitCache {
+    balance: OnceLock<IdentityCircuit>,
+    credential: OnceLock<CredentialCircuit>,
+    nullifier: OnceLock<NullifierCircuit>,
+    passport: OnceLock<PassportCircuit>,
+    revocation: OnceLock<NonRevocationCircuit>,
+    /// Merkle membership, one circuit per tree depth.
+    membership: Mutex<HashMap<usize, Arc<MembershipCircuit>>>,
+    /// Verification policies, one circuit per distinct spec.
+    policies: Mutex<HashMap<PolicyCircuitSpec, Arc<PolicyCircuit>>>,
+    /// SD-JWT hidden claim predicates, one circuit per (claim, predicate).
+    #[cfg(feature = "sd-jwt")]
+    sd_jwt_predicates: Mutex<HashMap<SdJwtPredicateSpec, Arc<SdJwtPredicateCircuit>>>,
+}
+
+impl CircuitCache {
+    /// Process-wide cache for the handle-less JNI helpers (`Merkle`, `Revocation`,
+    /// `Credential`, ...). Engines keep their own.
+    pub fn shared() -> &'static Self {
+        static SHARED: OnceLock<CircuitCache> = OnceLock::new();
+        SHARED.get_or_init(Self::default)
+    }
+
+    pub(crate) fn balance(&self) -> ZkpResult<&IdentityCircuit> {
+        if let Some(circuit) = self.balance.get() {
+            return Ok(circuit);
+        }
+        let built = IdentityCircuit::build()?;
+        Ok(self.balance.get_or_init(|| built))
+    }
+
+    pub(crate) fn credential(&self) -> &CredentialCircuit {
+        self.credential.get_or_init(CredentialCircuit::build)
+    }
+
+    pub(crate) fn nullifier(&self) -> &NullifierCircuit {
+        self.nullifier.get_or_init(NullifierCircuit::build)
+    }
+
+    pub(crate) fn passport(&self) -> &PassportCircuit {
+        self.passport.get_or_init(PassportCircuit::build)
+    }
+
+    pub(crate) fn revocation(&self) -> &NonRevocationCircuit {
+        self.revocation.get_or_init(NonRevocationCircuit::build)
+    }
+
+    pub(crate) fn membership(&self, depth: usize) -> ZkpResult<Arc<MembershipCircuit>> {
+        // Lock build ke dauraan bhi: do threads ek hi depth do baar na banayein
+        let mut circuits = self.membership.lock().unwrap_or_else(|p| p.into_inner());
+        if let Some(circuit) = circuits.get(&depth) {
+            return Ok(circuit.clone());
+        }
+        let built = Arc::new(MembershipCircuit::build(depth)?);
+        circuits.insert(depth, built.clone());
+        Ok(built)
+    }
+
+    pub(crate) fn policy(&self, spec: &PolicyCircuitSpec) -> ZkpResult<Arc<PolicyCircuit>> {
+        let mut circuits = self.policies.lock().unwrap_or_else(|p| p.into_inner());
+        if let Some(circuit) = circuits.get(spec) {
+            return Ok(circuit.clone());
+        }
+        let built = Arc::new(PolicyCircuit::build(spec)?);
+        circuits.insert(spec.clone(), built.clone());
+        Ok(built)
+    }
+
+    #[cfg(feature = "sd-jwt")]
+    pub(crate) fn sd_jwt_predicate(&self, spec: &SdJwtPredicateSpec) -> ZkpResult<Arc<SdJwtPredicateCircuit>> {
+        let mut circuits = self.sd_jwt_predicates.lock().unwrap_or_else(|p| p.into_inner());
+        if let Some(circuit) = circuits.get(spec) {
+            return Ok(circuit.clone());
+        }
+        let built = Arc::new(SdJwtPredicateCircuit::build(spec)?);
+        circuits.insert(spec.clone(), built.clone());
+        Ok(built)
+    }
+
+    pub(crate) fn data(&self, kind: ProofKind) -> ZkpResult<&CircuitData<F, C, D>> {
+        Ok(match kind {
+            ProofKind::Balance => &self.balance()?.data,
+            ProofKind::Nullifier => &self.nullifier().data,
+            ProofKind::Passport => &self.passport().data,
+        })
+    }
+
+    /// Hex digest of the locally built `kind` circuit (what envelopes must carry).
+    pub fn digest(&self, kind: ProofKind) -> ZkpResult<String> {
+        self.data(kind).map(digest_hex)
+    }
+
+    /// Builds `kind` now so the first real request is warm.
+    pub fn warm(&self, kind: ProofKind) -> ZkpResult<()> {
+        self.data(kind).map(|_| ())
+    }
+}
+
+/// Checks digest + math. `ProofRejected` means the proof is well-formed but not valid.
+pub fn verify_envelope(envelope: &ProofEnvelope, circuits: &CircuitCache) -> ZkpResult<()> {
+    let proof = envelope.open()?;
+    let data = circuits.data(envelope.kind)?;
+
+    if envelope.circuit_digest != digest_hex(data) {
+        return Err(ZkpError::ProofRejected("circuit digest mismatch".into()));
+    }
+    if !envelope.public_inputs.iter().copied().eq(proof.public_inputs.iter().map(|x| x.to_canonical_u64())) {
+        return Err(ZkpError::ProofRejected("envelope public inputs do not match its proof".into()));
+    }
+
+    data.verify(proof)
+        .map_err(|e| ZkpError::ProofRejected(format!("{:?}", e)))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ⚙️ CONFIG & REPORT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// `createEngine(configJson)` input. Every field is optional.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct EngineConfig {
+    /// Threads for plonky2's parallel work inside this engine.
+    pub prover_threads: usize,
+    /// Circuits to build inside `createEngine` instead of on first use.
+    pub warm_circuits: Vec<ProofKind>,
+    /// Accepted circuit digests (hex). Empty = accept whatever this build produces.
+    pub trusted_digests: Vec<String>,
+    /// Reject a nullifier proof whose nullifier was already seen by this engine.
+    pub track_nullifiers: bool,
+    /// Ed25519 keys (hex) allowed to sign trust bundles (trust_bundle.rs).
+    pub trust_bundle_publishers: Vec<String>,
+    /// Highest bundle version the app accepted before; older bundles are rollbacks.
+    pub min_trust_bundle_version: u64,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            prover_threads: 2,
+            warm_circuits: Vec::new(),
+            trusted_digests: Vec::new(),
+            track_nullifiers: true,
+            trust_bundle_publishers: Vec::new(),
+            min_trust_bundle_version: 0,
+        }
+    }
+}
+
+/// What `verify` returns. A rejected proof is a normal report with `verified: false`.
+#[derive(Serialize, Debug, Clone)]
+pub struct VerificationReport {
+    pub kind: ProofKind,
+    pub verified: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub circuit_digest: String,
+    pub public_inputs: Vec<u64>,
+    pub elapsed_ms: u64,
+    /// Installed trust bundle this was checked against.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust_bundle_version: Option<u64>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🏭 ENGINE
+// ═══════════════════════════════════════════════════════════════════════════
+
+pub struct ZkpEngine {
+    config: EngineConfig,
+    pool: rayon::ThreadPool,
+    circuits: CircuitCache,
+    trusted_digests: HashSet<String>,
+    seen_nullifiers: Mutex<HashSet<u64>>,
+    trust: Mutex<TrustStore>,
+}
+
+impl ZkpEngine {
+    pub fn new(config: EngineConfig) -> ZkpResult<Self> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(config.prover_threads.max(1))
+            .thread_name(|i| format!("zkp-engine-{}", i))
+            .build()
+            .map_err(|e| ZkpError::InvalidInput(format!("thread pool: {}", e)))?;
+
+        let trust = TrustStore::new(&config.trust_bundle_publishers, config.min_trust_bundle_version)?;
+        let engine = Self {
+            trusted_digests: config.trusted_digests.iter().map(|d| d.to_lowercase()).collect(),
+            config,
+            pool,
+            circuits: CircuitCache::default(),
+            seen_nullifiers: Mutex::new(HashSet::new()),
+            trust: Mutex::new(trust),
+        };
+
+        let start = Instant::now();
+        for kind in &engine.config.warm_circuits {
+            engine.pool.install(|| engine.circuits.warm(*kind))?;
+        }
+        info!("🏭 Engine ready ({} warm circuits) in {:.2?}", engine.config.warm_circuits.len(), start.elapsed());
+
+        Ok(engine)
+    }
+
+    pub fn from_json(config_json: &str) -> ZkpResult<Self> {
+        let config: EngineConfig = if config_json.trim().is_empty() {
+            EngineConfig::default()
+        } else {
+            serde_json::from_str(config_json)?
+        };
+        Self::new(config)
+    }
+
+    pub fn config(&self) -> &EngineConfig {
+        &self.config
+    }
+
+    pub fn circuits(&self) -> &CircuitCache {
+        &self.circuits
+    }
+
+    pub fn prove(&self, request: &IdentityRequest, progress: &mut dyn ProgressSink) -> ZkpResult<ProofEnvelope> {
+        self.pool.install(|| prove_request(request, &self.circuits, progress))
+    }
+
+    /// Installs a signed trust bundle (CBOR bytes) and returns it.
+    pub fn install_trust_bundle(&self, bytes: &[u8]) -> ZkpResult<TrustBundle> {
+        let mut trust = self.trust.lock().unwrap_or_else(|p| p.into_inner());
+        let bundle = trust.install(bytes, now_unix())?.clone();
+        info!("🧳 Trust bundle version {} installed", bundle.version);
+        Ok(bundle)
+    }
+
+    /// Installed bundle version (persist it as `min_trust_bundle_version`).
+    pub fn trust_bundle_version(&self) -> Option<u64> {
+        let trust = self.trust.lock().unwrap_or_else(|p| p.into_inner());
+        trust.current().map(|b| b.version)
+    }
+
+    /// Installed bundle version, and the bundle if it is usable now (expired = error).
+    fn trust_snapshot(&self) -> (Option<u64>, ZkpResult<Option<TrustBundle>>) {
+        let trust = self.trust.lock().unwrap_or_else(|p| p.into_inner());
+        (trust.current().map(|b| b.version), trust.active(now_unix()).map(|b| b.cloned()))
+    }
+
+    /// Bundle digests if it lists any, else `trusted_digests` (empty = accept all).
+    fn check_digest(&self, bundle: Option<&TrustBundle>, digest: &str) -> ZkpResult<()> {
+        let digest = digest.to_lowercase();
+        let trusted = match bundle.filter(|b| !b.circuit_digests.is_empty()) {
+            Some(bundle) => bundle.circuit_digests.iter().any(|d| d.to_lowercase() == digest),
+            None => self.trusted_digests.is_empty() || self.trusted_digests.contains(&digest),
+        };
+        if !trusted {
+            return Err(ZkpError::ProofRejected("circuit digest not in trust store".into()));
+        }
+        Ok(())
+    }
+
+    /// Verifies against cached circuits, the trust store and (optionally) nullifier replay.
+    /// An installed trust bundle with circuit digests replaces `trusted_digests`.
+    pub fn verify(&self, envelope: &ProofEnvelope) -> ZkpResult<VerificationReport> {
+        let start = Instant::now();
+        let proof = envelope.open()?;
+        let (trust_bundle_version, bundle) = self.trust_snapshot();
+
+        let outcome = self.pool.install(|| {
+            // Expired bundle ke saath kuch bhi verify nahi hota
+            let bundle = bundle?;
+            self.check_digest(bundle.as_ref(), &envelope.circuit_digest)?;
+            verify_envelope(envelope, &self.circuits)?;
+
+            if envelope.kind == ProofKind::Nullifier && self.config.track_nullifiers {
+                let nullifier = proof
+                    .public_inputs
+                    .get(PI_NULLIFIER)
+                    .ok_or_else(|| ZkpError::CorruptProof("nullifier public input missing".into()))?
+                    .to_canonical_u64();
+
+                let mut seen = self.seen_nullifiers.lock().unwrap_or_else(|p| p.into_inner());
+                if !seen.insert(nullifier) {
+                    return Err(ZkpError::ProofRejected("nullifier already used".into()));
+                }
+            }
+            Ok(())
+        });
+
+        let (verified, reason) = match outcome {
+            Ok(()) => (true, None),
+            Err(ZkpError::ProofRejected(reason)) => (false, Some(reason)),
+            Err(e) => return Err(e),
+        };
+
+        Ok(VerificationReport {
+            kind: envelope.kind,
+            verified,
+            reason,
+            circuit_digest: envelope.circuit_digest.clone(),
+            public_inputs: proof.public_inputs.iter().map(|x| x.to_canonical_u64()).collect(),
+            elapsed_ms: start.elapsed().as_millis() as u64,
+            trust_bundle_version,
+        })
+    }
+
+    /// `policy::verify_with_policy` on this engine's circuits, behind the same
+    /// digest allow-list as `verify`. The installed bundle narrows the policy: its
+    /// issuers only keep keys the bundle trusts, and its revocation root must be
+    /// the bundle's. Whatever the policy leaves out comes from the bundle.
+    pub fn verify_with_policy(&self, payload: &str, policy_text: &str) -> ZkpResult<PolicyReport> {
+        let (_, bundle) = self.trust_snapshot();
+        let bundle = bundle?;
+        let mut document = PolicyDocument::parse(policy_text)?;
+        if let Some(bundle) = &bundle {
+            narrow_to_bundle(&mut document, bundle)?;
+        }
+        let policy = Policy::compile(document)?;
+
+        let mut report = self.pool.install(|| {
+            let digest = match &policy.selection {
+                CircuitSelection::Balance => digest_hex(&self.circuits.balance()?.data),
+                CircuitSelection::Credential(spec) => digest_hex(&self.circuits.policy(spec)?.data),
+            };
+            self.check_digest(bundle.as_ref(), &digest)?;
+            policy::verify_with_policy(payload, &policy, &self.circuits)
+        })?;
+        report.trust_bundle_version = bundle.map(|b| b.version);
+        Ok(report)
+    }
+
+    /// mdoc check (mdoc.rs) with the installed bundle's CSCA entries as the IACA roots.
+    #[cfg(feature = "mdoc")]
+    pub fn verify_mdoc(&self, bytes: &[u8], doc_type: &str) -> ZkpResult<VerifiedMdoc> {
+        let (_, bundle) = self.trust_snapshot();
+        let bundle = bundle?
+            .ok_or_else(|| ZkpError::InvalidInput("no trust bundle installed (mdoc roots come from its CSCA list)".into()))?;
+        mdoc::verify_issuer_signed(bytes, doc_type, &IacaTrustList::from_bundle(&bundle)?, now_unix())
+    }
+
+    // JSON in / JSON out: the shape every foreign binding (JNI, C ABI) exposes.
+
+    /// `IdentityRequest` JSON -> `ProofEnvelope` JSON.
+    pub fn prove_json(&self, request_json: &str, progress: &mut dyn ProgressSink) -> ZkpResult<String> {
+        // ⚠️ Secrets: request ko log mat karna
+        let request = IdentityRequest::from_json(request_json)?;
+        self.prove(&request, progress)?.to_json()
+    }
+
+    /// `ProofEnvelope` JSON -> `VerificationReport` JSON.
+    pub fn verify_json(&self, envelope_json: &str) -> ZkpResult<String> {
+        let report = self.verify(&ProofEnvelope::from_json(envelope_json)?)?;
+        serde_json::to_string(&report).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+
+    /// Payload + policy (JSON / TOML) -> `PolicyReport` JSON.
+    pub fn verify_with_policy_json(&self, payload: &str, policy_text: &str) -> ZkpResult<String> {
+        let report = self.verify_with_policy(payload, policy_text)?;
+        serde_json::to_string(&report).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+
+    /// Base64 or QR frames in, installed `TrustBundle` JSON out.
+    pub fn install_trust_bundle_json(&self, text: &str) -> ZkpResult<String> {
+        self.install_trust_bundle(&trust_bundle::decode_text(text)?)?.to_json()
+    }
+
+    /// Base64 `IssuerSigned` / `Document` CBOR -> `VerifiedMdoc` JSON.
+    #[cfg(feature = "mdoc")]
+    pub fn verify_mdoc_json(&self, issuer_signed_b64: &str, doc_type: &str) -> ZkpResult<String> {
+        // ⚠️ Licence data hai: elements log mat karna
+        let mdoc = self.verify_mdoc(&general_purpose::STANDARD.decode(issuer_signed_b64.trim())?, doc_type)?;
+        serde_json::to_string(&mdoc).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+}
+
+/// Bundle wins over policy text: issuers are intersected, the revocation root must match.
+fn narrow_to_bundle(document: &mut PolicyDocument, bundle: &TrustBundle) -> ZkpResult<()> {
+    if let Some(allowed) = &bundle.trusted_issuers {
+        document.trusted_issuers = Some(match document.trusted_issuers.take() {
+            Some(requested) => {
+                let mut kept = Vec::new();
+                for issuer in requested.issuers {
+                    if allowed.find(&PublicKey::from_hex(&issuer.public_key)?).is_some() {
+                        kept.push(issuer);
+                    }
+                }
+                TrustedIssuers::new(requested.version, kept)?
+            }
+            None => allowed.clone(),
+        });
+    }
+    if let Some(root) = &bundle.revocation_root {
+        match &document.revocation_root {
+            Some(requested) if requested != root => {
+                return Err(ZkpError::InvalidInput(format!(
+                    "policy revocation root (version {}) is not the trust bundle's (version {})",
+                    requested.version, root.version
+                )));
+            }
+            _ => document.revocation_root = Some(root.clone()),
+        }
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔑 HANDLES (shared by JNI `jlong` and C ABI `ZkpEngine*`)
+// Handle = `Arc<ZkpEngine>` raw pointer, taaki running jobs engine ko zinda rakh sakein.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(any(feature = "jni", feature = "capi"))]
+pub(crate) fn into_handle(engine: ZkpEngine) -> *const ZkpEngine {
+    Arc::into_raw(Arc::new(engine))
+}
+
+/// Borrows the engine behind `handle` as a new strong reference.
+///
+/// # Safety
+/// `handle` is null or came from `into_handle` and has not been released.
+#[cfg(any(feature = "jni", feature = "capi"))]
+pub(crate) unsafe fn borrow_handle(handle: *const ZkpEngine) -> ZkpResult<Arc<ZkpEngine>> {
+    if handle.is_null() {
+        return Err(ZkpError::InvalidInput("engine handle is null (destroyed?)".into()));
+    }
+    Arc::increment_strong_count(handle);
+    Ok(Arc::from_raw(handle))
+}
+
+/// Drops the handle's reference; in-flight jobs keep their own.
+///
+/// # Safety
+/// Same as `borrow_handle`, and the handle must not be used afterwards.
+#[cfg(any(feature = "jni", feature = "capi"))]
+pub(crate) unsafe fn release_handle(handle: *const ZkpEngine) {
+    if !handle.is_null() {
+        drop(Arc::from_raw(handle));
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS (com.example.zkpapp.ZkpEngine)
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+pub(crate) mod jni_exports {
+    use std::ptr;
+    use std::sync::Arc;
+
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::{jlong, jstring};
+    use log::info;
+
+    use super::ZkpEngine;
+    use crate::capi::{self, call_json, status_result};
+    use crate::error::ZkpResult;
+    use crate::jni_bridge::{guard, new_string, read_string};
+    use crate::trust_bundle;
+
+    // Kotlin ka engine API = C ABI (capi.rs) + JNI strings. Yahan sirf
+    // jstring <-> bytes hota hai; JSON aur errors wahi ke hain.
+
+    /// `jlong` flavour of `borrow_handle` for JNI callers.
+    pub(crate) fn engine_from_handle(handle: jlong) -> ZkpResult<Arc<ZkpEngine>> {
+        // SAFETY: Kotlin only passes handles returned by createEngine and not yet destroyed.
+        unsafe { super::borrow_handle(handle as *const ZkpEngine) }
+    }
+
+    fn engine_ptr(handle: jlong) -> *const ZkpEngine {
+        handle as *const ZkpEngine
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ZkpEngine_createEngine(
+        mut env: JNIEnv,
+        _class: JClass,
+        config_json: JString,
+    ) -> jlong {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let config = read_string(env, &config_json, "config_json")?;
+            let mut engine = ptr::null();
+            // SAFETY: `config` outlives the call and `engine` is a local out slot.
+            status_result(unsafe { capi::zkp_engine_new(config.as_ptr(), config.len(), &mut engine) })?;
+            let handle = engine as jlong;
+            info!("🏭 Engine created: {:#x}", handle);
+            Ok(handle)
+        })
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ZkpEngine_destroyEngine(
+        mut env: JNIEnv,
+        _class: JClass,
+        handle: jlong,
+    ) {
+        guard(&mut env, |_| {
+            if handle != 0 {
+                // SAFETY: balances createEngine; jobs hold their own refs.
+                unsafe { capi::zkp_engine_free(engine_ptr(handle)) };
+                info!("🧹 Engine destroyed: {:#x}", handle);
+            }
+            Ok(())
+        })
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ZkpEngine_prove(
+        mut env: JNIEnv,
+        _class: JClass,
+        handle: jlong,
+        request_json: JString,
+    ) -> jstring {
+        guard(&mut env, |env| {
+            let request = read_string(env, &request_json, "request_json")?;
+            // SAFETY (all exports below): Kotlin passes live handles; inputs outlive the call.
+            let envelope =
+                call_json(|out| unsafe { capi::zkp_engine_prove(engine_ptr(handle), request.as_ptr(), request.len(), out) })?;
+            new_string(env, envelope)
+        })
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ZkpEngine_verify(
+        mut env: JNIEnv,
+        _class: JClass,
+        handle: jlong,
+        envelope_json: JString,
+    ) -> jstring {
+        guard(&mut env, |env| {
+            let envelope = read_string(env, &envelope_json, "envelope_json")?;
+            let report =
+                call_json(|out| unsafe { capi::zkp_engine_verify(engine_ptr(handle), envelope.as_ptr(), envelope.len(), out) })?;
+            new_string(env, report)
+        })
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ZkpEngine_verifyWithPolicy(
+        mut env: JNIEnv,
+        _class: JClass,
+        handle: jlong,
+        payload: JString,
+        policy: JString,
+    ) -> jstring {
+        guard(&mut env, |env| {
+            let payload = read_string(env, &payload, "payload")?;
+            let policy = read_string(env, &policy, "policy")?;
+            let report = call_json(|out| unsafe {
+                capi::zkp_engine_verify_with_policy(
+                    engine_ptr(handle),
+                    payload.as_ptr(),
+                    payload.len(),
+                    policy.as_ptr(),
+                    policy.len(),
+                    out,
+                )
+            })?;
+            new_string(env, report)
+        })
+    }
+
+    /// `bundle` = base64 of the signed file, or the QR frames as a JSON array.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ZkpEngine_installTrustBundle(
+        mut env: JNIEnv,
+        _class: JClass,
+        handle: jlong,
+        bundle: JString,
+    ) -> jstring {
+        guard(&mut env, |env| {
+            let signed = trust_bundle::decode_text(&read_string(env, &bundle, "bundle")?)?;
+            let installed = call_json(|out| unsafe {
+                capi::zkp_engine_install_trust_bundle(engine_ptr(handle), signed.as_ptr(), signed.len(), out)
+            })?;
+            new_string(env, installed)
+        })
+    }
+
+    /// `issuerSignedB64` = base64 of the `IssuerSigned` (or `Document`) CBOR, checked
+    /// against the installed bundle's CSCA list; returns `VerifiedMdoc` JSON.
+    #[cfg(feature = "mdoc")]
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ZkpEngine_verifyMdoc(
+        mut env: JNIEnv,
+        _class: JClass,
+        handle: jlong,
+        issuer_signed_b64: JString,
+        doc_type: JString,
+    ) -> jstring {
+        use base64::{Engine as _, engine::general_purpose};
+
+        guard(&mut env, |env| {
+            let issuer_signed_b64 = read_string(env, &issuer_signed_b64, "issuer_signed")?;
+            let issuer_signed = general_purpose::STANDARD.decode(issuer_signed_b64.trim())?;
+            let doc_type = read_string(env, &doc_type, "doc_type")?;
+            let mdoc = call_json(|out| unsafe {
+                capi::zkp_engine_verify_mdoc(
+                    engine_ptr(handle),
+                    issuer_signed.as_ptr(),
+                    issuer_signed.len(),
+                    doc_type.as_ptr(),
+                    doc_type.len(),
+                    out,
+                )
+            })?;
+            new_string(env, mdoc)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::issuer::TrustedIssuer;
+    use crate::revocation::{RevocationRegistry, RevocationRoot};
+    use crate::schnorr::SecretKey;
+
+    fn trusted(keys: &[&SecretKey]) -> TrustedIssuers {
+        let issuers = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| TrustedIssuer { issuer_id: format!("issuer-{}", i), public_key: key.public_key().to_hex() })
+            .collect();
+        TrustedIssuers::new(1, issuers).unwrap()
+    }
+
+    fn document() -> PolicyDocument {
+        PolicyDocument::parse(r#"{"name": "bar-entry", "clauses": ["issuer in trusted_set", "not revoked"]}"#).unwrap()
+    }
+
+    #[test]
+    fn policy_issuers_are_narrowed_to_the_bundle() {
+        let (bank, rogue) = (SecretKey::generate(), SecretKey::generate());
+        let mut bundle = TrustBundle::new(1, 0, u64::MAX);
+        bundle.trusted_issuers = Some(trusted(&[&bank]));
+
+        let mut widened = document();
+        widened.trusted_issuers = Some(trusted(&[&bank, &rogue]));
+        narrow_to_bundle(&mut widened, &bundle).unwrap();
+        let kept = widened.trusted_issuers.unwrap();
+        assert!(kept.find(&bank.public_key()).is_some());
+        assert!(kept.find(&rogue.public_key()).is_none());
+
+        let mut silent = document();
+        narrow_to_bundle(&mut silent, &bundle).unwrap();
+        assert_eq!(silent.trusted_issuers, bundle.trusted_issuers);
+    }
+
+    #[test]
+    fn policy_revocation_root_must_be_the_bundles() {
+        let root = RevocationRegistry::new().published_root();
+        let mut bundle = TrustBundle::new(1, 0, u64::MAX);
+        bundle.revocation_root = Some(root.clone());
+
+        let mut silent = document();
+        narrow_to_bundle(&mut silent, &bundle).unwrap();
+        assert_eq!(silent.revocation_root.as_ref(), Some(&root));
+
+        let mut same = document();
+        same.revocation_root = Some(root.clone());
+        assert!(narrow_to_bundle(&mut same, &bundle).is_ok());
+
+        let mut other = document();
+        other.revocation_root = Some(RevocationRoot { version: root.version + 1, ..root });
+        assert!(matches!(narrow_to_bundle(&mut other, &bundle), Err(ZkpError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,114 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ✉️ PROOF ENVELOPE
+// Ek proof + uska context (kind, circuit digest, public inputs) in one JSON
+// object. Yehi format prover return karta hai aur verifier accept karta hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use base64::{Engine as _, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+
+use plonky2::field::types::PrimeField64;
+use plonky2::plonk::circuit_data::CircuitData;
+use plonky2::plonk::config::{GenericConfig, GenericHashOut, PoseidonGoldilocksConfig};
+use plonky2::plonk::proof::ProofWithPublicInputs;
+
+use crate::error::{ZkpError, ZkpResult};
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// Bump when the JSON layout changes.
+pub const ENVELOPE_VERSION: u8 = 1;
+
+/// Which circuit produced the proof.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ProofKind {
+    Balance,
+    Nullifier,
+    Passport,
+}
+
+impl ProofKind {
+    /// Same spelling as the JSON (`"balance"`, ...).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProofKind::Balance => "balance",
+            ProofKind::Nullifier => "nullifier",
+            ProofKind::Passport => "passport",
+        }
+    }
+}
+
+impl std::str::FromStr for ProofKind {
+    type Err = ZkpError;
+
+    fn from_str(s: &str) -> ZkpResult<Self> {
+        match s {
+            "balance" => Ok(ProofKind::Balance),
+            "nullifier" => Ok(ProofKind::Nullifier),
+            "passport" => Ok(ProofKind::Passport),
+            other => Err(ZkpError::InvalidInput(format!("unknown proof kind '{}'", other))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ProofEnvelope {
+    pub version: u8,
+    pub kind: ProofKind,
+    /// Hex of the circuit's `verifier_only.circuit_digest`.
+    pub circuit_digest: String,
+    /// Canonical u64 values of the proof's public inputs. A copy for readers;
+    /// `verify_envelope` rejects it if it differs from the proof.
+    pub public_inputs: Vec<u64>,
+    /// Base64 of the bincode-serialized `ProofWithPublicInputs`.
+    pub proof: String,
+}
+
+impl ProofEnvelope {
+    /// Wraps a fresh proof together with the circuit that produced it.
+    pub fn seal(
+        kind: ProofKind,
+        data: &CircuitData<F, C, D>,
+        proof: &ProofWithPublicInputs<F, C, D>,
+    ) -> ZkpResult<Self> {
+        let bytes = bincode::serialize(proof).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+
+        Ok(Self {
+            version: ENVELOPE_VERSION,
+            kind,
+            circuit_digest: digest_hex(data),
+            public_inputs: proof.public_inputs.iter().map(|x| x.to_canonical_u64()).collect(),
+            proof: general_purpose::STANDARD.encode(bytes),
+        })
+    }
+
+    /// Decodes the embedded proof. Public inputs inside the proof are authoritative.
+    pub fn open(&self) -> ZkpResult<ProofWithPublicInputs<F, C, D>> {
+        if self.version != ENVELOPE_VERSION {
+            return Err(ZkpError::InvalidInput(format!("unsupported envelope version {}", self.version)));
+        }
+        let bytes = general_purpose::STANDARD.decode(&self.proof)?;
+        bincode::deserialize(&bytes).map_err(|e| ZkpError::CorruptProof(e.to_string()))
+    }
+
+    /// Canonical public inputs read from the proof itself (not the copy).
+    pub fn proof_public_inputs(&self) -> ZkpResult<Vec<u64>> {
+        Ok(self.open()?.public_inputs.iter().map(|x| x.to_canonical_u64()).collect())
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// Hex fingerprint of a circuit; prover and verifier must agree on it.
+pub fn digest_hex(data: &CircuitData<F, C, D>) -> String {
+    hex::encode(data.verifier_only.circuit_digest.to_bytes())
+}
@@ -0,0 +1,163 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🚨 UNIFIED ERROR MODEL
+// Har JNI / C ABI export isi enum se fail hota hai. Codes are STABLE: Kotlin
+// (`ZkpException.code`) aur C callers (`ZKP_ERR_*`) switch karte hain in par,
+// so never renumber them.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::fmt;
+
+/// Every failure the native engine can report to a caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZkpError {
+    /// Reading or creating a Java object across the JNI boundary failed.
+    Jni(String),
+    /// The caller passed something structurally wrong (empty secret, bad field).
+    InvalidInput(String),
+    /// Base64 / hex decoding failed.
+    InvalidEncoding(String),
+    /// Input JSON could not be parsed into the expected shape.
+    MalformedJson(String),
+    /// Proof bytes could not be deserialized.
+    CorruptProof(String),
+    /// Proof deserialized fine but failed verification.
+    ProofRejected(String),
+    /// Circuit construction failed.
+    CircuitBuild(String),
+    /// Witness generation or proving failed.
+    ProvingFailed(String),
+    /// Serializing a result (bincode / JSON) failed.
+    Serialization(String),
+    /// Passport data hash is not contained in the SOD.
+    IntegrityCheckFailed(String),
+    /// A signature did not verify.
+    SignatureInvalid(String),
+    /// An async proving job was cancelled by the caller.
+    Cancelled(String),
+    /// Rust panicked; caught by the JNI / C adapter before it could reach the caller.
+    Panic(String),
+}
+
+/// Shorthand used across the crate.
+pub type ZkpResult<T> = Result<T, ZkpError>;
+
+impl ZkpError {
+    /// Stable numeric code exposed to Java/Kotlin.
+    pub fn code(&self) -> i32 {
+        match self {
+            ZkpError::Jni(_) => 100,
+            ZkpError::InvalidInput(_) => 200,
+            ZkpError::InvalidEncoding(_) => 201,
+            ZkpError::MalformedJson(_) => 202,
+            ZkpError::CorruptProof(_) => 300,
+            ZkpError::ProofRejected(_) => 301,
+            ZkpError::CircuitBuild(_) => 400,
+            ZkpError::ProvingFailed(_) => 401,
+            ZkpError::Serialization(_) => 402,
+            ZkpError::IntegrityCheckFailed(_) => 500,
+            ZkpError::SignatureInvalid(_) => 501,
+            ZkpError::Cancelled(_) => 600,
+            ZkpError::Panic(_) => 900,
+        }
+    }
+
+    /// Short machine-readable name, handy in logs and JSON reports.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ZkpError::Jni(_) => "JNI",
+            ZkpError::InvalidInput(_) => "INVALID_INPUT",
+            ZkpError::InvalidEncoding(_) => "INVALID_ENCODING",
+            ZkpError::MalformedJson(_) => "MALFORMED_JSON",
+            ZkpError::CorruptProof(_) => "CORRUPT_PROOF",
+            ZkpError::ProofRejected(_) => "PROOF_REJECTED",
+            ZkpError::CircuitBuild(_) => "CIRCUIT_BUILD",
+            ZkpError::ProvingFailed(_) => "PROVING_FAILED",
+            ZkpError::Serialization(_) => "SERIALIZATION",
+            ZkpError::IntegrityCheckFailed(_) => "INTEGRITY_CHECK_FAILED",
+            ZkpError::SignatureInvalid(_) => "SIGNATURE_INVALID",
+            ZkpError::Cancelled(_) => "CANCELLED",
+            ZkpError::Panic(_) => "PANIC",
+        }
+    }
+
+    /// Wraps a caught panic payload (JNI / C ABI panic barriers).
+    #[cfg(any(feature = "jni", feature = "capi"))]
+    pub(crate) fn from_panic(payload: &(dyn std::any::Any + Send)) -> Self {
+        let message = if let Some(s) = payload.downcast_ref::<&str>() {
+            (*s).to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "unknown panic".to_string()
+        };
+        ZkpError::Panic(message)
+    }
+
+    fn detail(&self) -> &str {
+        match self {
+            ZkpError::Jni(m)
+            | ZkpError::InvalidInput(m)
+            | ZkpError::InvalidEncoding(m)
+            | ZkpError::MalformedJson(m)
+            | ZkpError::CorruptProof(m)
+            | ZkpError::ProofRejected(m)
+            | ZkpError::CircuitBuild(m)
+            | ZkpError::ProvingFailed(m)
+            | ZkpError::Serialization(m)
+            | ZkpError::IntegrityCheckFailed(m)
+            | ZkpError::SignatureInvalid(m)
+            | ZkpError::Cancelled(m)
+            | ZkpError::Panic(m) => m,
+        }
+    }
+}
+
+impl fmt::Display for ZkpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.kind(), self.detail())
+    }
+}
+
+impl std::error::Error for ZkpError {}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔁 CONVERSIONS (so `?` just works inside the modules)
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+impl From<jni::errors::Error> for ZkpError {
+    fn from(e: jni::errors::Error) -> Self {
+        ZkpError::Jni(e.to_string())
+    }
+}
+
+impl From<base64::DecodeError> for ZkpError {
+    fn from(e: base64::DecodeError) -> Self {
+        ZkpError::InvalidEncoding(format!("base64: {}", e))
+    }
+}
+
+impl From<hex::FromHexError> for ZkpError {
+    fn from(e: hex::FromHexError) -> Self {
+        ZkpError::InvalidEncoding(format!("hex: {}", e))
+    }
+}
+
+impl From<serde_json::Error> for ZkpError {
+    fn from(e: serde_json::Error) -> Self {
+        ZkpError::MalformedJson(e.to_string())
+    }
+}
+
+#[cfg(feature = "circom")]
+impl From<ark_relations::r1cs::SynthesisError> for ZkpError {
+    fn from(e: ark_relations::r1cs::SynthesisError) -> Self {
+        ZkpError::ProvingFailed(format!("groth16: {}", e))
+    }
+}
+
+impl From<rsa::Error> for ZkpError {
+    fn from(e: rsa::Error) -> Self {
+        ZkpError::SignatureInvalid(e.to_string())
+    }
+}
@@ -0,0 +1,295 @@
+// Comparisons on Goldilocks targets. Field me "<" hota hi nahi, isliye har
+// comparison pehle dono operands ko `bits` width me bandhta hai:
+//   • bits <= 62: a >= b  <=>  a - b fits in `bits` bits. Agar a < b, to
+//     a - b wrap hokar >= p - 2^62 ho jaata hai, jo 62 bits me kabhi fit nahi.
+//   • bits == 63: wahi trick unsound hai (p - 2^63 < 2^63), isliye dono ko
+//     bits me todkar MSB se lexicographic compare karte hain.
+// Dates YYYYMMDD integers hain: valid dates ka integer order = calendar order.
+
+use plonky2::field::types::Field;
+use plonky2::iop::target::{BoolTarget, Target};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+use crate::error::{ZkpError, ZkpResult};
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// Widest supported operand width (values < 2^63).
+pub const MAX_COMPARE_BITS: usize = 63;
+
+/// Widest width for which the cheap difference range check is sound.
+const DIFF_CHECK_BITS: usize = 62;
+
+/// YYYYMMDD fits here (99991231 < 2^27).
+pub const DATE_BITS: usize = 27;
+
+fn check_width(bits: usize) {
+    assert!(bits > 0 && bits <= MAX_COMPARE_BITS, "comparison width must be 1..={}, got {}", MAX_COMPARE_BITS, bits);
+}
+
+fn constant(builder: &mut CircuitBuilder<F, D>, value: u64) -> Target {
+    builder.constant(F::from_canonical_u64(value))
+}
+
+/// Native mirror of the width rule: `value < 2^bits`.
+pub fn fits(value: u64, bits: usize) -> bool {
+    bits >= 64 || value >> bits == 0
+}
+
+/// Constrains `x < 2^bits`.
+pub fn assert_width(builder: &mut CircuitBuilder<F, D>, x: Target, bits: usize) {
+    check_width(bits);
+    builder.range_check(x, bits);
+}
+
+/// `a >= b` as a bit; `a`, `b` are constrained to `bits` bits. Always uses the
+/// bit-decomposed path (the diff trick cannot produce a boolean).
+pub fn is_gte(builder: &mut CircuitBuilder<F, D>, a: Target, b: Target, bits: usize) -> BoolTarget {
+    check_width(bits);
+    // split_le: sum == x aur har bit boolean; bits <= 63 < log2(p) => unique
+    let a_bits = builder.split_le(a, bits);
+    let b_bits = builder.split_le(b, bits);
+
+    // MSB se: `gt` = ab tak a > b tay ho chuka, `eq` = ab tak sab barabar
+    let mut gt = builder.zero();
+    let mut eq = builder.one();
+    for (a_i, b_i) in a_bits.iter().zip(&b_bits).rev() {
+        // a_i & !b_i = a_i - a_i*b_i ;  a_i == b_i = 1 - a_i - b_i + 2 a_i b_i
+        let both = builder.mul(a_i.target, b_i.target);
+        let a_only = builder.sub(a_i.target, both);
+        let sum = builder.add(a_i.target, b_i.target);
+        let differ = builder.mul_const_add(-F::TWO, both, sum);
+        let one = builder.one();
+        let same = builder.sub(one, differ);
+
+        gt = builder.mul_add(eq, a_only, gt);
+        eq = builder.mul(eq, same);
+    }
+    // gt aur eq kabhi ek saath 1 nahi hote
+    BoolTarget::new_unsafe(builder.add(gt, eq))
+}
+
+/// Constrains `a >= b`, both `< 2^bits`.
+pub fn assert_gte(builder: &mut CircuitBuilder<F, D>, a: Target, b: Target, bits: usize) {
+    check_width(bits);
+    if bits <= DIFF_CHECK_BITS {
+        builder.range_check(a, bits);
+        builder.range_check(b, bits);
+        let diff = builder.sub(a, b);
+        builder.range_check(diff, bits);
+    } else {
+        let gte = is_gte(builder, a, b, bits);
+        builder.assert_one(gte.target);
+    }
+}
+
+/// Constrains `a <= b`, both `< 2^bits`.
+pub fn assert_lte(builder: &mut CircuitBuilder<F, D>, a: Target, b: Target, bits: usize) {
+    assert_gte(builder, b, a, bits);
+}
+
+/// Constrains `a >= bound`; `bound` must fit in `bits`.
+pub fn assert_gte_const(builder: &mut CircuitBuilder<F, D>, a: Target, bound: u64, bits: usize) {
+    assert!(fits(bound, bits), "bound {} does not fit {} bits", bound, bits);
+    let bound = constant(builder, bound);
+    assert_gte(builder, a, bound, bits);
+}
+
+/// Constrains `a <= bound`; `bound` must fit in `bits`.
+pub fn assert_lte_const(builder: &mut CircuitBuilder<F, D>, a: Target, bound: u64, bits: usize) {
+    assert!(fits(bound, bits), "bound {} does not fit {} bits", bound, bits);
+    let bound = constant(builder, bound);
+    assert_lte(builder, a, bound, bits);
+}
+
+/// Constrains `lo <= x <= hi` (inclusive), all `< 2^bits`.
+pub fn in_range(builder: &mut CircuitBuilder<F, D>, x: Target, lo: u64, hi: u64, bits: usize) {
+    assert!(lo <= hi, "empty range {}..={}", lo, hi);
+    assert_gte_const(builder, x, lo, bits);
+    assert_lte_const(builder, x, hi, bits);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📅 DATES (YYYYMMDD)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Splits a YYYYMMDD integer, checking month 1..=12 and day 1..=31.
+pub fn parse_yyyymmdd(date: u64) -> ZkpResult<(u64, u64, u64)> {
+    let (year, month, day) = (date / 10_000, date / 100 % 100, date % 100);
+    if year > 9999 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(ZkpError::InvalidInput(format!("not a YYYYMMDD date: {}", date)));
+    }
+    Ok((year, month, day))
+}
+
+/// Latest birth date that is at least `years` old on `today` (both YYYYMMDD).
+/// 29 Feb works too: integer order handles the missing day.
+pub fn age_cutoff(today: u64, years: u64) -> ZkpResult<u64> {
+    let (year, month, day) = parse_yyyymmdd(today)?;
+    let year = year
+        .checked_sub(years)
+        .ok_or_else(|| ZkpError::InvalidInput(format!("{} years before {} is out of range", years, today)))?;
+    Ok(year * 10_000 + month * 100 + day)
+}
+
+/// `year * 10000 + month * 100 + day` with each part range-checked, so the
+/// result is a well-formed YYYYMMDD (parts come from the witness).
+pub fn date_from_parts(builder: &mut CircuitBuilder<F, D>, year: Target, month: Target, day: Target) -> Target {
+    in_range(builder, year, 0, 9999, 14);
+    in_range(builder, month, 1, 12, 4);
+    in_range(builder, day, 1, 31, 5);
+    let date = builder.mul_const_add(F::from_canonical_u64(100), month, day);
+    builder.mul_const_add(F::from_canonical_u64(10_000), year, date)
+}
+
+/// Constrains `date <= cutoff` (on or before), e.g. birth date vs `age_cutoff`.
+pub fn assert_date_on_or_before(builder: &mut CircuitBuilder<F, D>, date: Target, cutoff: u64) {
+    assert_lte_const(builder, date, cutoff, DATE_BITS);
+}
+
+/// Constrains `date >= cutoff` (on or after), e.g. expiry vs today.
+pub fn assert_date_on_or_after(builder: &mut CircuitBuilder<F, D>, date: Target, cutoff: u64) {
+    assert_gte_const(builder, date, cutoff, DATE_BITS);
+}
+
+#[cfg(test)]
+mod tests {
+    use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+    use plonky2::plonk::circuit_data::{CircuitConfig, CircuitData};
+
+    use super::*;
+    use crate::test_util::proves;
+
+    fn builder() -> CircuitBuilder<F, D> {
+        CircuitBuilder::new(CircuitConfig::standard_recursion_config())
+    }
+
+    /// Proves `gadget(a, b)` with the given witness values.
+    fn two_inputs(gadget: impl Fn(&mut CircuitBuilder<F, D>, Target, Target), a: u64, b: u64) -> bool {
+        let mut builder = builder();
+        let (a_t, b_t) = (builder.add_virtual_target(), builder.add_virtual_target());
+        gadget(&mut builder, a_t, b_t);
+        let data = builder.build::<C>();
+
+        let mut pw = PartialWitness::new();
+        pw.set_target(a_t, F::from_canonical_u64(a));
+        pw.set_target(b_t, F::from_canonical_u64(b));
+        proves(&data, pw)
+    }
+
+    fn gte(a: u64, b: u64, bits: usize) -> bool {
+        two_inputs(|builder, a, b| assert_gte(builder, a, b, bits), a, b)
+    }
+
+    fn one_input(gadget: impl Fn(&mut CircuitBuilder<F, D>, Target), x: u64) -> bool {
+        two_inputs(|builder, a, _| gadget(builder, a), x, 0)
+    }
+
+    #[test]
+    fn wrapped_difference_is_rejected() {
+        // a = b - 1: a - b = p - 1, kisi bhi width me fit nahi
+        for bits in [8, 32, DIFF_CHECK_BITS, MAX_COMPARE_BITS] {
+            let b = (1u64 << bits) - 1;
+            assert!(!gte(b - 1, b, bits), "bits = {}", bits);
+            assert!(!gte(0, 1, bits), "bits = {}", bits);
+            assert!(gte(b, b, bits), "bits = {}", bits);
+            assert!(gte(b, b - 1, bits), "bits = {}", bits);
+        }
+    }
+
+    #[test]
+    fn diff_path_and_bit_path_agree_at_the_boundary() {
+        let (max62, two62, max63) = ((1u64 << 62) - 1, 1u64 << 62, (1u64 << 63) - 1);
+
+        // 62-bit path: 2^62 - 1 is the widest operand, 2^62 is out
+        assert!(gte(max62, 0, 62));
+        assert!(!gte(two62, 0, 62));
+        assert!(!gte(max62, two62, 62));
+
+        // 63-bit path: same pairs, plus the very top
+        assert!(gte(max62, 0, 63));
+        assert!(gte(two62, max62, 63));
+        assert!(!gte(max62, two62, 63));
+        assert!(gte(max63, two62, 63));
+        assert!(gte(max63, max63, 63));
+        assert!(!gte(max63 - 1, max63, 63));
+        assert!(!gte(1u64 << 63, 0, 63));
+    }
+
+    #[test]
+    fn balance_above_old_32_bit_window_passes() {
+        let min = crate::offline_identity::MIN_REQUIRED_BALANCE;
+        let check = |builder: &mut CircuitBuilder<F, D>, x: Target| assert_gte_const(builder, x, min, MAX_COMPARE_BITS);
+        assert!(one_input(check, (1u64 << 32) + min + 1));
+        assert!(one_input(check, min));
+        assert!(!one_input(check, min - 1));
+    }
+
+    #[test]
+    fn single_value_range() {
+        let check = |builder: &mut CircuitBuilder<F, D>, x: Target| in_range(builder, x, 42, 42, 8);
+        assert!(one_input(check, 42));
+        assert!(!one_input(check, 41));
+        assert!(!one_input(check, 43));
+    }
+
+    fn date_proves(year: u64, month: u64, day: u64) -> bool {
+        let mut builder = builder();
+        let parts = [builder.add_virtual_target(), builder.add_virtual_target(), builder.add_virtual_target()];
+        let date = date_from_parts(&mut builder, parts[0], parts[1], parts[2]);
+        builder.register_public_input(date);
+        let data = builder.build::<C>();
+
+        let mut pw = PartialWitness::new();
+        for (target, value) in parts.iter().zip([year, month, day]) {
+            pw.set_target(*target, F::from_canonical_u64(value));
+        }
+        proves(&data, pw)
+    }
+
+    #[test]
+    fn date_parts_are_range_checked() {
+        assert!(date_proves(2026, 10, 18));
+        assert!(date_proves(9999, 12, 31));
+        assert!(!date_proves(2026, 13, 1));
+        assert!(!date_proves(2026, 0, 1));
+        assert!(!date_proves(2026, 1, 0));
+        assert!(!date_proves(2026, 1, 32));
+        assert!(!date_proves(10_000, 1, 1));
+    }
+
+    fn is_gte_circuit(bits: usize) -> (CircuitData<F, C, D>, Target, Target) {
+        let mut builder = builder();
+        let (a, b) = (builder.add_virtual_target(), builder.add_virtual_target());
+        let gte = is_gte(&mut builder, a, b, bits);
+        builder.register_public_input(gte.target);
+        (builder.build::<C>(), a, b)
+    }
+
+    #[test]
+    fn is_gte_is_a_boolean() {
+        let (data, a_t, b_t) = is_gte_circuit(MAX_COMPARE_BITS);
+        let top = (1u64 << 63) - 1;
+        for (a, b, expected) in [(5, 5, 1), (6, 5, 1), (5, 6, 0), (top, top, 1), (0, 0, 1), (top - 1, top, 0)] {
+            let mut pw = PartialWitness::new();
+            pw.set_target(a_t, F::from_canonical_u64(a));
+            pw.set_target(b_t, F::from_canonical_u64(b));
+            let proof = data.prove(pw).unwrap();
+            assert_eq!(proof.public_inputs, vec![F::from_canonical_u64(expected)], "{} >= {}", a, b);
+            data.verify(proof).unwrap();
+        }
+    }
+
+    #[test]
+    fn native_date_helpers() {
+        assert_eq!(parse_yyyymmdd(20261018).unwrap(), (2026, 10, 18));
+        assert!(parse_yyyymmdd(20261318).is_err());
+        assert!(parse_yyyymmdd(20261000).is_err());
+        assert_eq!(age_cutoff(20240229, 18).unwrap(), 20060229);
+        assert!(age_cutoff(10101, 18).is_err());
+        assert!(fits((1 << 62) - 1, 62) && !fits(1 << 62, 62));
+    }
+}
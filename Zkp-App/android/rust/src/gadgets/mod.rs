@@ -0,0 +1,10 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧰 REUSABLE CIRCUIT GADGETS
+// Chhote, circuit-agnostic building blocks jo har naya circuit (policy,
+// identity, credential) share karta hai. Har gadget `CircuitBuilder` leta hai
+// aur sirf constraints add karta hai; public inputs caller decide karta hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+pub mod comparison;
+pub mod set_membership;
+pub mod sha256;
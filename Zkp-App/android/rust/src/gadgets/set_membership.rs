@@ -0,0 +1,165 @@
+// "value ek public set me hai" bina value bataye (country in EU, document type
+// in {P, ID}). Do tarike, set size dekh ke:
+//   • chhota set: vanishing polynomial, prod (value - s_i) == 0. Har member ek
+//     mul, koi witness nahi.
+//   • bada set: allowed values ka Poseidon Merkle tree (merkle.rs), leaf =
+//     Poseidon(value). Circuit sirf root jaanta hai; prover path deta hai.
+// Set hamesha verifier ka hota hai (policy), isliye root circuit constant hai.
+
+use plonky2::field::types::{Field, PrimeField64};
+use plonky2::hash::hash_types::{HashOut, HashOutTarget};
+use plonky2::hash::poseidon::PoseidonHash;
+use plonky2::iop::target::{BoolTarget, Target};
+use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::config::{GenericConfig, Hasher, PoseidonGoldilocksConfig};
+
+use crate::error::{ZkpError, ZkpResult};
+use crate::merkle::{merkle_root_gadget, MerklePath, MerkleTree};
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// Sets up to this size use the vanishing polynomial, bigger ones a Merkle root.
+pub const SMALL_SET_MAX: usize = 16;
+
+/// Merkle leaf of an allowed value.
+pub fn set_leaf(value: u64) -> HashOut<F> {
+    PoseidonHash::hash_no_pad(&[F::from_canonical_u64(value)])
+}
+
+/// Constrains `value` to one of `set` (vanishing polynomial). Cost: one mul per member.
+pub fn assert_in_small_set(builder: &mut CircuitBuilder<F, D>, value: Target, set: &[u64]) {
+    assert!(!set.is_empty(), "set must not be empty");
+    let mut product = builder.one();
+    for member in set {
+        let member = builder.constant(F::from_canonical_u64(*member));
+        let diff = builder.sub(value, member);
+        product = builder.mul(product, diff);
+    }
+    builder.assert_zero(product);
+}
+
+/// Witness slots of one `assert_in_merkle_set`.
+pub struct MerkleSetTargets {
+    siblings: Vec<HashOutTarget>,
+    index_bits: Vec<BoolTarget>,
+}
+
+impl MerkleSetTargets {
+    pub fn set_witness(&self, pw: &mut PartialWitness<F>, path: &MerklePath) {
+        for (level, (target, sibling)) in self.siblings.iter().zip(&path.siblings).enumerate() {
+            pw.set_hash_target(*target, *sibling);
+            pw.set_bool_target(self.index_bits[level], (path.index >> level) & 1 == 1);
+        }
+    }
+}
+
+/// Constrains `Poseidon(value)` to be a leaf under `root` (constant or public input).
+pub fn assert_in_merkle_set(
+    builder: &mut CircuitBuilder<F, D>,
+    value: Target,
+    root: HashOutTarget,
+    depth: usize,
+) -> MerkleSetTargets {
+    let siblings: Vec<HashOutTarget> = (0..depth).map(|_| builder.add_virtual_hash()).collect();
+    let index_bits: Vec<BoolTarget> = (0..depth).map(|_| builder.add_virtual_bool_target_safe()).collect();
+    let leaf = builder.hash_n_to_hash_no_pad::<PoseidonHash>(vec![value]);
+    let computed = merkle_root_gadget(builder, leaf, &siblings, &index_bits);
+    builder.connect_hashes(computed, root);
+    MerkleSetTargets { siblings, index_bits }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📚 ALLOWED SET (native side)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// A verifier's public set of allowed values (sorted, deduplicated). The Merkle
+/// tree is only built for sets larger than `SMALL_SET_MAX`.
+pub struct AllowedSet {
+    values: Vec<u64>,
+    tree: Option<MerkleTree>,
+}
+
+impl AllowedSet {
+    pub fn new(values: &[u64]) -> ZkpResult<Self> {
+        let mut values = values.to_vec();
+        values.sort_unstable();
+        values.dedup();
+        if values.is_empty() {
+            return Err(ZkpError::InvalidInput("allowed set must not be empty".into()));
+        }
+        if let Some(bad) = values.iter().find(|v| F::from_noncanonical_u64(**v).to_canonical_u64() != **v) {
+            return Err(ZkpError::InvalidInput(format!("set value {} does not fit the field", bad)));
+        }
+
+        let tree = if values.len() > SMALL_SET_MAX {
+            // depth = ceil(log2(len)); khaali leaves zero rehte hain
+            let depth = (usize::BITS - (values.len() - 1).leading_zeros()) as usize;
+            Some(MerkleTree::from_leaves(depth, values.iter().map(|v| set_leaf(*v)).collect())?)
+        } else {
+            None
+        };
+        Ok(Self { values, tree })
+    }
+
+    pub fn values(&self) -> &[u64] {
+        &self.values
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        self.values.binary_search(&value).is_ok()
+    }
+
+    /// Merkle root, `None` for small (polynomial) sets.
+    pub fn root(&self) -> Option<HashOut<F>> {
+        self.tree.as_ref().map(MerkleTree::root)
+    }
+
+    /// Membership path for `value` (large sets only).
+    pub fn path(&self, value: u64) -> ZkpResult<MerklePath> {
+        let tree = self
+            .tree
+            .as_ref()
+            .ok_or_else(|| ZkpError::InvalidInput("small sets have no merkle path".into()))?;
+        let index = self
+            .values
+            .binary_search(&value)
+            .map_err(|_| ZkpError::InvalidInput("value is not in the allowed set".into()))?;
+        tree.path(index as u64)
+    }
+}
+
+/// What `assert_in_set` left for the prover to fill.
+pub enum SetMembershipTargets {
+    Small,
+    Merkle(MerkleSetTargets),
+}
+
+/// Constrains `value` to `set`, picking polynomial or Merkle form by size.
+pub fn assert_in_set(builder: &mut CircuitBuilder<F, D>, value: Target, set: &AllowedSet) -> SetMembershipTargets {
+    match &set.tree {
+        Some(tree) => {
+            let root = builder.constant_hash(tree.root());
+            SetMembershipTargets::Merkle(assert_in_merkle_set(builder, value, root, tree.depth()))
+        }
+        None => {
+            assert_in_small_set(builder, value, &set.values);
+            SetMembershipTargets::Small
+        }
+    }
+}
+
+impl SetMembershipTargets {
+    /// Prover side: `value` must be in `set` (the same set the circuit was built with).
+    pub fn set_witness(&self, pw: &mut PartialWitness<F>, set: &AllowedSet, value: u64) -> ZkpResult<()> {
+        if !set.contains(value) {
+            return Err(ZkpError::InvalidInput("value is not in the allowed set".into()));
+        }
+        if let SetMembershipTargets::Merkle(targets) = self {
+            targets.set_witness(pw, &set.path(value)?);
+        }
+        Ok(())
+    }
+}
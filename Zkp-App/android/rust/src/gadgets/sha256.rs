@@ -0,0 +1,191 @@
+// SHA-256 compression in-circuit (FIPS 180-4). Poseidon hamara native hash
+// hai, lekin bahar ke formats (SD-JWT disclosures, JWT digests) SHA-256 use
+// karte hain. Har 32-bit word = 32 `BoolTarget`s (index 0 = LSB):
+//   • rotate / shift = sirf wiring, free
+//   • xor = a + b - 2ab, ch = g + e(f - g), maj = ab + c(a xor b)
+//   • mod 2^32 addition: field me jodo, phir `split_le` se low 32 bits
+// Ek block ~ 64 rounds; padding aur block selection caller ka kaam hai.
+
+use plonky2::field::types::Field;
+use plonky2::iop::target::{BoolTarget, Target};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// A 32-bit word, little-endian bits.
+pub type U32Target = [BoolTarget; 32];
+
+pub const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+fn xor(builder: &mut CircuitBuilder<F, D>, a: BoolTarget, b: BoolTarget) -> BoolTarget {
+    // a + b - 2ab
+    let sum = builder.add(a.target, b.target);
+    BoolTarget::new_unsafe(builder.arithmetic(-F::TWO, F::ONE, a.target, b.target, sum))
+}
+
+fn xor3(builder: &mut CircuitBuilder<F, D>, a: &U32Target, b: &U32Target, c: &U32Target) -> U32Target {
+    std::array::from_fn(|i| {
+        let ab = xor(builder, a[i], b[i]);
+        xor(builder, ab, c[i])
+    })
+}
+
+fn rotr(word: &U32Target, n: usize) -> U32Target {
+    std::array::from_fn(|i| word[(i + n) % 32])
+}
+
+fn shr(builder: &mut CircuitBuilder<F, D>, word: &U32Target, n: usize) -> U32Target {
+    let zero = builder._false();
+    std::array::from_fn(|i| if i + n < 32 { word[i + n] } else { zero })
+}
+
+/// `(sum of words + constant) mod 2^32`, for up to 7 words.
+fn add_mod32(builder: &mut CircuitBuilder<F, D>, words: &[&U32Target], constant: u32) -> U32Target {
+    assert!(words.len() < 8, "sum must stay below 2^35");
+    let mut sum = builder.constant(F::from_canonical_u32(constant));
+    for word in words {
+        let value = builder.le_sum(word.iter());
+        sum = builder.add(sum, value);
+    }
+    let bits = builder.split_le(sum, 35);
+    std::array::from_fn(|i| bits[i])
+}
+
+pub fn u32_constant(builder: &mut CircuitBuilder<F, D>, value: u32) -> U32Target {
+    std::array::from_fn(|i| builder.constant_bool((value >> i) & 1 == 1))
+}
+
+/// The word as one field element.
+pub fn u32_value(builder: &mut CircuitBuilder<F, D>, word: &U32Target) -> Target {
+    builder.le_sum(word.iter())
+}
+
+pub fn iv(builder: &mut CircuitBuilder<F, D>) -> [U32Target; 8] {
+    IV.map(|word| u32_constant(builder, word))
+}
+
+/// Big-endian message word from 4 byte bit-vectors (each little-endian, 8 bits).
+pub fn u32_from_be_bytes(bytes: [&[BoolTarget]; 4]) -> U32Target {
+    std::array::from_fn(|i| bytes[3 - i / 8][i % 8])
+}
+
+/// One SHA-256 compression: `state` after absorbing the 16-word `block`.
+pub fn sha256_compress(builder: &mut CircuitBuilder<F, D>, state: &[U32Target; 8], block: &[U32Target; 16]) -> [U32Target; 8] {
+    // Message schedule
+    let mut w: Vec<U32Target> = block.to_vec();
+    for t in 16..64 {
+        let shifted = shr(builder, &w[t - 15], 3);
+        let s0 = xor3(builder, &rotr(&w[t - 15], 7), &rotr(&w[t - 15], 18), &shifted);
+        let shifted = shr(builder, &w[t - 2], 10);
+        let s1 = xor3(builder, &rotr(&w[t - 2], 17), &rotr(&w[t - 2], 19), &shifted);
+        let next = add_mod32(builder, &[&w[t - 16], &s0, &w[t - 7], &s1], 0);
+        w.push(next);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for t in 0..64 {
+        let big_s1 = xor3(builder, &rotr(&e, 6), &rotr(&e, 11), &rotr(&e, 25));
+        let ch: U32Target = std::array::from_fn(|i| {
+            let diff = builder.sub(f[i].target, g[i].target);
+            BoolTarget::new_unsafe(builder.mul_add(e[i].target, diff, g[i].target))
+        });
+        let big_s0 = xor3(builder, &rotr(&a, 2), &rotr(&a, 13), &rotr(&a, 22));
+        let maj: U32Target = std::array::from_fn(|i| {
+            let ab = builder.and(a[i], b[i]);
+            let a_xor_b = xor(builder, a[i], b[i]);
+            BoolTarget::new_unsafe(builder.mul_add(c[i].target, a_xor_b.target, ab.target))
+        });
+
+        // temp1 = h + S1 + ch + K + w ; e' = d + temp1 ; a' = temp1 + S0 + maj
+        let new_e = add_mod32(builder, &[&d, &h, &big_s1, &ch, &w[t]], K[t]);
+        let new_a = add_mod32(builder, &[&h, &big_s1, &ch, &w[t], &big_s0, &maj], K[t]);
+        h = g;
+        g = f;
+        f = e;
+        e = new_e;
+        d = c;
+        c = b;
+        b = a;
+        a = new_a;
+    }
+
+    let out = [a, b, c, d, e, f, g, h];
+    std::array::from_fn(|i| add_mod32(builder, &[&state[i], &out[i]], 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use plonky2::field::types::PrimeField64;
+    use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+    use plonky2::plonk::circuit_data::CircuitConfig;
+    use sha2::{Digest, Sha256};
+
+    /// FIPS 180-4 padding: 0x80, zeros, 64-bit big-endian bit length.
+    fn padded(message: &[u8]) -> Vec<u8> {
+        let mut bytes = message.to_vec();
+        bytes.push(0x80);
+        while bytes.len() % 64 != 56 {
+            bytes.push(0);
+        }
+        bytes.extend((message.len() as u64 * 8).to_be_bytes());
+        bytes
+    }
+
+    /// Digest of `message` as computed by `sha256_compress` over witness bits.
+    fn circuit_sha256(message: &[u8]) -> Vec<u8> {
+        let bytes = padded(message);
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let bits: Vec<Vec<BoolTarget>> =
+            bytes.iter().map(|_| (0..8).map(|_| builder.add_virtual_bool_target_safe()).collect()).collect();
+        let mut state = iv(&mut builder);
+        for block in bits.chunks_exact(64) {
+            let words: [U32Target; 16] =
+                std::array::from_fn(|w| u32_from_be_bytes([&block[w * 4], &block[w * 4 + 1], &block[w * 4 + 2], &block[w * 4 + 3]]));
+            state = sha256_compress(&mut builder, &state, &words);
+        }
+        for word in &state {
+            let value = u32_value(&mut builder, word);
+            builder.register_public_input(value);
+        }
+        let data = builder.build::<C>();
+
+        let mut pw = PartialWitness::new();
+        for (byte, targets) in bytes.iter().zip(&bits) {
+            for (i, bit) in targets.iter().enumerate() {
+                pw.set_bool_target(*bit, (byte >> i) & 1 == 1);
+            }
+        }
+        let proof = data.prove(pw).unwrap();
+        proof.public_inputs.iter().flat_map(|w| (w.to_canonical_u64() as u32).to_be_bytes()).collect()
+    }
+
+    #[test]
+    fn matches_sha2_known_answers() {
+        let disclosure = format!("WyJ{}", "a".repeat(180));
+        let messages: [&[u8]; 6] = [b"", b"abc", &[b'x'; 55], &[b'x'; 56], &[b'x'; 64], disclosure.as_bytes()];
+        for message in messages {
+            assert_eq!(circuit_sha256(message), Sha256::digest(message).to_vec(), "{} bytes", message.len());
+        }
+        // 55 = ek block, 56 aur 64 = do, 183 = teen
+        assert_eq!(padded(&[0; 55]).len(), 64);
+        assert_eq!(padded(&[0; 56]).len(), 128);
+        assert_eq!(padded(disclosure.as_bytes()).len(), 192);
+    }
+}
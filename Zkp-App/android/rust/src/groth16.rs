@@ -0,0 +1,381 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔷 GROTH16 VERIFIER (snarkjs / Circom, BN254)
+// day14 (PIN), age aur day18 (Merkle) jaise Circom circuits ke proofs ab phone par
+// hi verify ho jaate hain, plonky2 ke bagal me. Input exactly wahi JSON files jo
+// snarkjs likhta hai:
+//   • verification_key.json  (`snarkjs zkey export verificationkey`)
+//   • proof.json + public.json (`snarkjs groth16 prove`)
+// Field elements decimal strings hain; points projective [x, y, z] with z = 1
+// (z = 0 = point at infinity). G2 coords [c0, c1] order me, jaise snarkjs deta hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::str::FromStr;
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger256, PrimeField};
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, VerifyingKey};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ZkpError, ZkpResult};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📄 SNARKJS JSON SHAPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// `verification_key.json` as written by snarkjs.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SnarkjsVerifyingKey {
+    pub protocol: String,
+    pub curve: String,
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    pub vk_alpha_1: Vec<String>,
+    pub vk_beta_2: Vec<Vec<String>>,
+    pub vk_gamma_2: Vec<Vec<String>>,
+    pub vk_delta_2: Vec<Vec<String>>,
+    #[serde(rename = "IC")]
+    pub ic: Vec<Vec<String>>,
+}
+
+/// `proof.json` as written by snarkjs.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SnarkjsProof {
+    pub pi_a: Vec<String>,
+    pub pi_b: Vec<Vec<String>>,
+    pub pi_c: Vec<String>,
+    #[serde(default = "default_protocol")]
+    pub protocol: String,
+    #[serde(default = "default_curve")]
+    pub curve: String,
+}
+
+impl SnarkjsVerifyingKey {
+    /// Same JSON snarkjs `zkey export verificationkey` writes (minus `vk_alphabeta_12`).
+    pub fn from_ark(vk: &VerifyingKey<Bn254>) -> Self {
+        Self {
+            protocol: default_protocol(),
+            curve: default_curve(),
+            n_public: vk.gamma_abc_g1.len() - 1,
+            vk_alpha_1: g1_json(&vk.alpha_g1),
+            vk_beta_2: g2_json(&vk.beta_g2),
+            vk_gamma_2: g2_json(&vk.gamma_g2),
+            vk_delta_2: g2_json(&vk.delta_g2),
+            ic: vk.gamma_abc_g1.iter().map(g1_json).collect(),
+        }
+    }
+}
+
+impl SnarkjsProof {
+    pub fn from_ark(proof: &Proof<Bn254>) -> Self {
+        Self {
+            pi_a: g1_json(&proof.a),
+            pi_b: g2_json(&proof.b),
+            pi_c: g1_json(&proof.c),
+            protocol: default_protocol(),
+            curve: default_curve(),
+        }
+    }
+}
+
+fn default_protocol() -> String {
+    "groth16".into()
+}
+
+fn default_curve() -> String {
+    "bn128".into()
+}
+
+/// Outcome of `check`, serialized for the CLI / JNI.
+#[derive(Serialize, Debug)]
+pub struct Groth16Report {
+    pub verified: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub public_signals: Vec<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔑 PREPARED VERIFIER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// A parsed + pairing-prepared verification key; reuse it for many proofs.
+pub struct Groth16Verifier {
+    pvk: PreparedVerifyingKey<Bn254>,
+    n_public: usize,
+}
+
+impl Groth16Verifier {
+    /// From an already-parsed arkworks key (e.g. the one inside a `.zkey`).
+    pub fn from_ark(vk: &VerifyingKey<Bn254>) -> Self {
+        Self { pvk: ark_groth16::prepare_verifying_key(vk), n_public: vk.gamma_abc_g1.len() - 1 }
+    }
+
+    pub fn from_json(vk_json: &str) -> ZkpResult<Self> {
+        let raw: SnarkjsVerifyingKey = serde_json::from_str(vk_json)?;
+        Self::from_snarkjs(&raw)
+    }
+
+    pub fn from_snarkjs(raw: &SnarkjsVerifyingKey) -> ZkpResult<Self> {
+        check_protocol(&raw.protocol, &raw.curve)?;
+        if raw.ic.len() != raw.n_public + 1 {
+            return Err(ZkpError::InvalidInput(format!(
+                "verification key has {} IC points for nPublic = {}",
+                raw.ic.len(),
+                raw.n_public
+            )));
+        }
+
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: g1(&raw.vk_alpha_1, "vk_alpha_1")?,
+            beta_g2: g2(&raw.vk_beta_2, "vk_beta_2")?,
+            gamma_g2: g2(&raw.vk_gamma_2, "vk_gamma_2")?,
+            delta_g2: g2(&raw.vk_delta_2, "vk_delta_2")?,
+            gamma_abc_g1: raw
+                .ic
+                .iter()
+                .enumerate()
+                .map(|(i, p)| g1(p, &format!("IC[{}]", i)))
+                .collect::<ZkpResult<_>>()?,
+        };
+
+        Ok(Self::from_ark(&vk))
+    }
+
+    /// Number of public signals this key expects.
+    pub fn n_public(&self) -> usize {
+        self.n_public
+    }
+
+    /// `Ok(())` = valid; `ProofRejected` = well-formed but false; anything else = bad input.
+    pub fn verify(&self, proof: &SnarkjsProof, public_signals: &[String]) -> ZkpResult<()> {
+        check_protocol(&proof.protocol, &proof.curve)?;
+        if public_signals.len() != self.n_public {
+            return Err(ZkpError::InvalidInput(format!(
+                "expected {} public signals, got {}",
+                self.n_public,
+                public_signals.len()
+            )));
+        }
+
+        // Proof points bahar se aate hain: curve + subgroup check zaroori
+        let proof = Proof::<Bn254> {
+            a: g1(&proof.pi_a, "pi_a").map_err(as_corrupt)?,
+            b: g2(&proof.pi_b, "pi_b").map_err(as_corrupt)?,
+            c: g1(&proof.pi_c, "pi_c").map_err(as_corrupt)?,
+        };
+        let inputs = public_signals
+            .iter()
+            .enumerate()
+            .map(|(i, s)| field::<Fr>(s, &format!("public[{}]", i)))
+            .collect::<ZkpResult<Vec<_>>>()?;
+
+        match Groth16::<Bn254>::verify_proof(&self.pvk, &proof, &inputs) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(ZkpError::ProofRejected("groth16 pairing check failed".into())),
+            Err(e) => Err(ZkpError::ProofRejected(format!("groth16: {}", e))),
+        }
+    }
+
+    /// Same as `verify`, straight from the `proof.json` / `public.json` text.
+    pub fn verify_json(&self, proof_json: &str, public_json: &str) -> ZkpResult<()> {
+        let proof: SnarkjsProof = serde_json::from_str(proof_json)?;
+        let public: Vec<String> = serde_json::from_str(public_json)?;
+        self.verify(&proof, &public)
+    }
+}
+
+/// One-shot check of the three snarkjs files.
+pub fn verify_snarkjs(vk_json: &str, proof_json: &str, public_json: &str) -> ZkpResult<()> {
+    Groth16Verifier::from_json(vk_json)?.verify_json(proof_json, public_json)
+}
+
+/// A rejected proof is `verified: false`; unreadable files are errors.
+pub fn check(vk_json: &str, proof_json: &str, public_json: &str) -> ZkpResult<Groth16Report> {
+    let public_signals: Vec<String> = serde_json::from_str(public_json)?;
+    let (verified, reason) = match verify_snarkjs(vk_json, proof_json, public_json) {
+        Ok(()) => (true, None),
+        Err(ZkpError::ProofRejected(reason)) => (false, Some(reason)),
+        Err(e) => return Err(e),
+    };
+    Ok(Groth16Report { verified, reason, public_signals })
+}
+
+/// `check` as JSON (JNI).
+pub fn verify_report(vk_json: &str, proof_json: &str, public_json: &str) -> ZkpResult<String> {
+    let report = check(vk_json, proof_json, public_json)?;
+    serde_json::to_string(&report).map_err(|e| ZkpError::Serialization(e.to_string()))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔢 PARSING HELPERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn check_protocol(protocol: &str, curve: &str) -> ZkpResult<()> {
+    if protocol != "groth16" {
+        return Err(ZkpError::InvalidInput(format!("unsupported protocol '{}'", protocol)));
+    }
+    // snarkjs BN254 ko "bn128" bolta hai
+    if !matches!(curve, "bn128" | "bn254") {
+        return Err(ZkpError::InvalidInput(format!("unsupported curve '{}'", curve)));
+    }
+    Ok(())
+}
+
+fn as_corrupt(e: ZkpError) -> ZkpError {
+    match e {
+        ZkpError::InvalidInput(m) => ZkpError::CorruptProof(m),
+        other => other,
+    }
+}
+
+/// Canonical decimal (< modulus) -> field element. Reduced values are refused,
+/// otherwise one proof would verify under several encodings.
+fn field<P: PrimeField<BigInt = BigInteger256>>(decimal: &str, what: &str) -> ZkpResult<P> {
+    let value = BigUint::from_str(decimal)
+        .map_err(|_| ZkpError::InvalidInput(format!("{}: '{}' is not a decimal integer", what, decimal)))?;
+    BigInteger256::try_from(value)
+        .ok()
+        .and_then(P::from_bigint)
+        .ok_or_else(|| ZkpError::InvalidInput(format!("{}: value is not below the field modulus", what)))
+}
+
+/// `[x, y, z]`: z = "1" is an affine point, z = "0" is infinity.
+fn g1(coords: &[String], what: &str) -> ZkpResult<G1Affine> {
+    let [x, y, z] = coords else {
+        return Err(ZkpError::InvalidInput(format!("{}: expected [x, y, z]", what)));
+    };
+    if is_zero(z) {
+        return Ok(G1Affine::zero());
+    }
+    if z != "1" {
+        return Err(ZkpError::InvalidInput(format!("{}: point is not normalized (z != 1)", what)));
+    }
+    let point = G1Affine::new_unchecked(field::<Fq>(x, what)?, field::<Fq>(y, what)?);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ZkpError::InvalidInput(format!("{}: not a valid G1 point", what)));
+    }
+    Ok(point)
+}
+
+/// `[[x.c0, x.c1], [y.c0, y.c1], [z.c0, z.c1]]`.
+fn g2(coords: &[Vec<String>], what: &str) -> ZkpResult<G2Affine> {
+    let [x, y, z] = coords else {
+        return Err(ZkpError::InvalidInput(format!("{}: expected [x, y, z]", what)));
+    };
+    let fq2 = |pair: &[String]| -> ZkpResult<Fq2> {
+        let [c0, c1] = pair else {
+            return Err(ZkpError::InvalidInput(format!("{}: expected [c0, c1]", what)));
+        };
+        Ok(Fq2::new(field::<Fq>(c0, what)?, field::<Fq>(c1, what)?))
+    };
+
+    let z = fq2(z)?;
+    if z == Fq2::from(0u64) {
+        return Ok(G2Affine::zero());
+    }
+    if z != Fq2::from(1u64) {
+        return Err(ZkpError::InvalidInput(format!("{}: point is not normalized (z != 1)", what)));
+    }
+    let point = G2Affine::new_unchecked(fq2(x)?, fq2(y)?);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ZkpError::InvalidInput(format!("{}: not a valid G2 point", what)));
+    }
+    Ok(point)
+}
+
+fn decimal<P: PrimeField<BigInt = BigInteger256>>(value: &P) -> String {
+    BigUint::from(value.into_bigint()).to_string()
+}
+
+fn g1_json(point: &G1Affine) -> Vec<String> {
+    match point.xy() {
+        Some((x, y)) => vec![decimal(x), decimal(y), "1".into()],
+        None => vec!["0".into(), "1".into(), "0".into()],
+    }
+}
+
+fn g2_json(point: &G2Affine) -> Vec<Vec<String>> {
+    match point.xy() {
+        Some((x, y)) => vec![
+            vec![decimal(&x.c0), decimal(&x.c1)],
+            vec![decimal(&y.c0), decimal(&y.c1)],
+            vec!["1".into(), "0".into()],
+        ],
+        None => vec![vec!["0".into(), "0".into()], vec!["1".into(), "0".into()], vec!["0".into(), "0".into()]],
+    }
+}
+
+fn is_zero(decimal: &str) -> bool {
+    !decimal.is_empty() && decimal.bytes().all(|b| b == b'0')
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::jstring;
+
+    use crate::jni_bridge::{guard, new_string, read_string};
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Groth16_verify(
+        mut env: JNIEnv,
+        _class: JClass,
+        vk_json: JString,
+        proof_json: JString,
+        public_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let vk = read_string(env, &vk_json, "vk_json")?;
+            let proof = read_string(env, &proof_json, "proof_json")?;
+            let public = read_string(env, &public_json, "public_json")?;
+            new_string(env, super::verify_report(&vk, &proof, &public)?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// snarkjs output checked into the repo (day14 = PIN, day18 = Merkle).
+    fn fixture(day: &str, file: &str) -> String {
+        let path = format!("{}/../../../{}/{}", env!("CARGO_MANIFEST_DIR"), day, file);
+        std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+    }
+
+    #[test]
+    fn snarkjs_proofs_verify() {
+        for day in ["day14", "day18"] {
+            let vk = fixture(day, "verification_key.json");
+            verify_snarkjs(&vk, &fixture(day, "proof.json"), &fixture(day, "public.json")).unwrap();
+            let report = check(&vk, &fixture(day, "proof.json"), &fixture(day, "public.json")).unwrap();
+            assert!(report.verified, "{}", day);
+        }
+    }
+
+    #[test]
+    fn changed_public_input_is_rejected() {
+        let vk = fixture("day14", "verification_key.json");
+        let proof = fixture("day14", "proof.json");
+        let mut public: Vec<String> = serde_json::from_str(&fixture("day14", "public.json")).unwrap();
+        let value = BigUint::from_str(&public[0]).unwrap() + 1u32;
+        public[0] = value.to_string();
+        let public = serde_json::to_string(&public).unwrap();
+
+        assert!(matches!(verify_snarkjs(&vk, &proof, &public), Err(ZkpError::ProofRejected(_))));
+        let report = check(&vk, &proof, &public).unwrap();
+        assert!(!report.verified && report.reason.is_some());
+
+        // Galat ginti = bad input, rejection nahi
+        assert!(matches!(verify_snarkjs(&vk, &proof, "[]"), Err(ZkpError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,96 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧾 TYPED IDENTITY REQUESTS
+// `ZkAuthManager.generateZkpProof(json)` ka input. JSON ka "kind" field
+// decide karta hai kaunsa circuit chalega:
+//   {"kind":"balance","balance":50000}
+//   {"kind":"nullifier","secret":"..","domain":"..","challenge":".."}
+//   {"kind":"passport", ...PassportData fields...}
+// ⚠️ Yeh structs secrets carry karte hain: never log them (Debug is redacted).
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::fmt;
+
+use log::info;
+use serde::Deserialize;
+
+use crate::engine::CircuitCache;
+use crate::envelope::{ProofEnvelope, ProofKind};
+use crate::error::{ZkpError, ZkpResult};
+use crate::passport_security::{self, PassportData};
+use crate::proving_jobs::{Phase, ProgressSink};
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IdentityRequest {
+    Balance {
+        balance: u64,
+    },
+    Nullifier {
+        secret: String,
+        domain: String,
+        challenge: String,
+    },
+    Passport(PassportData),
+}
+
+impl IdentityRequest {
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        // serde ke messages me values echo ho sakti hain ("invalid type: string ..."),
+        // isliye sirf position report karo.
+        serde_json::from_str(json).map_err(|e| {
+            ZkpError::MalformedJson(format!(
+                "identity request rejected ({:?}) at line {} column {}",
+                e.classify(),
+                e.line(),
+                e.column()
+            ))
+        })
+    }
+
+    pub fn kind(&self) -> ProofKind {
+        match self {
+            IdentityRequest::Balance { .. } => ProofKind::Balance,
+            IdentityRequest::Nullifier { .. } => ProofKind::Nullifier,
+            IdentityRequest::Passport(_) => ProofKind::Passport,
+        }
+    }
+}
+
+// Sirf kind print hota hai, payload kabhi nahi.
+impl fmt::Debug for IdentityRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IdentityRequest({:?}, <redacted>)", self.kind())
+    }
+}
+
+/// Dispatches to the matching (cached) circuit and returns a sealed envelope.
+pub fn prove_request(
+    request: &IdentityRequest,
+    circuits: &CircuitCache,
+    progress: &mut dyn ProgressSink,
+) -> ZkpResult<ProofEnvelope> {
+    info!("🧾 Proving {:?} request", request.kind());
+
+    progress.enter(Phase::CircuitLoad)?;
+    let (data, proof) = match request {
+        IdentityRequest::Balance { balance } => {
+            let circuit = circuits.balance()?;
+            (&circuit.data, circuit.prove(*balance, progress)?)
+        }
+        IdentityRequest::Nullifier { secret, domain, challenge } => {
+            let circuit = circuits.nullifier();
+            progress.enter(Phase::Prove)?;
+            (&circuit.data, circuit.prove(secret, domain, challenge)?)
+        }
+        IdentityRequest::Passport(passport) => {
+            progress.enter(Phase::Witness)?;
+            let dg1 = passport_security::checked_dg1(passport)?;
+            let circuit = circuits.passport();
+            progress.enter(Phase::Prove)?;
+            (&circuit.data, circuit.prove(&dg1)?)
+        }
+    };
+
+    progress.enter(Phase::Serialize)?;
+    ProofEnvelope::seal(request.kind(), data, &proof)
+}
@@ -0,0 +1,240 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🏦 CREDENTIAL ISSUER (bank side)
+// Partner bank isse balance credentials deta hai:
+//   {holder_commitment, balance, expiry, nonce}  ->  credential.rs `Credential`
+// Holder apna secret kabhi bank ko nahi deta, sirf `holder_commitment(secret)`.
+// Key file (secret key!) bank ke paas rehti hai; verifier app ko sirf
+// `TrustedIssuers` (public keys + version) milta hai, JSON ya QR frames me.
+// CLI: `zkp-cli issue keygen | balance | trust-set | check`.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use plonky2::field::types::PrimeField64;
+
+use crate::credential::Credential;
+use crate::error::{ZkpError, ZkpResult};
+use crate::qr_stream;
+use crate::schnorr::{PublicKey, SecretKey, Signature};
+use crate::zk_auth::hash_to_field;
+
+/// Schema string signed into every balance credential.
+pub const BALANCE_SCHEMA: &str = "zkp.bank.balance.v1";
+
+// Attribute names (slot order = this order)
+pub const ATTR_HOLDER: &str = "holder_commitment";
+pub const ATTR_BALANCE: &str = "balance";
+pub const ATTR_EXPIRY: &str = "expiry";
+pub const ATTR_NONCE: &str = "nonce";
+
+pub fn now_unix() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// What the holder hands the bank instead of their secret.
+pub fn holder_commitment(holder_secret: &str) -> u64 {
+    hash_to_field(&format!("zkp-holder:{}", holder_secret)).to_canonical_u64()
+}
+
+/// The claims inside a balance credential. `expiry` = unix seconds.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalanceClaim {
+    pub holder_commitment: u64,
+    pub balance: u64,
+    pub expiry: u64,
+    pub nonce: u64,
+}
+
+impl BalanceClaim {
+    /// Fresh random nonce, so re-issuing the same balance gives a new credential.
+    pub fn new(holder_commitment: u64, balance: u64, expiry: u64) -> Self {
+        // 63 bits: hamesha Goldilocks field me fit
+        Self { holder_commitment, balance, expiry, nonce: rand::random::<u64>() >> 1 }
+    }
+
+    /// Reads the claims back out of a balance credential (does not check the signature).
+    pub fn from_credential(credential: &Credential) -> ZkpResult<Self> {
+        if credential.schema != BALANCE_SCHEMA {
+            return Err(ZkpError::InvalidInput(format!("not a balance credential: {}", credential.schema)));
+        }
+        let get = |name: &str| -> ZkpResult<u64> {
+            credential
+                .attribute(name)
+                .map(|a| a.value)
+                .ok_or_else(|| ZkpError::InvalidInput(format!("balance credential has no {}", name)))
+        };
+        Ok(Self {
+            holder_commitment: get(ATTR_HOLDER)?,
+            balance: get(ATTR_BALANCE)?,
+            expiry: get(ATTR_EXPIRY)?,
+            nonce: get(ATTR_NONCE)?,
+        })
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expiry
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔑 ISSUER KEYS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Key file JSON: `{"issuer_id", "secret_key", "public_key", "created_at"}`.
+/// ⚠️ Contains the secret key: HSM / vault me rakho, repo ya logs me kabhi nahi.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct IssuerKeyFile {
+    pub issuer_id: String,
+    pub secret_key: String,
+    pub public_key: String,
+    pub created_at: u64,
+}
+
+/// A loaded issuer: id + signing key.
+pub struct Issuer {
+    issuer_id: String,
+    secret: SecretKey,
+}
+
+impl Issuer {
+    pub fn generate(issuer_id: &str) -> ZkpResult<Self> {
+        if issuer_id.trim().is_empty() {
+            return Err(ZkpError::InvalidInput("issuer_id must not be empty".into()));
+        }
+        Ok(Self { issuer_id: issuer_id.to_string(), secret: SecretKey::generate() })
+    }
+
+    pub fn from_key_file(json: &str) -> ZkpResult<Self> {
+        let file: IssuerKeyFile = serde_json::from_str(json)?;
+        let secret = SecretKey::from_hex(&file.secret_key)?;
+        // Galat / edited public_key wali file load hi na ho
+        if secret.public_key() != PublicKey::from_hex(&file.public_key)? {
+            return Err(ZkpError::InvalidInput("key file public_key does not match its secret_key".into()));
+        }
+        Ok(Self { issuer_id: file.issuer_id, secret })
+    }
+
+    pub fn to_key_file(&self) -> IssuerKeyFile {
+        IssuerKeyFile {
+            issuer_id: self.issuer_id.clone(),
+            secret_key: self.secret.to_hex(),
+            public_key: self.public_key().to_hex(),
+            created_at: now_unix(),
+        }
+    }
+
+    pub fn issuer_id(&self) -> &str {
+        &self.issuer_id
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        self.secret.public_key()
+    }
+
+    /// The entry verifiers add to their `TrustedIssuers`.
+    pub fn trusted_entry(&self) -> TrustedIssuer {
+        TrustedIssuer { issuer_id: self.issuer_id.clone(), public_key: self.public_key().to_hex() }
+    }
+
+    /// Any schema (e.g. mdoc.rs re-issuing licence elements).
+    pub fn issue(&self, schema: &str, attributes: &[(&str, u64)]) -> ZkpResult<Credential> {
+        Credential::issue(schema, attributes, &self.secret)
+    }
+
+    pub fn issue_balance(&self, claim: &BalanceClaim) -> ZkpResult<Credential> {
+        if claim.is_expired(now_unix()) {
+            return Err(ZkpError::InvalidInput("credential expiry is in the past".into()));
+        }
+        self.issue(
+            BALANCE_SCHEMA,
+            &[
+                (ATTR_HOLDER, claim.holder_commitment),
+                (ATTR_BALANCE, claim.balance),
+                (ATTR_EXPIRY, claim.expiry),
+                (ATTR_NONCE, claim.nonce),
+            ],
+        )
+    }
+}
+
+/// Holder / QA side: signature valid, issuer trusted, not expired.
+pub fn check_balance_credential(credential: &Credential, trusted: &TrustedIssuers, now: u64) -> ZkpResult<BalanceClaim> {
+    let claim = BalanceClaim::from_credential(credential)?;
+    let issuer = credential.issuer_key()?;
+    if !issuer.verify(&credential.message()?, &Signature::from_hex(&credential.signature)?) {
+        return Err(ZkpError::ProofRejected("credential signature does not verify".into()));
+    }
+    if !trusted.keys()?.contains(&issuer) {
+        return Err(ZkpError::ProofRejected("credential issuer is not trusted".into()));
+    }
+    if claim.is_expired(now) {
+        return Err(ZkpError::ProofRejected(format!("credential expired at {}", claim.expiry)));
+    }
+    Ok(claim)
+}
+
+/// Same `i/n|data` frames as proofs (qr_stream.rs), for handing a credential to the holder's phone.
+pub fn credential_qr_frames(credential: &Credential) -> ZkpResult<Vec<String>> {
+    Ok(qr_stream::split(&credential.to_json()?, qr_stream::QR_CHUNK_SIZE))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📜 TRUSTED ISSUER SET (verifier app ke liye)
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TrustedIssuer {
+    pub issuer_id: String,
+    /// ecgfp5 public key hex (schnorr.rs)
+    pub public_key: String,
+}
+
+/// JSON: `{"version": 3, "issuers": [{"issuer_id", "public_key"}]}`. Verifier
+/// sirf badi `version` wala set accept kare (rollback se purani key wapas na aaye).
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrustedIssuers {
+    pub version: u64,
+    pub issuers: Vec<TrustedIssuer>,
+}
+
+impl TrustedIssuers {
+    /// Keys must parse and issuer ids must be unique.
+    pub fn new(version: u64, issuers: Vec<TrustedIssuer>) -> ZkpResult<Self> {
+        let set = Self { version, issuers };
+        set.keys()?;
+        for (i, issuer) in set.issuers.iter().enumerate() {
+            if set.issuers[..i].iter().any(|other| other.issuer_id == issuer.issuer_id) {
+                return Err(ZkpError::InvalidInput(format!("issuer {} listed twice", issuer.issuer_id)));
+            }
+        }
+        Ok(set)
+    }
+
+    pub fn keys(&self) -> ZkpResult<Vec<PublicKey>> {
+        self.issuers.iter().map(|i| PublicKey::from_hex(&i.public_key)).collect()
+    }
+
+    pub fn find(&self, public_key: &PublicKey) -> Option<&TrustedIssuer> {
+        let hex = public_key.to_hex();
+        self.issuers.iter().find(|i| i.public_key == hex)
+    }
+
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        let set: Self = serde_json::from_str(json)?;
+        Self::new(set.version, set.issuers)
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+
+    pub fn to_qr_frames(&self) -> ZkpResult<Vec<String>> {
+        Ok(qr_stream::split(&self.to_json()?, qr_stream::QR_CHUNK_SIZE))
+    }
+
+    pub fn from_qr_frames<S: AsRef<str>>(frames: &[S]) -> ZkpResult<Self> {
+        Self::from_json(&qr_stream::join(frames)?)
+    }
+}
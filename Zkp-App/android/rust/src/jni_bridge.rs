@@ -0,0 +1,103 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI ADAPTER
+// Har `Java_com_example_zkpapp_*` export apna kaam `guard` ke andar karta hai:
+//   • panics are caught here, never unwind into the JVM
+//   • `Err(ZkpError)` becomes a thrown `com.example.zkpapp.ZkpException`
+//   • the function then returns a harmless fallback (null / 0)
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::panic::{self, AssertUnwindSafe};
+
+use jni::objects::{JString, JThrowable, JValue};
+use jni::sys::{jboolean, jlong, jstring};
+use jni::JNIEnv;
+use log::error;
+
+use crate::error::{ZkpError, ZkpResult};
+
+/// Fully-qualified JNI name of the Kotlin exception class.
+pub const EXCEPTION_CLASS: &str = "com/example/zkpapp/ZkpException";
+const EXCEPTION_CTOR_SIG: &str = "(ILjava/lang/String;)V";
+
+/// Value handed back to Java after an exception has been thrown.
+/// The JVM ignores it because the pending exception wins.
+pub trait JniFallback {
+    fn fallback() -> Self;
+}
+
+impl JniFallback for jstring {
+    fn fallback() -> Self {
+        std::ptr::null_mut()
+    }
+}
+
+impl JniFallback for jboolean {
+    fn fallback() -> Self {
+        0
+    }
+}
+
+impl JniFallback for jlong {
+    fn fallback() -> Self {
+        0
+    }
+}
+
+impl JniFallback for () {
+    fn fallback() -> Self {}
+}
+
+/// Runs `body` behind a panic barrier and converts any failure into a Java exception.
+pub fn guard<'local, T, F>(env: &mut JNIEnv<'local>, body: F) -> T
+where
+    T: JniFallback,
+    F: FnOnce(&mut JNIEnv<'local>) -> ZkpResult<T>,
+{
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| body(&mut *env)));
+
+    let err = match outcome {
+        Ok(Ok(value)) => return value,
+        Ok(Err(e)) => e,
+        Err(payload) => ZkpError::from_panic(payload.as_ref()),
+    };
+
+    error!("❌ [{}] {}", err.code(), err);
+    throw(env, &err);
+    T::fallback()
+}
+
+/// Throws `ZkpException(code, message)`; falls back to `RuntimeException` if the class is missing.
+pub fn throw(env: &mut JNIEnv, err: &ZkpError) {
+    // Koi purani pending exception (e.g. from a failed get_string) ko replace karo,
+    // taaki Kotlin ko hamesha ek hi type mile.
+    if env.exception_check().unwrap_or(false) {
+        let _ = env.exception_clear();
+    }
+
+    let thrown = (|| -> jni::errors::Result<()> {
+        let message = env.new_string(err.to_string())?;
+        let exception = env.new_object(
+            EXCEPTION_CLASS,
+            EXCEPTION_CTOR_SIG,
+            &[JValue::Int(err.code()), JValue::Object(&message)],
+        )?;
+        env.throw(JThrowable::from(exception))
+    })();
+
+    if thrown.is_err() {
+        let _ = env.exception_clear();
+        let _ = env.throw_new("java/lang/RuntimeException", err.to_string());
+    }
+}
+
+/// Reads a Java string argument; `what` names it in the error.
+pub fn read_string(env: &mut JNIEnv, value: &JString, what: &str) -> ZkpResult<String> {
+    env.get_string(value)
+        .map(Into::into)
+        .map_err(|e| ZkpError::Jni(format!("{}: {}", what, e)))
+}
+
+/// Allocates a Java string for returning.
+pub fn new_string(env: &mut JNIEnv, value: impl AsRef<str>) -> ZkpResult<jstring> {
+    Ok(env.new_string(value.as_ref())?.into_raw())
+}
@@ -0,0 +1,483 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🕸️ JSON-LD -> RDF (VC documents ke liye)
+// JSON-LD 1.1 "toRdf" ka woh hissa jo VC Data Model v2 documents ko chahiye:
+//   • contexts: VC v2 URL (andar embedded, koi network fetch nahi), inline
+//     objects (string / {@id, @type, @container} terms), null
+//   • VC / VP type-scoped contexts (non-propagating), `verifiableCredential`
+//     ka property-scoped `@context: null`
+//   • `@container: @graph` (proof, verifiableCredential) -> alag named graph
+//   • values: IRI (@id / @vocab coercion), typed literal, string, bool, integer
+// Baaki (remote contexts, @list, @value objects, language maps, floats,
+// relative IRIs) pe error: chupchaap galat quads se behtar. Canonical form
+// rdfc.rs banata hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::error::{ZkpError, ZkpResult};
+use crate::rdfc::{Quad, Term, RDF_TYPE, XSD_BOOLEAN, XSD_INTEGER, XSD_STRING};
+
+pub const VC_V2_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+
+const CRED: &str = "https://www.w3.org/2018/credentials#";
+const SEC: &str = "https://w3id.org/security#";
+const XSD_DATE_TIME: &str = "http://www.w3.org/2001/XMLSchema#dateTime";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📚 CONTEXTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Coerce {
+    None,
+    Id,
+    Vocab,
+}
+
+/// Scoped contexts of the VC v2 context that we know how to apply.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Scoped {
+    Credential,
+    Presentation,
+    Null,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct TermDef {
+    /// Absolute IRI, or a keyword (`@id` / `@type`) for aliases.
+    iri: String,
+    coerce: Coerce,
+    datatype: Option<String>,
+    graph: bool,
+    scoped: Option<Scoped>,
+}
+
+impl TermDef {
+    fn plain(iri: &str) -> Self {
+        Self { iri: iri.to_string(), coerce: Coerce::None, datatype: None, graph: false, scoped: None }
+    }
+
+    fn id(iri: &str) -> Self {
+        Self { coerce: Coerce::Id, ..Self::plain(iri) }
+    }
+
+    fn graph(iri: &str) -> Self {
+        Self { graph: true, ..Self::id(iri) }
+    }
+
+    fn typed(iri: &str, datatype: &str) -> Self {
+        Self { datatype: Some(datatype.to_string()), ..Self::plain(iri) }
+    }
+
+    fn scoped(iri: &str, scoped: Scoped) -> Self {
+        Self { scoped: Some(scoped), ..Self::plain(iri) }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+struct Context {
+    terms: HashMap<String, TermDef>,
+    vocab: Option<String>,
+    protected: Vec<String>,
+}
+
+impl Context {
+    fn define(&mut self, term: &str, def: TermDef, protect: bool) -> ZkpResult<()> {
+        if self.protected.iter().any(|p| p == term) && self.terms.get(term) != Some(&def) {
+            return Err(ZkpError::InvalidInput(format!("@context redefines protected term {:?}", term)));
+        }
+        self.terms.insert(term.to_string(), def);
+        if protect {
+            self.protected.push(term.to_string());
+        }
+        Ok(())
+    }
+
+    /// Built-in definitions, applied without the protection check.
+    fn extend(&mut self, defs: Vec<(&str, TermDef)>) {
+        for (term, def) in defs {
+            self.terms.insert(term.to_string(), def);
+            self.protected.push(term.to_string());
+        }
+    }
+
+    fn apply_scoped(&self, scoped: Scoped) -> Self {
+        let mut next = self.clone();
+        match scoped {
+            Scoped::Credential => next.extend(credential_terms()),
+            Scoped::Presentation => next.extend(presentation_terms()),
+            Scoped::Null => next = Context::default(),
+        }
+        next
+    }
+
+    /// Term -> absolute IRI (or alias keyword) for a property or `@type` value.
+    fn expand_vocab(&self, value: &str) -> ZkpResult<Option<String>> {
+        if value.starts_with('@') {
+            return Ok(Some(value.to_string()));
+        }
+        if let Some(def) = self.terms.get(value) {
+            return Ok(Some(def.iri.clone()));
+        }
+        if is_absolute_iri(value) {
+            return Ok(Some(value.to_string()));
+        }
+        Ok(self.vocab.as_ref().map(|vocab| format!("{}{}", vocab, value)))
+    }
+
+    fn process(&self, local: &Value) -> ZkpResult<Self> {
+        let mut next = self.clone();
+        let entries = match local {
+            Value::Array(items) => items.clone(),
+            other => vec![other.clone()],
+        };
+        for entry in entries {
+            match entry {
+                Value::Null => next = Context::default(),
+                Value::String(url) if url == VC_V2_CONTEXT => {
+                    next.extend(v2_terms());
+                    next.vocab = Some("https://www.w3.org/ns/credentials/issuer-dependent#".into());
+                }
+                Value::String(url) => {
+                    return Err(ZkpError::InvalidInput(format!("remote @context {:?} is not supported", url)));
+                }
+                Value::Object(map) => next.process_inline(&map)?,
+                other => return Err(ZkpError::InvalidInput(format!("invalid @context entry {}", other))),
+            }
+        }
+        Ok(next)
+    }
+
+    fn process_inline(&mut self, map: &Map<String, Value>) -> ZkpResult<()> {
+        let protect = map.get("@protected").and_then(Value::as_bool).unwrap_or(false);
+        if let Some(vocab) = map.get("@vocab") {
+            self.vocab = vocab.as_str().map(str::to_string);
+        }
+        for (term, value) in map.iter().filter(|(k, _)| !k.starts_with('@')) {
+            let def = match value {
+                Value::String(iri) => TermDef::plain(&self.expand_definition(iri)?),
+                Value::Object(def) => {
+                    let iri = def.get("@id").and_then(Value::as_str).unwrap_or(term);
+                    let mut out = TermDef::plain(&self.expand_definition(iri)?);
+                    match def.get("@type").and_then(Value::as_str) {
+                        None => {}
+                        Some("@id") => out.coerce = Coerce::Id,
+                        Some("@vocab") => out.coerce = Coerce::Vocab,
+                        Some(datatype) => out.datatype = Some(self.expand_definition(datatype)?),
+                    }
+                    match def.get("@container").and_then(Value::as_str) {
+                        None | Some("@set") => {}
+                        Some("@graph") => out.graph = true,
+                        Some(other) => return Err(ZkpError::InvalidInput(format!("@container {} is not supported", other))),
+                    }
+                    if def.contains_key("@context") {
+                        return Err(ZkpError::InvalidInput(format!("scoped context on {:?} is not supported", term)));
+                    }
+                    out
+                }
+                other => return Err(ZkpError::InvalidInput(format!("invalid definition for {:?}: {}", term, other))),
+            };
+            self.define(term, def, protect)?;
+        }
+        Ok(())
+    }
+
+    fn expand_definition(&self, iri: &str) -> ZkpResult<String> {
+        if iri.starts_with('@') || is_absolute_iri(iri) {
+            return Ok(iri.to_string());
+        }
+        self.expand_vocab(iri)?
+            .ok_or_else(|| ZkpError::InvalidInput(format!("term definition {:?} is not an absolute IRI", iri)))
+    }
+}
+
+/// Top level of https://www.w3.org/ns/credentials/v2 (terms relevant to VC / VP documents).
+fn v2_terms() -> Vec<(&'static str, TermDef)> {
+    vec![
+        ("id", TermDef::plain("@id")),
+        ("type", TermDef::plain("@type")),
+        ("description", TermDef::plain("https://schema.org/description")),
+        ("name", TermDef::plain("https://schema.org/name")),
+        ("VerifiableCredential", TermDef::scoped(&format!("{}VerifiableCredential", CRED), Scoped::Credential)),
+        ("VerifiablePresentation", TermDef::scoped(&format!("{}VerifiablePresentation", CRED), Scoped::Presentation)),
+        ("JsonSchema", TermDef::plain("https://www.w3.org/2018/credentials#JsonSchema")),
+    ]
+}
+
+/// Type-scoped context of `VerifiableCredential`.
+fn credential_terms() -> Vec<(&'static str, TermDef)> {
+    vec![
+        ("credentialSchema", TermDef::id(&format!("{}credentialSchema", CRED))),
+        ("credentialStatus", TermDef::id(&format!("{}credentialStatus", CRED))),
+        ("credentialSubject", TermDef::id(&format!("{}credentialSubject", CRED))),
+        ("evidence", TermDef::id(&format!("{}evidence", CRED))),
+        ("issuer", TermDef::id(&format!("{}issuer", CRED))),
+        ("proof", TermDef::graph(&format!("{}proof", SEC))),
+        ("refreshService", TermDef::id(&format!("{}refreshService", CRED))),
+        ("termsOfUse", TermDef::id(&format!("{}termsOfUse", CRED))),
+        ("validFrom", TermDef::typed(&format!("{}validFrom", CRED), XSD_DATE_TIME)),
+        ("validUntil", TermDef::typed(&format!("{}validUntil", CRED), XSD_DATE_TIME)),
+    ]
+}
+
+/// Type-scoped context of `VerifiablePresentation`.
+fn presentation_terms() -> Vec<(&'static str, TermDef)> {
+    let mut credential = TermDef::graph(&format!("{}verifiableCredential", CRED));
+    credential.scoped = Some(Scoped::Null);
+    vec![
+        ("holder", TermDef::id(&format!("{}holder", CRED))),
+        ("proof", TermDef::graph(&format!("{}proof", SEC))),
+        ("termsOfUse", TermDef::id(&format!("{}termsOfUse", CRED))),
+        ("verifiableCredential", credential),
+    ]
+}
+
+fn is_absolute_iri(value: &str) -> bool {
+    match value.split_once(':') {
+        Some((scheme, _)) => {
+            let mut chars = scheme.chars();
+            chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+                && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        }
+        None => false,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔁 TO RDF
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Default)]
+struct ToRdf {
+    quads: Vec<Quad>,
+    blank_nodes: usize,
+}
+
+impl ToRdf {
+    fn fresh_blank(&mut self) -> Term {
+        self.blank_nodes += 1;
+        Term::Blank(format!("b{}", self.blank_nodes - 1))
+    }
+
+    fn emit(&mut self, subject: &Term, predicate: &str, object: Term, graph: &Option<Term>) {
+        self.quads.push(Quad { subject: subject.clone(), predicate: predicate.to_string(), object, graph: graph.clone() });
+    }
+
+    /// Emits a node object's quads into `graph`; returns its subject.
+    fn node(&mut self, active: &Context, node: &Map<String, Value>, graph: &Option<Term>) -> ZkpResult<Term> {
+        let local = match node.get("@context") {
+            Some(context) => active.process(context)?,
+            None => active.clone(),
+        };
+        let alias_of = |key: &str| local.terms.get(key).map(|d| d.iri.as_str()).unwrap_or(key).to_string();
+
+        let mut types: Vec<&str> = Vec::new();
+        let mut subject = None;
+        for (key, value) in node {
+            match alias_of(key).as_str() {
+                "@type" => match value {
+                    Value::String(t) => types.push(t),
+                    Value::Array(items) => {
+                        for item in items {
+                            types.push(item.as_str().ok_or_else(|| ZkpError::InvalidInput("@type must be strings".into()))?);
+                        }
+                    }
+                    _ => return Err(ZkpError::InvalidInput("@type must be strings".into())),
+                },
+                "@id" => {
+                    let id = value.as_str().ok_or_else(|| ZkpError::InvalidInput("@id must be a string".into()))?;
+                    subject = Some(absolute_iri(id)?);
+                }
+                _ => {}
+            }
+        }
+        let subject = match subject {
+            Some(subject) => subject,
+            None => self.fresh_blank(),
+        };
+
+        // Type-scoped contexts: sirf isi node ke liye, bachchon pe nahi
+        let mut typed = local.clone();
+        let mut sorted_types = types.clone();
+        sorted_types.sort();
+        for t in &sorted_types {
+            if let Some(scoped) = local.terms.get(*t).and_then(|d| d.scoped) {
+                typed = typed.apply_scoped(scoped);
+            }
+        }
+        for t in types {
+            let iri = local
+                .expand_vocab(t)?
+                .ok_or_else(|| ZkpError::InvalidInput(format!("type {:?} does not expand to an IRI", t)))?;
+            self.emit(&subject, RDF_TYPE, absolute_iri(&iri)?, graph);
+        }
+
+        for (key, value) in node {
+            if key == "@context" || matches!(alias_of(key).as_str(), "@id" | "@type") {
+                continue;
+            }
+            if key.starts_with('@') {
+                return Err(ZkpError::InvalidInput(format!("{} is not supported", key)));
+            }
+            let Some(predicate) = typed.expand_vocab(key)? else {
+                continue; // JSON-LD undefined terms drop kar deta hai
+            };
+            let def = typed.terms.get(key).cloned().unwrap_or_else(|| TermDef::plain(&predicate));
+            let child = match def.scoped {
+                Some(scoped) => local.apply_scoped(scoped),
+                None => local.clone(),
+            };
+
+            let items = match value {
+                Value::Array(items) => items.as_slice(),
+                other => std::slice::from_ref(other),
+            };
+            for item in items {
+                if let Some(object) = self.object(&def, &child, &typed, item, graph)? {
+                    self.emit(&subject, &predicate, object, graph);
+                }
+            }
+        }
+        Ok(subject)
+    }
+
+    /// Object of one property value (`None` for JSON null). Nested nodes are
+    /// emitted on the way; `@graph` containers get their own blank graph name.
+    fn object(
+        &mut self,
+        def: &TermDef,
+        child: &Context,
+        typed: &Context,
+        value: &Value,
+        graph: &Option<Term>,
+    ) -> ZkpResult<Option<Term>> {
+        let object = match value {
+            Value::Null => return Ok(None),
+            Value::Object(map) if map.contains_key("@value") || map.contains_key("@list") => {
+                return Err(ZkpError::InvalidInput("@value / @list objects are not supported".into()));
+            }
+            Value::Object(map) if def.graph => {
+                let name = self.fresh_blank();
+                self.node(child, map, &Some(name.clone()))?;
+                name
+            }
+            Value::Object(map) => self.node(child, map, graph)?,
+            Value::String(text) => match (def.coerce, &def.datatype) {
+                (Coerce::Id, _) => absolute_iri(text)?,
+                (Coerce::Vocab, _) => {
+                    let iri = typed
+                        .expand_vocab(text)?
+                        .ok_or_else(|| ZkpError::InvalidInput(format!("{:?} does not expand to an IRI", text)))?;
+                    absolute_iri(&iri)?
+                }
+                (Coerce::None, Some(datatype)) => Term::literal(text.as_str(), datatype),
+                (Coerce::None, None) => Term::literal(text.as_str(), XSD_STRING),
+            },
+            Value::Bool(flag) => Term::literal(flag.to_string(), XSD_BOOLEAN),
+            Value::Number(number) if number.is_i64() || number.is_u64() => Term::literal(number.to_string(), XSD_INTEGER),
+            Value::Number(number) => {
+                return Err(ZkpError::InvalidInput(format!("non-integer number {} is not supported", number)));
+            }
+            Value::Array(_) => return Err(ZkpError::InvalidInput("nested arrays are not supported".into())),
+        };
+        Ok(Some(object))
+    }
+}
+
+/// No base IRI here, so relative references cannot be resolved: error
+/// instead of JSON-LD's silent drop.
+fn absolute_iri(value: &str) -> ZkpResult<Term> {
+    if let Some(label) = value.strip_prefix("_:") {
+        return Ok(Term::Blank(format!("x{}", label)));
+    }
+    if !is_absolute_iri(value) || value.chars().any(|c| c <= ' ' || "<>\"{}|^`\\".contains(c)) {
+        return Err(ZkpError::InvalidInput(format!("{:?} is not an absolute IRI", value)));
+    }
+    Ok(Term::Iri(value.to_string()))
+}
+
+/// JSON-LD document (one top-level node object) -> RDF dataset.
+pub fn to_rdf(document: &Value) -> ZkpResult<Vec<Quad>> {
+    let Value::Object(node) = document else {
+        return Err(ZkpError::InvalidInput("JSON-LD document must be an object".into()));
+    };
+    let mut out = ToRdf::default();
+    out.node(&Context::default(), node, &None)?;
+    Ok(out.quads)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::rdfc::canonicalize;
+
+    #[test]
+    fn vc_v2_terms_expand_and_graph_containers_split() {
+        let doc = json!({
+            "@context": [VC_V2_CONTEXT],
+            "type": ["VerifiablePresentation"],
+            "verifiableCredential": [{
+                "@context": [VC_V2_CONTEXT],
+                "type": ["VerifiableCredential"],
+                "issuer": "urn:example:issuer",
+                "credentialSubject": { "age": 21, "adult": true }
+            }]
+        });
+        let out = canonicalize(&to_rdf(&doc).unwrap()).unwrap();
+
+        assert!(out.contains(&format!("<{}> <{}VerifiablePresentation> .", RDF_TYPE, CRED)));
+        assert!(out.contains(&format!("<{}verifiableCredential> _:c14n", CRED)));
+        assert!(out.contains(&format!("<{}issuer> <urn:example:issuer> _:c14n", CRED)));
+        assert!(out.contains(&format!(
+            "<https://www.w3.org/ns/credentials/issuer-dependent#age> \"21\"^^<{}> _:c14n",
+            XSD_INTEGER
+        )));
+        assert!(out.contains(&format!("\"true\"^^<{}>", XSD_BOOLEAN)));
+    }
+
+    #[test]
+    fn type_scoped_terms_do_not_leak_into_children() {
+        // `issuer` sirf VerifiableCredential node pe defined hai
+        let doc = json!({
+            "@context": [VC_V2_CONTEXT],
+            "type": "VerifiableCredential",
+            "credentialSubject": { "issuer": "not an IRI here" }
+        });
+        let out = canonicalize(&to_rdf(&doc).unwrap()).unwrap();
+        assert!(out.contains("<https://www.w3.org/ns/credentials/issuer-dependent#issuer> \"not an IRI here\""));
+    }
+
+    #[test]
+    fn inline_context_and_protection() {
+        let doc = json!({
+            "@context": [VC_V2_CONTEXT, { "digest": "urn:example#digest", "when": { "@id": "urn:example#when", "@type": "http://www.w3.org/2001/XMLSchema#dateTime" } }],
+            "digest": "abc",
+            "when": "2026-01-01T00:00:00Z"
+        });
+        let out = canonicalize(&to_rdf(&doc).unwrap()).unwrap();
+        assert!(out.contains("<urn:example#digest> \"abc\" ."));
+        assert!(out.contains(&format!("\"2026-01-01T00:00:00Z\"^^<{}>", XSD_DATE_TIME)));
+
+        let redefined = json!({ "@context": [VC_V2_CONTEXT, { "name": "urn:example#name" }], "name": "x" });
+        assert!(to_rdf(&redefined).is_err());
+    }
+
+    #[test]
+    fn unsupported_input_is_an_error() {
+        assert!(to_rdf(&json!({ "@context": "https://example.org/ctx", "a": 1 })).is_err());
+        assert!(to_rdf(&json!({ "@context": [VC_V2_CONTEXT], "id": "relative/path" })).is_err());
+        assert!(to_rdf(&json!({ "@context": [VC_V2_CONTEXT], "x": 1.5 })).is_err());
+        assert!(to_rdf(&json!([])).is_err());
+    }
+
+    #[test]
+    fn key_order_does_not_change_the_canonical_form() {
+        let a: Value = serde_json::from_str(r#"{"@context":["https://www.w3.org/ns/credentials/v2"],"type":"VerifiableCredential","issuer":"urn:i","credentialSubject":{"x":1,"y":[2,3]}}"#).unwrap();
+        let b: Value = serde_json::from_str(r#"{"credentialSubject":{"y":[3,2],"x":1},"issuer":"urn:i","type":["VerifiableCredential"],"@context":"https://www.w3.org/ns/credentials/v2"}"#).unwrap();
+        assert_eq!(canonicalize(&to_rdf(&a).unwrap()).unwrap(), canonicalize(&to_rdf(&b).unwrap()).unwrap());
+    }
+}
@@ -0,0 +1,57 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧓 LEGACY ABI (feature = "legacy-abi")
+// Purane `Zkp-App/rust_layer` crate ke entry points, ab isi crate ke circuits
+// par. Old APKs ke liye hi enable karo: yeh `VerifierActivity.verifyProofFromRust`
+// ka String version replace kar deta hai (same JNI symbol, boolean return).
+// ═══════════════════════════════════════════════════════════════════════════
+
+use jni::JNIEnv;
+use jni::objects::{JClass, JString};
+use jni::sys::{jboolean, jstring, JNI_FALSE, JNI_TRUE};
+use log::{info, warn};
+
+use crate::capi::{self, status_result};
+use crate::jni_bridge::{guard, new_string, read_string};
+use crate::offline_identity;
+use crate::proving_jobs::NoProgress;
+use crate::qr_stream::LEGACY_QR_CHUNK_SIZE;
+
+/// Old prover: same balance proof, 500-char frames.
+#[no_mangle]
+pub extern "system" fn Java_com_example_zkpapp_MainActivity_stringFromRust(
+    mut env: JNIEnv,
+    _class: JClass,
+) -> jstring {
+    crate::init_logger();
+    info!("🧓 LEGACY PROVER: Request Received");
+
+    guard(&mut env, |env| {
+        let json = offline_identity::prove_balance_chunks(LEGACY_QR_CHUNK_SIZE, &mut NoProgress)?;
+        new_string(env, json)
+    })
+}
+
+/// Old verifier: `true` only for a valid proof. Bad input is `false`, as before,
+/// but panics are still caught by `guard`.
+#[no_mangle]
+pub extern "system" fn Java_com_example_zkpapp_VerifierActivity_verifyProofFromRust(
+    mut env: JNIEnv,
+    _class: JClass,
+    proof_str: JString,
+) -> jboolean {
+    crate::init_logger();
+
+    guard(&mut env, |env| {
+        let proof_base64 = read_string(env, &proof_str, "proof")?;
+
+        // SAFETY: `proof_base64` outlives the call.
+        let status = unsafe { capi::zkp_verify_balance_proof(proof_base64.as_ptr(), proof_base64.len()) };
+        Ok(match status_result(status) {
+            Ok(()) => JNI_TRUE,
+            Err(e) => {
+                warn!("🧓 LEGACY VERIFIER: [{}] {}", e.code(), e);
+                JNI_FALSE
+            }
+        })
+    })
+}
@@ -1,10 +1,10 @@
-use jni::JNIEnv;
-use jni::objects::{JClass, JString};
-use jni::sys::jstring;
+#[cfg(feature = "jni")]
 use android_logger::Config;
-use log::{LevelFilter, info};
+#[cfg(feature = "jni")]
+use log::LevelFilter;
 
-// 1. Logger Setup
+// 1. Logger Setup (sirf ek baar; har JNI export isko call karta hai)
+#[cfg(feature = "jni")]
 fn init_logger() {
     let _ = android_logger::init_once(
         Config::default().with_max_level(LevelFilter::Debug).with_tag("RustZKP_Main"),
@@ -12,8 +12,45 @@ fn init_logger() {
 }
 
 // 2. Module Declarations
+#[cfg(feature = "capi")]
+pub mod capi;
+pub mod challenge;
+#[cfg(feature = "circom")]
+pub mod circom;
+pub mod credential;
+pub mod ecgfp5;
+pub mod engine;
+pub mod envelope;
+pub mod error;
+pub mod gadgets;
+#[cfg(feature = "groth16")]
+pub mod groth16;
+pub mod identity_request;
+pub mod issuer;
+#[cfg(feature = "jni")]
+pub mod jni_bridge;
+pub mod jsonld;
+#[cfg(feature = "legacy-abi")]
+mod legacy;
+#[cfg(feature = "mdoc")]
+pub mod mdoc;
+pub mod merkle;
 pub mod offline_identity;
 pub mod passport_security;
+pub mod policy;
+pub mod proving_jobs;
+#[cfg(feature = "qr-render")]
+pub mod qr_image;
+pub mod qr_stream;
+pub mod rdfc;
+pub mod revocation;
+pub mod schnorr;
+#[cfg(feature = "sd-jwt")]
+pub mod sdjwt;
+#[cfg(test)]
+mod test_util;
+pub mod trust_bundle;
+pub mod vc;
 pub mod zk_auth;
 
 // =========================================================
@@ -21,30 +58,47 @@ pub mod zk_auth;
 // Note: Function names MUST match your Java/Kotlin package
 // =========================================================
 
-#[no_mangle]
-pub extern "C" fn Java_com_example_zkpapp_ZkAuthManager_initRust(
-    _env: JNIEnv,
-    _class: JClass,
-) {
-    init_logger();
-    info!("🦁 Rust ZKP Engine Initialized!");
-}
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::jstring;
+    use log::info;
+
+    use crate::engine::CircuitCache;
+    use crate::identity_request::{prove_request, IdentityRequest};
+    use crate::jni_bridge::{guard, new_string, read_string};
+    use crate::proving_jobs::NoProgress;
+
+    // Kotlin: `com.example.zkpapp.auth.ZkAuthManager`
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_auth_ZkAuthManager_initRust(
+        mut env: JNIEnv,
+        _class: JClass,
+    ) {
+        guard(&mut env, |_| {
+            crate::init_logger();
+            info!("🦁 Rust ZKP Engine Initialized!");
+            Ok(())
+        })
+    }
 
-#[no_mangle]
-pub extern "C" fn Java_com_example_zkpapp_ZkAuthManager_generateZkpProof(
-    mut env: JNIEnv,
-    _class: JClass,
-    identity_json: JString,
-) -> jstring {
-    // 🦁 Yeh line Plonky2 ko link karegi
-    // Hum zk_auth module se function call kar rahe hain
-    let input: String = env.get_string(&identity_json).expect("Invalid JSON").into();
-    
-    info!("Generating Proof for: {}", input);
-
-    // Placeholder for calling your actual ZK logic
-    // let proof = zk_auth::prove_identity(input); 
-    
-    let response = format!("Proof Generated for {}", input);
-    env.new_string(response).expect("Failed to create string").into_raw()
-}
\ No newline at end of file
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_auth_ZkAuthManager_generateZkpProof(
+        mut env: JNIEnv,
+        _class: JClass,
+        identity_json: JString,
+    ) -> jstring {
+        guard(&mut env, |env| {
+            // ⚠️ Input me secrets hain: yahan kuch bhi log mat karna except kind
+            let input = read_string(env, &identity_json, "identity_json")?;
+            let request = IdentityRequest::from_json(&input)?;
+
+            // One-shot path; long-lived callers should use ZkpEngine (engine.rs)
+            let envelope = prove_request(&request, &CircuitCache::default(), &mut NoProgress)?;
+            info!("✅ {:?} proof generated", envelope.kind);
+
+            new_string(env, envelope.to_json()?)
+        })
+    }
+}
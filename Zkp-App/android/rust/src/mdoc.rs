@@ -0,0 +1,958 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🚗 ISO 18013-5 MDOC (mobile driving licence) INGESTION
+// Wallet se `IssuerSigned` CBOR aata hai:
+//   { "nameSpaces": { ns: [ #6.24(bstr IssuerSignedItem) ] },
+//     "issuerAuth": COSE_Sign1( payload = #6.24(bstr MobileSecurityObject) ) }
+// Check order: COSE_Sign1 signature (ES256 / ES384, curve alg se match, DS cert
+// from x5chain, EKU = mdoc DS) -> DS cert IACA trust list ke kisi root se
+// signed -> MSO docType + validity window -> har disclosed item ka digest
+// (jaise bytes aaye the, re-encode karke nahi) MSO ke `valueDigests` me.
+// `Document` (DeviceResponse ka) bhi chalega, tab uska docType bhi match ho.
+// P-256 circuit me verify karna abhi possible nahi, isliye bridge: bank /
+// backend (issuer.rs) mdoc natively verify karke wahi elements ek ecgfp5-signed
+// `Credential` me re-issue karta hai (`derive_credential`). Uske baad same
+// selective disclosure (credential.rs) aur predicate (policy.rs) circuits;
+// verifier bridge issuer ko `TrustedIssuers` me trust karta hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::BTreeMap;
+
+use base64::{Engine as _, engine::general_purpose};
+use ciborium::value::Value;
+use coset::{iana, AsCborValue, CoseSign1, Label, RegisteredLabelWithPrivate};
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use x509_cert::der::oid::ObjectIdentifier;
+use x509_cert::der::{Decode, DecodePem, Encode};
+use x509_cert::ext::pkix::ExtendedKeyUsage;
+use x509_cert::Certificate;
+
+use crate::credential::{text_value, Credential, MAX_ATTRIBUTES};
+use crate::error::{ZkpError, ZkpResult};
+use crate::gadgets::comparison::parse_yyyymmdd;
+use crate::issuer::Issuer;
+use crate::trust_bundle::TrustBundle;
+
+pub const MDL_DOC_TYPE: &str = "org.iso.18013.5.1.mDL";
+pub const MDL_NAMESPACE: &str = "org.iso.18013.5.1";
+/// Attribute every derived credential gets: MSO `validUntil` (unix seconds).
+pub const ATTR_VALID_UNTIL: &str = "valid_until";
+
+/// COSE header label of the certificate chain (RFC 9360).
+const X5CHAIN: i64 = 33;
+/// CBOR tags: embedded CBOR, full-date.
+const TAG_ENCODED_CBOR: u64 = 24;
+const TAG_FULL_DATE: u64 = 1004;
+/// Nesting limit for the raw CBOR walk (IssuerSigned is ~4 levels deep).
+const MAX_CBOR_DEPTH: usize = 32;
+
+/// extKeyUsage of an mdoc Document Signer certificate (18013-5 Annex B).
+const MDOC_DS_EKU: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.0.18013.5.1.2");
+/// Uncompressed SEC1 point sizes.
+const P256_POINT: usize = 65;
+const P384_POINT: usize = 97;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🏛️ IACA TRUST LIST
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IacaEntry {
+    pub name: String,
+    #[serde(default)]
+    pub country: Option<String>,
+    /// PEM, or base64 of the DER.
+    pub certificate: String,
+}
+
+/// JSON: `{"version": 2, "iacas": [{"name", "country", "certificate"}]}`.
+/// Local file, shipped with the app / backend; no network lookups.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct IacaTrustList {
+    pub version: u64,
+    pub iacas: Vec<IacaEntry>,
+}
+
+impl IacaEntry {
+    fn parse(&self) -> ZkpResult<Certificate> {
+        let text = self.certificate.trim();
+        let cert = if text.starts_with("-----BEGIN") {
+            Certificate::from_pem(text.as_bytes())
+        } else {
+            Certificate::from_der(&general_purpose::STANDARD.decode(text)?)
+        };
+        cert.map_err(|e| ZkpError::InvalidEncoding(format!("IACA {}: {}", self.name, e)))
+    }
+}
+
+impl IacaTrustList {
+    /// Every certificate must parse.
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        let list: Self = serde_json::from_str(json)?;
+        list.certificates()?;
+        Ok(list)
+    }
+
+    /// Roots from a signed trust bundle: its CSCA entries are the IACAs.
+    pub fn from_bundle(bundle: &TrustBundle) -> ZkpResult<Self> {
+        let iacas = bundle
+            .csca
+            .iter()
+            .map(|c| IacaEntry { name: c.name.clone(), country: c.country.clone(), certificate: c.certificate.clone() })
+            .collect();
+        let list = Self { version: bundle.version, iacas };
+        list.certificates()?;
+        Ok(list)
+    }
+
+    fn certificates(&self) -> ZkpResult<Vec<(&IacaEntry, Certificate)>> {
+        self.iacas.iter().map(|entry| Ok((entry, entry.parse()?))).collect()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📄 VERIFIED MDOC
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// A disclosed element value. Byte strings (portrait, signatures) only keep their length.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ElementValue {
+    Text(String),
+    /// `full-date`, `YYYY-MM-DD`.
+    Date(String),
+    Integer(i64),
+    Bool(bool),
+    Bytes(usize),
+    /// Arrays / maps (e.g. `driving_privileges`), as CBOR diagnostic-ish text.
+    Other(String),
+}
+
+impl ElementValue {
+    fn from_cbor(value: &Value) -> Self {
+        match value {
+            Value::Text(text) => ElementValue::Text(text.clone()),
+            Value::Tag(TAG_FULL_DATE, inner) => match inner.as_ref() {
+                Value::Text(date) => ElementValue::Date(date.clone()),
+                other => ElementValue::Other(format!("{:?}", other)),
+            },
+            Value::Bool(flag) => ElementValue::Bool(*flag),
+            Value::Integer(n) => i64::try_from(i128::from(*n))
+                .map(ElementValue::Integer)
+                .unwrap_or_else(|_| ElementValue::Other(i128::from(*n).to_string())),
+            Value::Bytes(bytes) => ElementValue::Bytes(bytes.len()),
+            other => ElementValue::Other(format!("{:?}", other)),
+        }
+    }
+
+    /// Credential attribute encoding: text via `text_value`, dates as YYYYMMDD
+    /// (gadgets/comparison.rs), bools as 0 / 1.
+    pub fn attribute_value(&self, name: &str) -> ZkpResult<u64> {
+        let unsupported = || ZkpError::InvalidInput(format!("element {} cannot go into a credential", name));
+        match self {
+            ElementValue::Text(text) => Ok(text_value(text)),
+            ElementValue::Date(date) => {
+                let digits: String = date.chars().filter(|c| *c != '-').collect();
+                let value: u64 = digits.parse().map_err(|_| unsupported())?;
+                if date.len() != 10 || digits.len() != 8 {
+                    return Err(unsupported());
+                }
+                parse_yyyymmdd(value)?;
+                Ok(value)
+            }
+            ElementValue::Integer(n) => u64::try_from(*n).map_err(|_| unsupported()),
+            ElementValue::Bool(flag) => Ok(u64::from(*flag)),
+            ElementValue::Bytes(_) | ElementValue::Other(_) => Err(unsupported()),
+        }
+    }
+}
+
+/// What survived every check. Element keys are `elementIdentifier`s.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VerifiedMdoc {
+    pub doc_type: String,
+    /// Trust list entry the DS certificate chains to.
+    pub iaca: String,
+    /// DS certificate subject.
+    pub signer: String,
+    /// MSO validity info, unix seconds.
+    pub signed: u64,
+    pub valid_from: u64,
+    pub valid_until: u64,
+    pub elements: BTreeMap<String, ElementValue>,
+}
+
+/// Verifies an `IssuerSigned` structure, or a `Document` wrapping one, for
+/// `doc_type` against `trust` at time `now` (unix seconds).
+pub fn verify_issuer_signed(bytes: &[u8], doc_type: &str, trust: &IacaTrustList, now: u64) -> ZkpResult<VerifiedMdoc> {
+    let root: Value = ciborium::de::from_reader(bytes).map_err(|e| ZkpError::InvalidEncoding(format!("IssuerSigned: {}", e)))?;
+    let (root, raw) = if field(&root, "issuerSigned").is_ok() {
+        let claimed = text(field(&root, "docType")?, "docType")?;
+        if claimed != doc_type {
+            return Err(ZkpError::ProofRejected(format!("document is a {}, expected {}", claimed, doc_type)));
+        }
+        (field(&root, "issuerSigned")?.clone(), raw_field(bytes, "issuerSigned")?)
+    } else {
+        (root, bytes)
+    };
+    let issuer_auth = CoseSign1::from_cbor_value(field(&root, "issuerAuth")?.clone())
+        .map_err(|e| ZkpError::InvalidEncoding(format!("issuerAuth: {:?}", e)))?;
+
+    // 1. COSE_Sign1 signature, DS certificate ki key se
+    let ds_cert = ds_certificate(&issuer_auth)?;
+    let ds_key = public_key_bytes(&ds_cert)?;
+    let alg = issuer_auth.protected.header.alg.clone();
+    issuer_auth.verify_signature(b"", |signature, data| {
+        let (prehash, point) = match &alg {
+            Some(RegisteredLabelWithPrivate::Assigned(iana::Algorithm::ES256)) => (Sha256::digest(data).to_vec(), P256_POINT),
+            Some(RegisteredLabelWithPrivate::Assigned(iana::Algorithm::ES384)) => (Sha384::digest(data).to_vec(), P384_POINT),
+            _ => return Err(ZkpError::SignatureInvalid(format!("unsupported issuerAuth algorithm {:?}", alg))),
+        };
+        // ES256 = P-256, ES384 = P-384; curve alag hua to `alg` ka koi matlab nahi
+        if ds_key.len() != point {
+            return Err(ZkpError::SignatureInvalid(format!("{:?} does not match the DS key's curve", alg)));
+        }
+        verify_ecdsa(&ds_key, &prehash, signature, false)
+    })?;
+
+    // 2. DS certificate -> IACA
+    check_ds_usage(&ds_cert)?;
+    let iaca = trusted_iaca(&ds_cert, trust, now)?;
+
+    // 3. MSO
+    let payload = issuer_auth
+        .payload
+        .as_deref()
+        .ok_or_else(|| ZkpError::InvalidEncoding("issuerAuth has a detached payload".into()))?;
+    let mso = embedded_cbor(&ciborium::de::from_reader(payload).map_err(|e| ZkpError::InvalidEncoding(format!("MSO: {}", e)))?)?;
+    let signed_doc_type = text(field(&mso, "docType")?, "docType")?;
+    if signed_doc_type != doc_type {
+        return Err(ZkpError::ProofRejected(format!("MSO is for {}, expected {}", signed_doc_type, doc_type)));
+    }
+    let validity = field(&mso, "validityInfo")?;
+    let signed = tdate(field(validity, "signed")?)?;
+    let valid_from = tdate(field(validity, "validFrom")?)?;
+    let valid_until = tdate(field(validity, "validUntil")?)?;
+    if now < valid_from || now >= valid_until {
+        return Err(ZkpError::ProofRejected(format!("mdoc is not valid now (valid {}..{})", valid_from, valid_until)));
+    }
+
+    // 4. Har disclosed item ka digest MSO me hona chahiye
+    let algorithm = text(field(&mso, "digestAlgorithm")?, "digestAlgorithm")?;
+    let value_digests = field(&mso, "valueDigests")?;
+    let mut elements = BTreeMap::new();
+    // Digest wire bytes ka hai: ciborium se re-encode karne par length / float encoding badal sakti hai
+    for (namespace, items) in raw_entries(raw_field(raw, "nameSpaces")?, "nameSpaces")? {
+        let namespace: Value = decode(namespace, "namespace")?;
+        let namespace = text(&namespace, "namespace")?;
+        let digests = field(value_digests, namespace)?;
+        for encoded in raw_items(items, &format!("nameSpaces.{}", namespace))? {
+            let item = embedded_cbor(&decode(encoded, "IssuerSignedItemBytes")?)?;
+            let digest_id = field(&item, "digestID")?;
+            let expected = map_entries(digests, "valueDigests")?
+                .iter()
+                .find(|(id, _)| id == digest_id)
+                .and_then(|(_, digest)| digest.as_bytes())
+                .ok_or_else(|| ZkpError::IntegrityCheckFailed(format!("{}: digestID {:?} not in MSO", namespace, digest_id)))?;
+            if digest(algorithm, encoded)? != *expected {
+                return Err(ZkpError::IntegrityCheckFailed(format!("{}: item digest does not match MSO", namespace)));
+            }
+
+            let identifier = text(field(&item, "elementIdentifier")?, "elementIdentifier")?;
+            let value = ElementValue::from_cbor(field(&item, "elementValue")?);
+            // Alag namespaces me same naam hua to policy clause ambiguous ho jaata
+            if elements.insert(identifier.to_string(), value).is_some() {
+                return Err(ZkpError::InvalidInput(format!("element {} disclosed twice", identifier)));
+            }
+        }
+    }
+
+    Ok(VerifiedMdoc {
+        doc_type: doc_type.to_string(),
+        iaca: iaca.name.clone(),
+        signer: ds_cert.tbs_certificate.subject.to_string(),
+        signed,
+        valid_from,
+        valid_until,
+        elements,
+    })
+}
+
+/// Bridge issuer side: re-issues `elements` (+ `valid_until`) as an ecgfp5
+/// credential with schema = docType, for the credential / policy circuits.
+pub fn derive_credential(mdoc: &VerifiedMdoc, elements: &[&str], issuer: &Issuer) -> ZkpResult<Credential> {
+    if elements.is_empty() || elements.len() + 1 > MAX_ATTRIBUTES {
+        return Err(ZkpError::InvalidInput(format!("pick 1..={} elements", MAX_ATTRIBUTES - 1)));
+    }
+    let mut attributes = Vec::with_capacity(elements.len() + 1);
+    for name in elements {
+        let value = mdoc
+            .elements
+            .get(*name)
+            .ok_or_else(|| ZkpError::InvalidInput(format!("element {} was not disclosed", name)))?;
+        attributes.push((*name, value.attribute_value(name)?));
+    }
+    attributes.push((ATTR_VALID_UNTIL, mdoc.valid_until));
+    issuer.issue(&mdoc.doc_type, &attributes)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔏 CERTIFICATES & SIGNATURES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// First certificate of the x5chain header (protected or unprotected).
+fn ds_certificate(sign1: &CoseSign1) -> ZkpResult<Certificate> {
+    let chain = sign1
+        .protected
+        .header
+        .rest
+        .iter()
+        .chain(&sign1.unprotected.rest)
+        .find(|(label, _)| *label == Label::Int(X5CHAIN))
+        .map(|(_, value)| value)
+        .ok_or_else(|| ZkpError::InvalidEncoding("issuerAuth has no x5chain".into()))?;
+    let leaf = match chain {
+        Value::Bytes(der) => der,
+        Value::Array(certs) => certs
+            .first()
+            .and_then(Value::as_bytes)
+            .ok_or_else(|| ZkpError::InvalidEncoding("empty x5chain".into()))?,
+        _ => return Err(ZkpError::InvalidEncoding("x5chain must be bytes".into())),
+    };
+    Certificate::from_der(leaf).map_err(|e| ZkpError::InvalidEncoding(format!("DS certificate: {}", e)))
+}
+
+/// Uncompressed SEC1 point from the certificate's SubjectPublicKeyInfo.
+fn public_key_bytes(cert: &Certificate) -> ZkpResult<Vec<u8>> {
+    cert.tbs_certificate
+        .subject_public_key_info
+        .subject_public_key
+        .as_bytes()
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| ZkpError::InvalidEncoding("certificate key is not byte aligned".into()))
+}
+
+/// P-256 or P-384 by key size. `der` = X.509 (DER) vs COSE (raw r || s) signature.
+fn verify_ecdsa(public_key: &[u8], prehash: &[u8], signature: &[u8], der: bool) -> ZkpResult<()> {
+    let bad = |e: p256::ecdsa::Error| ZkpError::SignatureInvalid(e.to_string());
+    match public_key.len() {
+        P256_POINT => {
+            let key = p256::ecdsa::VerifyingKey::from_sec1_bytes(public_key).map_err(bad)?;
+            let sig = if der { p256::ecdsa::Signature::from_der(signature) } else { p256::ecdsa::Signature::from_slice(signature) };
+            key.verify_prehash(prehash, &sig.map_err(bad)?).map_err(bad)
+        }
+        P384_POINT => {
+            let key = p384::ecdsa::VerifyingKey::from_sec1_bytes(public_key).map_err(bad)?;
+            let sig = if der { p384::ecdsa::Signature::from_der(signature) } else { p384::ecdsa::Signature::from_slice(signature) };
+            key.verify_prehash(prehash, &sig.map_err(bad)?).map_err(bad)
+        }
+        n => Err(ZkpError::SignatureInvalid(format!("unsupported public key ({} bytes)", n))),
+    }
+}
+
+/// DS certificates must carry the mdoc DS extKeyUsage: a TLS / other cert from the
+/// same IACA must not be able to sign MSOs.
+fn check_ds_usage(ds_cert: &Certificate) -> ZkpResult<()> {
+    let usage = ds_cert
+        .tbs_certificate
+        .get::<ExtendedKeyUsage>()
+        .map_err(|e| ZkpError::InvalidEncoding(format!("DS certificate extKeyUsage: {}", e)))?;
+    match usage {
+        Some((_, ExtendedKeyUsage(purposes))) if purposes.contains(&MDOC_DS_EKU) => Ok(()),
+        _ => Err(ZkpError::ProofRejected("DS certificate is not an mdoc document signer (extKeyUsage)".into())),
+    }
+}
+
+fn cert_valid_at(cert: &Certificate, now: u64) -> bool {
+    let validity = &cert.tbs_certificate.validity;
+    validity.not_before.to_unix_duration().as_secs() <= now && now <= validity.not_after.to_unix_duration().as_secs()
+}
+
+/// The trust list entry whose certificate signed `ds_cert`. Both must be valid at `now`.
+fn trusted_iaca<'a>(ds_cert: &Certificate, trust: &'a IacaTrustList, now: u64) -> ZkpResult<&'a IacaEntry> {
+    if !cert_valid_at(ds_cert, now) {
+        return Err(ZkpError::ProofRejected("DS certificate is expired or not yet valid".into()));
+    }
+    let tbs = ds_cert.tbs_certificate.to_der().map_err(|e| ZkpError::Serialization(e.to_string()))?;
+    let prehash = match ds_cert.signature_algorithm.oid.to_string().as_str() {
+        "1.2.840.10045.4.3.2" => Sha256::digest(&tbs).to_vec(),
+        "1.2.840.10045.4.3.3" => Sha384::digest(&tbs).to_vec(),
+        "1.2.840.10045.4.3.4" => Sha512::digest(&tbs).to_vec(),
+        other => return Err(ZkpError::SignatureInvalid(format!("unsupported DS certificate signature {}", other))),
+    };
+    let signature = ds_cert
+        .signature
+        .as_bytes()
+        .ok_or_else(|| ZkpError::InvalidEncoding("DS certificate signature is not byte aligned".into()))?;
+
+    for (entry, iaca) in trust.certificates()? {
+        if iaca.tbs_certificate.subject != ds_cert.tbs_certificate.issuer || !cert_valid_at(&iaca, now) {
+            continue;
+        }
+        if verify_ecdsa(&public_key_bytes(&iaca)?, &prehash, signature, true).is_ok() {
+            return Ok(entry);
+        }
+    }
+    Err(ZkpError::ProofRejected(format!(
+        "DS certificate ({}) does not chain to a trusted IACA",
+        ds_cert.tbs_certificate.issuer
+    )))
+}
+
+fn digest(algorithm: &str, bytes: &[u8]) -> ZkpResult<Vec<u8>> {
+    Ok(match algorithm {
+        "SHA-256" => Sha256::digest(bytes).to_vec(),
+        "SHA-384" => Sha384::digest(bytes).to_vec(),
+        "SHA-512" => Sha512::digest(bytes).to_vec(),
+        other => return Err(ZkpError::InvalidInput(format!("unsupported digestAlgorithm {}", other))),
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧮 CBOR HELPERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn map_entries<'a>(value: &'a Value, what: &str) -> ZkpResult<&'a [(Value, Value)]> {
+    match value {
+        Value::Map(entries) => Ok(entries),
+        _ => Err(ZkpError::InvalidEncoding(format!("{} must be a map", what))),
+    }
+}
+
+fn field<'a>(map: &'a Value, key: &str) -> ZkpResult<&'a Value> {
+    map_entries(map, key)?
+        .iter()
+        .find(|(k, _)| k.as_text() == Some(key))
+        .map(|(_, v)| v)
+        .ok_or_else(|| ZkpError::InvalidEncoding(format!("missing {}", key)))
+}
+
+fn text<'a>(value: &'a Value, what: &str) -> ZkpResult<&'a str> {
+    value.as_text().ok_or_else(|| ZkpError::InvalidEncoding(format!("{} must be text", what)))
+}
+
+fn decode(bytes: &[u8], what: &str) -> ZkpResult<Value> {
+    ciborium::de::from_reader(bytes).map_err(|e| ZkpError::InvalidEncoding(format!("{}: {}", what, e)))
+}
+
+/// CBOR head at `pos`: major type, argument (`None` = indefinite length), head size.
+fn raw_head(bytes: &[u8], pos: usize) -> ZkpResult<(u8, Option<u64>, usize)> {
+    let truncated = || ZkpError::InvalidEncoding("truncated CBOR".into());
+    let first = *bytes.get(pos).ok_or_else(truncated)?;
+    let (major, info) = (first >> 5, first & 0x1f);
+    let size = match info {
+        0..=23 => return Ok((major, Some(u64::from(info)), 1)),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        // 0xff (break) bhi yahin aata hai, major 7
+        31 if major >= 2 && major != 6 => return Ok((major, None, 1)),
+        _ => return Err(ZkpError::InvalidEncoding(format!("bad CBOR head 0x{:02x}", first))),
+    };
+    let argument = bytes.get(pos + 1..pos + 1 + size).ok_or_else(truncated)?;
+    Ok((major, Some(argument.iter().fold(0, |acc, b| acc << 8 | u64::from(*b))), 1 + size))
+}
+
+/// End offset of the data item starting at `pos`.
+fn raw_item_end(bytes: &[u8], pos: usize, depth: usize) -> ZkpResult<usize> {
+    if depth > MAX_CBOR_DEPTH {
+        return Err(ZkpError::InvalidEncoding("CBOR nested too deep".into()));
+    }
+    let (major, argument, head) = raw_head(bytes, pos)?;
+    let mut end = pos + head;
+    match (major, argument) {
+        (0 | 1 | 7, Some(_)) => {}
+        (2 | 3, Some(len)) => {
+            end = usize::try_from(len).ok().and_then(|len| end.checked_add(len)).unwrap_or(usize::MAX);
+        }
+        (4 | 5, Some(count)) => {
+            let items = if major == 5 { count.saturating_mul(2) } else { count };
+            // Har item kam se kam ek byte: bada count truncation par jaldi fail hota hai
+            for _ in 0..items {
+                end = raw_item_end(bytes, end, depth + 1)?;
+            }
+        }
+        (6, Some(_)) => end = raw_item_end(bytes, end, depth + 1)?,
+        (2..=5, None) => {
+            while bytes.get(end) != Some(&0xff) {
+                end = raw_item_end(bytes, end, depth + 1)?;
+            }
+            end += 1;
+        }
+        _ => return Err(ZkpError::InvalidEncoding("unexpected CBOR break".into())),
+    }
+    if end > bytes.len() {
+        return Err(ZkpError::InvalidEncoding("truncated CBOR".into()));
+    }
+    Ok(end)
+}
+
+/// Raw bytes of each child of the array / map (`major` 4 / 5) at the start of `bytes`.
+fn raw_children<'a>(bytes: &'a [u8], major: u8, what: &str) -> ZkpResult<Vec<&'a [u8]>> {
+    let (found, count, head) = raw_head(bytes, 0)?;
+    if found != major {
+        let kind = if major == 5 { "a map" } else { "an array" };
+        return Err(ZkpError::InvalidEncoding(format!("{} must be {}", what, kind)));
+    }
+    let count = count.map(|n| if major == 5 { n.saturating_mul(2) } else { n });
+    let (mut children, mut pos) = (Vec::new(), head);
+    loop {
+        let done = match count {
+            Some(n) => children.len() as u64 == n,
+            None => bytes.get(pos) == Some(&0xff),
+        };
+        if done {
+            return Ok(children);
+        }
+        let end = raw_item_end(bytes, pos, 1)?;
+        children.push(&bytes[pos..end]);
+        pos = end;
+    }
+}
+
+fn raw_items<'a>(bytes: &'a [u8], what: &str) -> ZkpResult<Vec<&'a [u8]>> {
+    raw_children(bytes, 4, what)
+}
+
+/// Map entries as (raw key, raw value).
+fn raw_entries<'a>(bytes: &'a [u8], what: &str) -> ZkpResult<Vec<(&'a [u8], &'a [u8])>> {
+    Ok(raw_children(bytes, 5, what)?.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect())
+}
+
+/// Raw bytes of the text-keyed `key` in the map at the start of `bytes`.
+fn raw_field<'a>(bytes: &'a [u8], key: &str) -> ZkpResult<&'a [u8]> {
+    for (raw_key, value) in raw_entries(bytes, key)? {
+        if decode(raw_key, key)?.as_text() == Some(key) {
+            return Ok(value);
+        }
+    }
+    Err(ZkpError::InvalidEncoding(format!("missing {}", key)))
+}
+
+/// `#6.24(bstr .cbor T)` -> T.
+fn embedded_cbor(value: &Value) -> ZkpResult<Value> {
+    match value {
+        Value::Tag(TAG_ENCODED_CBOR, inner) => match inner.as_ref() {
+            Value::Bytes(bytes) => ciborium::de::from_reader(bytes.as_slice())
+                .map_err(|e| ZkpError::InvalidEncoding(format!("embedded CBOR: {}", e))),
+            _ => Err(ZkpError::InvalidEncoding("tag 24 must wrap bytes".into())),
+        },
+        _ => Err(ZkpError::InvalidEncoding("expected #6.24 embedded CBOR".into())),
+    }
+}
+
+/// `tdate` (`#6.0("2026-01-31T12:00:00Z")`) -> unix seconds. UTC only, as 18013-5 requires.
+fn tdate(value: &Value) -> ZkpResult<u64> {
+    let raw = match value {
+        Value::Tag(0, inner) => inner.as_text(),
+        other => other.as_text(),
+    }
+    .ok_or_else(|| ZkpError::InvalidEncoding("tdate must be text".into()))?;
+    let bad = || ZkpError::InvalidEncoding(format!("bad tdate {:?}", raw));
+
+    let stamp = raw.strip_suffix('Z').ok_or_else(bad)?;
+    let stamp = stamp.split('.').next().unwrap_or(stamp);
+    let (date, time) = stamp.split_once('T').ok_or_else(bad)?;
+    let numbers = |part: &str, sep: char| -> Option<Vec<i64>> { part.split(sep).map(|n| n.parse().ok()).collect() };
+    let (Some(date), Some(time)) = (numbers(date, '-'), numbers(time, ':')) else {
+        return Err(bad());
+    };
+    let (&[year, month, day], &[hour, minute, second]) = (date.as_slice(), time.as_slice()) else {
+        return Err(bad());
+    };
+    // Year 0..=9999 (RFC 3339 ka 4-digit year): iske bina i64 arithmetic overflow ho sakta hai
+    let in_range = (0..=9999).contains(&year)
+        && (1..=12).contains(&month)
+        && (1..=31).contains(&day)
+        && (0..=23).contains(&hour)
+        && (0..=59).contains(&minute)
+        && (0..=60).contains(&second);
+    if !in_range {
+        return Err(bad());
+    }
+
+    // Howard Hinnant ka days_from_civil
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    u64::try_from(days * 86_400 + hour * 3_600 + minute * 60 + second).map_err(|_| bad())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use base64::{Engine as _, engine::general_purpose};
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::jstring;
+    use log::info;
+
+    use crate::error::ZkpError;
+    use crate::issuer::now_unix;
+    use crate::jni_bridge::{guard, new_string, read_string};
+
+    /// `issuerSignedB64` = base64 of the `IssuerSigned` (or `Document`) CBOR;
+    /// returns `VerifiedMdoc` JSON.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Mdoc_verify(
+        mut env: JNIEnv,
+        _class: JClass,
+        issuer_signed_b64: JString,
+        doc_type: JString,
+        iaca_trust_list_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            // ⚠️ Licence data hai: elements log mat karna
+            let bytes = general_purpose::STANDARD.decode(read_string(env, &issuer_signed_b64, "issuer_signed")?.trim())?;
+            let doc_type = read_string(env, &doc_type, "doc_type")?;
+            let trust = super::IacaTrustList::from_json(&read_string(env, &iaca_trust_list_json, "iaca_trust_list")?)?;
+
+            let mdoc = super::verify_issuer_signed(&bytes, &doc_type, &trust, now_unix())?;
+            info!("✅ mdoc verified ({}, {} elements)", mdoc.doc_type, mdoc.elements.len());
+            let json = serde_json::to_string(&mdoc).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+            new_string(env, json)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use coset::{CoseSign1Builder, HeaderBuilder};
+    use p256::ecdsa::signature::hazmat::PrehashSigner;
+    use p256::ecdsa::signature::Signer;
+    use x509_cert::certificate::{TbsCertificate, Version};
+    use x509_cert::der::asn1::{BitString, OctetString, UtcTime};
+    use x509_cert::der::oid::AssociatedOid;
+    use x509_cert::der::Any;
+    use x509_cert::ext::Extension;
+    use x509_cert::serial_number::SerialNumber;
+    use x509_cert::spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
+    use x509_cert::time::{Time, Validity};
+
+    use super::*;
+    use crate::trust_bundle::CscaEntry;
+
+    /// 2026-01-31T12:00:00Z, inside every validity window below.
+    const NOW: u64 = 1_769_860_800;
+    const SERVER_AUTH: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.3.1");
+
+    fn t(text: &str) -> Value {
+        Value::Text(text.into())
+    }
+
+    fn encode(value: &Value) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(value, &mut bytes).unwrap();
+        bytes
+    }
+
+    enum DsKey {
+        P256(p256::ecdsa::SigningKey),
+        P384(p384::ecdsa::SigningKey),
+    }
+
+    impl DsKey {
+        fn spki(&self) -> SubjectPublicKeyInfoOwned {
+            let (curve, point) = match self {
+                DsKey::P256(key) => ("1.2.840.10045.3.1.7", key.verifying_key().to_encoded_point(false).as_bytes().to_vec()),
+                DsKey::P384(key) => ("1.3.132.0.34", key.verifying_key().to_encoded_point(false).as_bytes().to_vec()),
+            };
+            SubjectPublicKeyInfoOwned {
+                algorithm: AlgorithmIdentifierOwned {
+                    oid: ObjectIdentifier::new_unwrap("1.2.840.10045.2.1"),
+                    parameters: Some(Any::encode_from(&ObjectIdentifier::new_unwrap(curve)).unwrap()),
+                },
+                subject_public_key: BitString::from_bytes(&point).unwrap(),
+            }
+        }
+
+        /// COSE signature (raw r || s) with the hash `alg` names, whatever the key's curve.
+        fn sign(&self, alg: iana::Algorithm, data: &[u8]) -> Vec<u8> {
+            let prehash = match alg {
+                iana::Algorithm::ES384 => Sha384::digest(data).to_vec(),
+                _ => Sha256::digest(data).to_vec(),
+            };
+            match self {
+                DsKey::P256(key) => PrehashSigner::<p256::ecdsa::Signature>::sign_prehash(key, &prehash).unwrap().to_vec(),
+                DsKey::P384(key) => PrehashSigner::<p384::ecdsa::Signature>::sign_prehash(key, &prehash).unwrap().to_vec(),
+            }
+        }
+    }
+
+    /// ecdsa-with-SHA256 certificate valid 2025..2030, signed by `signer`.
+    fn certificate(
+        subject: &str,
+        issuer: &str,
+        spki: SubjectPublicKeyInfoOwned,
+        signer: &p256::ecdsa::SigningKey,
+        eku: Option<ObjectIdentifier>,
+    ) -> Certificate {
+        let time = |unix| Time::UtcTime(UtcTime::from_unix_duration(Duration::from_secs(unix)).unwrap());
+        let algorithm = AlgorithmIdentifierOwned { oid: ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2"), parameters: None };
+        let extensions = eku.map(|purpose| {
+            let value = ExtendedKeyUsage(vec![purpose]).to_der().unwrap();
+            vec![Extension { extn_id: ExtendedKeyUsage::OID, critical: true, extn_value: OctetString::new(value).unwrap() }]
+        });
+        let tbs_certificate = TbsCertificate {
+            version: Version::V3,
+            serial_number: SerialNumber::new(&[1]).unwrap(),
+            signature: algorithm.clone(),
+            issuer: issuer.parse().unwrap(),
+            validity: Validity { not_before: time(1_735_689_600), not_after: time(1_893_456_000) },
+            subject: subject.parse().unwrap(),
+            subject_public_key_info: spki,
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions,
+        };
+        let signature: p256::ecdsa::Signature = signer.sign(&tbs_certificate.to_der().unwrap());
+        Certificate {
+            tbs_certificate,
+            signature_algorithm: algorithm,
+            signature: BitString::from_bytes(signature.to_der().as_bytes()).unwrap(),
+        }
+    }
+
+    /// IACA -> DS chain plus an mDL issuer that signs MSOs with it.
+    struct Fixture {
+        iaca_cert: Certificate,
+        ds: DsKey,
+        ds_cert: Certificate,
+        alg: iana::Algorithm,
+        doc_type: &'static str,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self::with_ds(DsKey::P256(p256::ecdsa::SigningKey::from_slice(&[2; 32]).unwrap()), MDOC_DS_EKU)
+        }
+
+        fn with_ds(ds: DsKey, eku: ObjectIdentifier) -> Self {
+            let iaca = p256::ecdsa::SigningKey::from_slice(&[1; 32]).unwrap();
+            let iaca_name = "CN=Test IACA,C=IN";
+            let iaca_cert = certificate(iaca_name, iaca_name, DsKey::P256(iaca.clone()).spki(), &iaca, None);
+            let ds_cert = certificate("CN=Test DS,C=IN", iaca_name, ds.spki(), &iaca, Some(eku));
+            Self { iaca_cert, ds, ds_cert, alg: iana::Algorithm::ES256, doc_type: MDL_DOC_TYPE }
+        }
+
+        fn trust(&self) -> IacaTrustList {
+            let certificate = general_purpose::STANDARD.encode(self.iaca_cert.to_der().unwrap());
+            IacaTrustList { version: 1, iacas: vec![IacaEntry { name: "test-iaca".into(), country: Some("IN".into()), certificate }] }
+        }
+
+        /// COSE_Sign1 over an MSO listing the digests of `items` (tagged item bytes).
+        fn issuer_auth(&self, items: &[Vec<u8>]) -> Value {
+            let digests = items
+                .iter()
+                .map(|raw| {
+                    let item = embedded_cbor(&decode(raw, "item").unwrap()).unwrap();
+                    (field(&item, "digestID").unwrap().clone(), Value::Bytes(Sha256::digest(raw).to_vec()))
+                })
+                .collect();
+            let date = |raw: &str| Value::Tag(0, Box::new(t(raw)));
+            let mso = Value::Map(vec![
+                (t("version"), t("1.0")),
+                (t("digestAlgorithm"), t("SHA-256")),
+                (t("valueDigests"), Value::Map(vec![(t(MDL_NAMESPACE), Value::Map(digests))])),
+                (t("docType"), t(self.doc_type)),
+                (
+                    t("validityInfo"),
+                    Value::Map(vec![
+                        (t("signed"), date("2026-01-01T00:00:00Z")),
+                        (t("validFrom"), date("2026-01-01T00:00:00Z")),
+                        (t("validUntil"), date("2027-01-01T00:00:00Z")),
+                    ]),
+                ),
+            ]);
+            CoseSign1Builder::new()
+                .protected(HeaderBuilder::new().algorithm(self.alg).build())
+                .unprotected(HeaderBuilder::new().value(X5CHAIN, Value::Bytes(self.ds_cert.to_der().unwrap())).build())
+                .payload(encode(&Value::Tag(TAG_ENCODED_CBOR, Box::new(Value::Bytes(encode(&mso))))))
+                .create_signature(b"", |data| self.ds.sign(self.alg, data))
+                .build()
+                .to_cbor_value()
+                .unwrap()
+        }
+
+        fn issuer_signed(&self, items: &[Vec<u8>]) -> Vec<u8> {
+            assemble(items, &self.issuer_auth(items))
+        }
+    }
+
+    /// `IssuerSigned` written by hand, so item bytes go out exactly as given.
+    fn assemble(items: &[Vec<u8>], issuer_auth: &Value) -> Vec<u8> {
+        let mut bytes = vec![0xa2];
+        bytes.extend(encode(&t("nameSpaces")));
+        bytes.push(0xa1);
+        bytes.extend(encode(&t(MDL_NAMESPACE)));
+        bytes.push(0x80 | items.len() as u8);
+        for item in items {
+            bytes.extend(item);
+        }
+        bytes.extend(encode(&t("issuerAuth")));
+        bytes.extend(encode(issuer_auth));
+        bytes
+    }
+
+    fn item_cbor(id: u64, name: &str, value: Value) -> Vec<u8> {
+        encode(&Value::Map(vec![
+            (t("digestID"), Value::Integer(id.into())),
+            (t("random"), Value::Bytes(vec![id as u8; 16])),
+            (t("elementIdentifier"), t(name)),
+            (t("elementValue"), value),
+        ]))
+    }
+
+    fn item(id: u64, name: &str, value: Value) -> Vec<u8> {
+        encode(&Value::Tag(TAG_ENCODED_CBOR, Box::new(Value::Bytes(item_cbor(id, name, value)))))
+    }
+
+    fn licence() -> Vec<Vec<u8>> {
+        vec![
+            item(0, "family_name", t("Sharma")),
+            item(1, "birth_date", Value::Tag(TAG_FULL_DATE, Box::new(t("1990-05-17")))),
+            item(2, "age_over_18", Value::Bool(true)),
+        ]
+    }
+
+    #[test]
+    fn signed_issuer_signed_verifies() {
+        let fixture = Fixture::new();
+        let mdoc = verify_issuer_signed(&fixture.issuer_signed(&licence()), MDL_DOC_TYPE, &fixture.trust(), NOW).unwrap();
+        assert_eq!(mdoc.doc_type, MDL_DOC_TYPE);
+        assert_eq!(mdoc.iaca, "test-iaca");
+        assert_eq!(mdoc.valid_until, 1_798_761_600);
+        assert_eq!(mdoc.elements["family_name"], ElementValue::Text("Sharma".into()));
+        assert_eq!(mdoc.elements["birth_date"], ElementValue::Date("1990-05-17".into()));
+        assert_eq!(mdoc.elements["age_over_18"], ElementValue::Bool(true));
+
+        let other = Fixture::with_ds(DsKey::P256(p256::ecdsa::SigningKey::from_slice(&[3; 32]).unwrap()), MDOC_DS_EKU);
+        let untrusted = verify_issuer_signed(&fixture.issuer_signed(&licence()), MDL_DOC_TYPE, &IacaTrustList::default(), NOW);
+        assert!(matches!(untrusted, Err(ZkpError::ProofRejected(_))));
+        assert!(verify_issuer_signed(&other.issuer_signed(&licence()), MDL_DOC_TYPE, &fixture.trust(), NOW).is_ok());
+        assert!(verify_issuer_signed(&fixture.issuer_signed(&licence()), MDL_DOC_TYPE, &fixture.trust(), 1_900_000_000).is_err());
+    }
+
+    #[test]
+    fn changed_element_fails_its_value_digest() {
+        let fixture = Fixture::new();
+        let signed = licence();
+        let mut sent = signed.clone();
+        sent[1] = item(1, "birth_date", Value::Tag(TAG_FULL_DATE, Box::new(t("2010-05-17"))));
+        let tampered = assemble(&sent, &fixture.issuer_auth(&signed));
+        let result = verify_issuer_signed(&tampered, MDL_DOC_TYPE, &fixture.trust(), NOW);
+        assert!(matches!(result, Err(ZkpError::IntegrityCheckFailed(_))));
+    }
+
+    #[test]
+    fn digest_covers_the_item_bytes_as_sent() {
+        // bstr length 2-byte head me (0x59): valid CBOR, par ciborium ise 1-byte head se re-encode karta
+        let inner = item_cbor(0, "family_name", t("Sharma"));
+        let mut wide = vec![0xd8, 0x18, 0x59];
+        wide.extend((inner.len() as u16).to_be_bytes());
+        wide.extend(&inner);
+        assert_ne!(wide, item(0, "family_name", t("Sharma")));
+
+        let fixture = Fixture::new();
+        let mdoc = verify_issuer_signed(&fixture.issuer_signed(&[wide]), MDL_DOC_TYPE, &fixture.trust(), NOW).unwrap();
+        assert_eq!(mdoc.elements["family_name"], ElementValue::Text("Sharma".into()));
+    }
+
+    #[test]
+    fn ds_certificate_needs_the_mdoc_eku() {
+        let fixture = Fixture::with_ds(DsKey::P256(p256::ecdsa::SigningKey::from_slice(&[2; 32]).unwrap()), SERVER_AUTH);
+        let result = verify_issuer_signed(&fixture.issuer_signed(&licence()), MDL_DOC_TYPE, &fixture.trust(), NOW);
+        assert!(matches!(result, Err(ZkpError::ProofRejected(_))));
+    }
+
+    #[test]
+    fn alg_must_match_the_ds_curve() {
+        let mut fixture = Fixture::with_ds(DsKey::P384(p384::ecdsa::SigningKey::from_slice(&[4; 48]).unwrap()), MDOC_DS_EKU);
+        fixture.alg = iana::Algorithm::ES384;
+        assert!(verify_issuer_signed(&fixture.issuer_signed(&licence()), MDL_DOC_TYPE, &fixture.trust(), NOW).is_ok());
+
+        // P-384 key, SHA-256 prehash, header me ES256
+        fixture.alg = iana::Algorithm::ES256;
+        let result = verify_issuer_signed(&fixture.issuer_signed(&licence()), MDL_DOC_TYPE, &fixture.trust(), NOW);
+        assert!(matches!(result, Err(ZkpError::SignatureInvalid(_))));
+    }
+
+    #[test]
+    fn doc_type_must_match() {
+        let mut fixture = Fixture::new();
+        let issuer_signed = fixture.issuer_signed(&licence());
+        let wrong = verify_issuer_signed(&issuer_signed, "org.iso.23220.photoid.1", &fixture.trust(), NOW);
+        assert!(matches!(wrong, Err(ZkpError::ProofRejected(_))));
+
+        // Document: { docType, issuerSigned }; bahar ka docType MSO se alag nahi ho sakta
+        let document = |doc_type: &str| {
+            let mut bytes = vec![0xa2];
+            bytes.extend(encode(&t("docType")));
+            bytes.extend(encode(&t(doc_type)));
+            bytes.extend(encode(&t("issuerSigned")));
+            bytes.extend(&issuer_signed);
+            bytes
+        };
+        assert!(verify_issuer_signed(&document(MDL_DOC_TYPE), MDL_DOC_TYPE, &fixture.trust(), NOW).is_ok());
+        let relabelled = verify_issuer_signed(&document("org.iso.23220.photoid.1"), MDL_DOC_TYPE, &fixture.trust(), NOW);
+        assert!(matches!(relabelled, Err(ZkpError::ProofRejected(_))));
+
+        fixture.doc_type = "org.iso.23220.photoid.1";
+        let mso = verify_issuer_signed(&fixture.issuer_signed(&licence()), MDL_DOC_TYPE, &fixture.trust(), NOW);
+        assert!(matches!(mso, Err(ZkpError::ProofRejected(_))));
+    }
+
+    fn parse(raw: &str) -> ZkpResult<u64> {
+        tdate(&Value::Tag(0, Box::new(Value::Text(raw.into()))))
+    }
+
+    #[test]
+    fn tdate_parses_utc_timestamps() {
+        assert_eq!(parse("1970-01-01T00:00:00Z").unwrap(), 0);
+        assert_eq!(parse("2026-01-31T12:00:00Z").unwrap(), 1_769_860_800);
+        assert_eq!(parse("2024-02-29T23:59:59.250Z").unwrap(), 1_709_251_199);
+        assert!(parse("9999-12-31T23:59:59Z").is_ok());
+    }
+
+    #[test]
+    fn trust_bundle_csca_entries_are_the_iaca_roots() {
+        let fixture = Fixture::new();
+        let mut bundle = TrustBundle::new(7, NOW - 100, NOW + 100);
+        let certificate = fixture.trust().iacas[0].certificate.clone();
+        bundle.csca.push(CscaEntry { name: "bundle-iaca".into(), country: Some("IN".into()), certificate });
+
+        let trust = IacaTrustList::from_bundle(&bundle).unwrap();
+        assert_eq!(trust.version, 7);
+        let mdoc = verify_issuer_signed(&fixture.issuer_signed(&licence()), MDL_DOC_TYPE, &trust, NOW).unwrap();
+        assert_eq!(mdoc.iaca, "bundle-iaca");
+
+        bundle.csca[0].certificate = "not a certificate".into();
+        assert!(IacaTrustList::from_bundle(&bundle).is_err());
+    }
+
+    #[test]
+    fn tdate_rejects_out_of_range_fields() {
+        for raw in [
+            "10000-01-01T00:00:00Z",
+            "9223372036854775807-01-01T00:00:00Z",
+            "1969-12-31T23:59:59Z",
+            "2026-13-01T00:00:00Z",
+            "2026-01-01T-1:00:00Z",
+            "2026-01-01T00:00:00+01:00",
+        ] {
+            assert!(parse(raw).is_err(), "{}", raw);
+        }
+    }
+}
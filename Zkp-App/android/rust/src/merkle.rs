@@ -0,0 +1,569 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌳 MERKLE MEMBERSHIP (day18/merkle.circom ka plonky2 port)
+// Registry = Poseidon Merkle tree (Goldilocks), depth 1..=32. Leaf = member ka
+// commitment, root public. Proof kehta hai "mera secret is tree me hai" bina
+// bataye ki kaunsa leaf / index: secret, siblings aur path bits sab private.
+//   • node  = Poseidon(left || right)            (merkle.circom HashLeftRight)
+//   • bit 0 = current node left me, 1 = right me  (merkle.circom DualMux)
+//   • khaali leaf = 0, khaali subtree = precomputed zero hashes, isliye
+//     depth 32 ke tree me bhi sirf asli leaves memory me rehte hain
+// ═══════════════════════════════════════════════════════════════════════════
+
+use base64::{Engine as _, engine::general_purpose};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use plonky2::field::types::{Field, PrimeField64};
+use plonky2::hash::hash_types::{HashOut, HashOutTarget};
+use plonky2::hash::poseidon::PoseidonHash;
+use plonky2::iop::target::BoolTarget;
+use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::circuit_data::{CircuitConfig, CircuitData};
+use plonky2::plonk::config::{GenericConfig, Hasher, PoseidonGoldilocksConfig};
+use plonky2::plonk::proof::ProofWithPublicInputs;
+
+use crate::engine::CircuitCache;
+use crate::envelope::digest_hex;
+use crate::error::{ZkpError, ZkpResult};
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// Deepest supported tree (2^32 leaves; index bits fit a `u64`).
+pub const MAX_DEPTH: usize = 32;
+
+// Public input order (DO NOT CHANGE): root ke 4 elements
+const PI_ROOT: usize = 0;
+
+fn check_depth(depth: usize) -> ZkpResult<()> {
+    if depth == 0 || depth > MAX_DEPTH {
+        return Err(ZkpError::InvalidInput(format!("merkle depth must be 1..={}, got {}", MAX_DEPTH, depth)));
+    }
+    Ok(())
+}
+
+pub(crate) fn hash_pair(left: &HashOut<F>, right: &HashOut<F>) -> HashOut<F> {
+    PoseidonHash::hash_no_pad(&[left.elements, right.elements].concat())
+}
+
+/// Private member key: Poseidon over the secret's bytes (4 elements, not 1).
+pub fn member_key(secret: &str) -> HashOut<F> {
+    let bytes: Vec<F> = secret.bytes().map(F::from_canonical_u8).collect();
+    PoseidonHash::hash_no_pad(&bytes)
+}
+
+/// The leaf a registry stores for `secret`: Poseidon(member_key(secret)).
+pub fn leaf_commitment(secret: &str) -> HashOut<F> {
+    PoseidonHash::hash_no_pad(&member_key(secret).elements)
+}
+
+/// 32 bytes, four little-endian `u64`s (same layout as circuit digests).
+pub fn hash_hex(hash: &HashOut<F>) -> String {
+    let bytes: Vec<u8> = hash.elements.iter().flat_map(|x| x.to_canonical_u64().to_le_bytes()).collect();
+    hex::encode(bytes)
+}
+
+pub fn hash_from_hex(value: &str) -> ZkpResult<HashOut<F>> {
+    let bytes = hex::decode(value.trim())?;
+    if bytes.len() != 32 {
+        return Err(ZkpError::InvalidInput(format!("merkle hash must be 32 bytes, got {}", bytes.len())));
+    }
+    let mut elements = [F::ZERO; 4];
+    for (element, chunk) in elements.iter_mut().zip(bytes.chunks(8)) {
+        let limb = u64::from_le_bytes(chunk.try_into().expect("8 bytes"));
+        if F::from_noncanonical_u64(limb).to_canonical_u64() != limb {
+            return Err(ZkpError::InvalidInput("merkle hash element is not canonical".into()));
+        }
+        *element = F::from_canonical_u64(limb);
+    }
+    Ok(HashOut { elements })
+}
+
+pub(crate) mod hex_hash {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(hash: &HashOut<F>, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&hash_hex(hash))
+    }
+
+    pub fn deserialize<'de, De: Deserializer<'de>>(d: De) -> Result<HashOut<F>, De::Error> {
+        let value = String::deserialize(d)?;
+        hash_from_hex(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+pub(crate) mod hex_hashes {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(hashes: &[HashOut<F>], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(hashes.iter().map(hash_hex))
+    }
+
+    pub fn deserialize<'de, De: Deserializer<'de>>(d: De) -> Result<Vec<HashOut<F>>, De::Error> {
+        let values = Vec::<String>::deserialize(d)?;
+        values.iter().map(|v| hash_from_hex(v).map_err(serde::de::Error::custom)).collect()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🏗️ TREE BUILDER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Authentication path for one leaf. JSON: `{"index", "root", "siblings": [hex...]}`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MerklePath {
+    pub index: u64,
+    #[serde(with = "hex_hash")]
+    pub root: HashOut<F>,
+    /// Leaf level first; `siblings.len()` is the tree depth.
+    #[serde(with = "hex_hashes")]
+    pub siblings: Vec<HashOut<F>>,
+}
+
+impl MerklePath {
+    pub fn depth(&self) -> usize {
+        self.siblings.len()
+    }
+
+    /// Root this path gives for `leaf` (native mirror of the circuit).
+    pub fn compute_root(&self, leaf: &HashOut<F>) -> HashOut<F> {
+        self.siblings.iter().enumerate().fold(*leaf, |node, (level, sibling)| {
+            if (self.index >> level) & 1 == 0 {
+                hash_pair(&node, sibling)
+            } else {
+                hash_pair(sibling, &node)
+            }
+        })
+    }
+
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        let path: Self = serde_json::from_str(json)?;
+        check_depth(path.depth())?;
+        if path.index >> path.depth() != 0 {
+            return Err(ZkpError::InvalidInput("merkle index does not fit the path depth".into()));
+        }
+        Ok(path)
+    }
+}
+
+/// Append-only-by-default Poseidon tree. Level `i` holds only the populated
+/// prefix; everything to the right is the level's zero hash.
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    depth: usize,
+    /// `levels[0]` = leaves, `levels[depth]` = root (once non-empty).
+    levels: Vec<Vec<HashOut<F>>>,
+    /// `zeros[i]` = root of an empty subtree of height `i`.
+    zeros: Vec<HashOut<F>>,
+}
+
+impl MerkleTree {
+    pub fn new(depth: usize) -> ZkpResult<Self> {
+        check_depth(depth)?;
+        let mut zeros = vec![HashOut::ZERO];
+        for i in 0..depth {
+            zeros.push(hash_pair(&zeros[i], &zeros[i]));
+        }
+        Ok(Self { depth, levels: vec![Vec::new(); depth + 1], zeros })
+    }
+
+    /// Bulk build (parallel per level); millions of leaves take seconds, not minutes.
+    pub fn from_leaves(depth: usize, leaves: Vec<HashOut<F>>) -> ZkpResult<Self> {
+        let mut tree = Self::new(depth)?;
+        tree.check_capacity(leaves.len() as u64)?;
+
+        tree.levels[0] = leaves;
+        for level in 0..depth {
+            let zero = tree.zeros[level];
+            let parents: Vec<_> = tree.levels[level]
+                .par_chunks(2)
+                .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&zero)))
+                .collect();
+            tree.levels[level + 1] = parents;
+        }
+        Ok(tree)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn len(&self) -> u64 {
+        self.levels[0].len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    pub fn root(&self) -> HashOut<F> {
+        self.levels[self.depth].first().copied().unwrap_or(self.zeros[self.depth])
+    }
+
+    pub fn leaf(&self, index: u64) -> Option<HashOut<F>> {
+        self.levels[0].get(usize::try_from(index).ok()?).copied()
+    }
+
+    /// Appends a leaf and returns its index.
+    pub fn push(&mut self, leaf: HashOut<F>) -> ZkpResult<u64> {
+        let index = self.len();
+        self.check_capacity(index + 1)?;
+        self.levels[0].push(leaf);
+        self.rehash(index as usize);
+        Ok(index)
+    }
+
+    /// Replaces an existing leaf (e.g. key rotation). O(depth).
+    pub fn set(&mut self, index: u64, leaf: HashOut<F>) -> ZkpResult<()> {
+        let slot = usize::try_from(index)
+            .ok()
+            .and_then(|i| self.levels[0].get_mut(i))
+            .ok_or_else(|| ZkpError::InvalidInput(format!("merkle leaf {} does not exist", index)))?;
+        *slot = leaf;
+        self.rehash(index as usize);
+        Ok(())
+    }
+
+    pub fn path(&self, index: u64) -> ZkpResult<MerklePath> {
+        if index >= self.len() {
+            return Err(ZkpError::InvalidInput(format!("merkle leaf {} does not exist", index)));
+        }
+        let mut position = index as usize;
+        let siblings = (0..self.depth)
+            .map(|level| {
+                let sibling = self.levels[level].get(position ^ 1).copied().unwrap_or(self.zeros[level]);
+                position >>= 1;
+                sibling
+            })
+            .collect();
+        Ok(MerklePath { index, root: self.root(), siblings })
+    }
+
+    fn check_capacity(&self, count: u64) -> ZkpResult<()> {
+        if count > 1u64 << self.depth {
+            return Err(ZkpError::InvalidInput(format!("merkle tree of depth {} holds at most 2^{} leaves", self.depth, self.depth)));
+        }
+        Ok(())
+    }
+
+    /// Recomputes the parents of leaf `position` up to the root.
+    fn rehash(&mut self, mut position: usize) {
+        for level in 0..self.depth {
+            let parent = position / 2;
+            let nodes = &self.levels[level];
+            let left = nodes[2 * parent];
+            let right = nodes.get(2 * parent + 1).copied().unwrap_or(self.zeros[level]);
+            let hash = hash_pair(&left, &right);
+
+            let above = &mut self.levels[level + 1];
+            if parent == above.len() {
+                above.push(hash);
+            } else {
+                above[parent] = hash;
+            }
+            position = parent;
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧠 MEMBERSHIP CIRCUIT
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn select_hash(builder: &mut CircuitBuilder<F, D>, bit: BoolTarget, x: HashOutTarget, y: HashOutTarget) -> HashOutTarget {
+    HashOutTarget { elements: std::array::from_fn(|i| builder.select(bit, x.elements[i], y.elements[i])) }
+}
+
+/// Gadget: root reached from `leaf` along `siblings`; `index_bits[i]` = 1 means
+/// the node is the right child at level `i`. Bits must already be boolean-constrained.
+pub(crate) fn merkle_root_gadget(
+    builder: &mut CircuitBuilder<F, D>,
+    leaf: HashOutTarget,
+    siblings: &[HashOutTarget],
+    index_bits: &[BoolTarget],
+) -> HashOutTarget {
+    siblings.iter().zip(index_bits).fold(leaf, |node, (sibling, bit)| {
+        let left = select_hash(builder, *bit, *sibling, node);
+        let right = select_hash(builder, *bit, node, *sibling);
+        builder.hash_n_to_hash_no_pad::<PoseidonHash>([left.elements, right.elements].concat())
+    })
+}
+
+/// "I know a secret whose leaf is under `root`" for one fixed depth.
+pub(crate) struct MembershipCircuit {
+    pub(crate) data: CircuitData<F, C, D>,
+    depth: usize,
+    t_key: HashOutTarget,
+    t_siblings: Vec<HashOutTarget>,
+    t_index_bits: Vec<BoolTarget>,
+}
+
+impl MembershipCircuit {
+    pub(crate) fn build(depth: usize) -> ZkpResult<Self> {
+        check_depth(depth)?;
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let t_key = builder.add_virtual_hash();
+        let t_siblings = builder.add_virtual_hashes(depth);
+        let t_index_bits: Vec<BoolTarget> = (0..depth).map(|_| builder.add_virtual_bool_target_safe()).collect();
+
+        let leaf = builder.hash_n_to_hash_no_pad::<PoseidonHash>(t_key.elements.to_vec());
+        let root = merkle_root_gadget(&mut builder, leaf, &t_siblings, &t_index_bits);
+        builder.register_public_inputs(&root.elements);
+
+        Ok(Self { data: builder.build::<C>(), depth, t_key, t_siblings, t_index_bits })
+    }
+
+    pub(crate) fn prove(&self, secret: &str, path: &MerklePath) -> ZkpResult<ProofWithPublicInputs<F, C, D>> {
+        if secret.is_empty() {
+            return Err(ZkpError::InvalidInput("secret must not be empty".into()));
+        }
+        if path.depth() != self.depth {
+            return Err(ZkpError::InvalidInput(format!("path depth {} != circuit depth {}", path.depth(), self.depth)));
+        }
+        // Galat secret / purana path: proof banega par kisi aur root ka, isliye pehle hi roko
+        if path.compute_root(&leaf_commitment(secret)) != path.root {
+            return Err(ZkpError::InvalidInput("secret is not the leaf at this merkle path".into()));
+        }
+
+        let mut pw = PartialWitness::new();
+        pw.set_hash_target(self.t_key, member_key(secret));
+        for (level, (target, sibling)) in self.t_siblings.iter().zip(&path.siblings).enumerate() {
+            pw.set_hash_target(*target, *sibling);
+            pw.set_bool_target(self.t_index_bits[level], (path.index >> level) & 1 == 1);
+        }
+
+        self.data
+            .prove(pw)
+            .map_err(|e| ZkpError::ProvingFailed(e.to_string()))
+    }
+}
+
+fn root_of(proof: &ProofWithPublicInputs<F, C, D>) -> ZkpResult<HashOut<F>> {
+    let elements = proof
+        .public_inputs
+        .get(PI_ROOT..PI_ROOT + 4)
+        .ok_or_else(|| ZkpError::CorruptProof("merkle root public inputs missing".into()))?;
+    Ok(HashOut { elements: elements.try_into().expect("4 elements") })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📦 PROVE / VERIFY
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Transport form. JSON: `{"depth", "root", "circuit_digest", "proof": base64}`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MembershipProof {
+    pub depth: usize,
+    #[serde(with = "hex_hash")]
+    pub root: HashOut<F>,
+    pub circuit_digest: String,
+    pub proof: String,
+}
+
+impl MembershipProof {
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+}
+
+pub fn prove_membership(secret: &str, path: &MerklePath, circuits: &CircuitCache) -> ZkpResult<MembershipProof> {
+    let circuit = circuits.membership(path.depth())?;
+    let proof = circuit.prove(secret, path)?;
+
+    let bytes = bincode::serialize(&proof).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+    Ok(MembershipProof {
+        depth: path.depth(),
+        root: root_of(&proof)?,
+        circuit_digest: digest_hex(&circuit.data),
+        proof: general_purpose::STANDARD.encode(bytes),
+    })
+}
+
+/// Verifies against the registry's current `root`. `ProofRejected` = valid
+/// format, but wrong root / digest / math.
+pub fn verify_membership(proof: &MembershipProof, root: &HashOut<F>, circuits: &CircuitCache) -> ZkpResult<()> {
+    let circuit = circuits.membership(proof.depth)?;
+    if proof.circuit_digest != digest_hex(&circuit.data) {
+        return Err(ZkpError::ProofRejected("circuit digest mismatch".into()));
+    }
+
+    let bytes = general_purpose::STANDARD.decode(proof.proof.trim())?;
+    let inner: ProofWithPublicInputs<F, C, D> =
+        bincode::deserialize(&bytes).map_err(|e| ZkpError::CorruptProof(e.to_string()))?;
+
+    // Label (proof.root) aur proof ka asli root dono registry se match hone chahiye
+    if root_of(&inner)? != *root || proof.root != *root {
+        return Err(ZkpError::ProofRejected("proof is for a different merkle root".into()));
+    }
+
+    circuit
+        .data
+        .verify(inner)
+        .map_err(|e| ZkpError::ProofRejected(format!("{:?}", e)))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::{jboolean, jstring, JNI_FALSE, JNI_TRUE};
+    use log::info;
+
+    use crate::engine::CircuitCache;
+    use crate::error::ZkpError;
+    use crate::jni_bridge::{guard, new_string, read_string};
+
+    /// `pathJson` comes from the registry (`MerkleTree::path`); returns `MembershipProof` JSON.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Merkle_proveMembership(
+        mut env: JNIEnv,
+        _class: JClass,
+        secret_input: JString,
+        path_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let secret = read_string(env, &secret_input, "secret")?;
+            let path = super::MerklePath::from_json(&read_string(env, &path_json, "path_json")?)?;
+
+            let proof = super::prove_membership(&secret, &path, CircuitCache::shared())?;
+            info!("✅ Merkle membership proof generated (depth {})", proof.depth);
+            new_string(env, proof.to_json()?)
+        })
+    }
+
+    /// `false` = rejected (wrong root / invalid); malformed input throws.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Merkle_verifyMembership(
+        mut env: JNIEnv,
+        _class: JClass,
+        proof_json: JString,
+        root_hex: JString,
+    ) -> jboolean {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let proof = super::MembershipProof::from_json(&read_string(env, &proof_json, "proof_json")?)?;
+            let root = super::hash_from_hex(&read_string(env, &root_hex, "root_hex")?)?;
+
+            match super::verify_membership(&proof, &root, CircuitCache::shared()) {
+                Ok(()) => Ok(JNI_TRUE),
+                Err(ZkpError::ProofRejected(reason)) => {
+                    info!("❌ Merkle membership rejected: {}", reason);
+                    Ok(JNI_FALSE)
+                }
+                Err(e) => Err(e),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree_of(depth: usize, secrets: &[&str]) -> MerkleTree {
+        MerkleTree::from_leaves(depth, secrets.iter().map(|s| leaf_commitment(s)).collect()).unwrap()
+    }
+
+    #[test]
+    fn push_matches_bulk_build_and_set_rehashes() {
+        let secrets = ["alice", "bob", "carol", "dave", "erin"];
+        let bulk = tree_of(4, &secrets);
+        let mut pushed = MerkleTree::new(4).unwrap();
+        for secret in secrets {
+            pushed.push(leaf_commitment(secret)).unwrap();
+        }
+        assert_eq!(pushed.root(), bulk.root());
+
+        let before = pushed.root();
+        pushed.set(2, leaf_commitment("mallory")).unwrap();
+        assert_ne!(pushed.root(), before);
+        let path = pushed.path(2).unwrap();
+        assert_eq!(path.compute_root(&leaf_commitment("mallory")), pushed.root());
+        assert!(pushed.set(9, leaf_commitment("x")).is_err());
+    }
+
+    #[test]
+    fn capacity_and_path_bounds() {
+        let mut tree = tree_of(1, &["a", "b"]);
+        assert!(tree.push(leaf_commitment("c")).is_err());
+        assert!(tree.path(2).is_err());
+        assert!(MerkleTree::new(MAX_DEPTH + 1).is_err());
+
+        let mut json: serde_json::Value = serde_json::from_str(&serde_json::to_string(&tree.path(1).unwrap()).unwrap()).unwrap();
+        json["index"] = 2.into();
+        assert!(MerklePath::from_json(&json.to_string()).is_err());
+    }
+
+    #[test]
+    fn round_trip_at_depth_1_and_32() {
+        let circuits = CircuitCache::default();
+        for depth in [1, MAX_DEPTH] {
+            let tree = tree_of(depth, &["alice", "bob"]);
+            let proof = prove_membership("bob", &tree.path(1).unwrap(), &circuits).unwrap();
+            assert_eq!(proof.depth, depth);
+            verify_membership(&MembershipProof::from_json(&proof.to_json().unwrap()).unwrap(), &tree.root(), &circuits)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn wrong_root_is_rejected() {
+        let circuits = CircuitCache::default();
+        let tree = tree_of(4, &["alice", "bob"]);
+        let proof = prove_membership("alice", &tree.path(0).unwrap(), &circuits).unwrap();
+
+        let other = tree_of(4, &["alice", "bob", "carol"]).root();
+        assert!(matches!(verify_membership(&proof, &other, &circuits), Err(ZkpError::ProofRejected(_))));
+
+        // Label badal ke bhi nahi chalega: proof ke andar wala root hi ginta hai
+        let relabelled = MembershipProof { root: other, ..proof };
+        assert!(matches!(verify_membership(&relabelled, &other, &circuits), Err(ZkpError::ProofRejected(_))));
+    }
+
+    #[test]
+    fn wrong_index_never_yields_the_real_root() {
+        let circuits = CircuitCache::default();
+        let tree = tree_of(4, &["alice", "bob", "carol"]);
+        let mut path = tree.path(1).unwrap();
+        path.index = 2;
+        assert!(prove_membership("bob", &path, &circuits).is_err());
+        assert!(prove_membership("carol", &tree.path(1).unwrap(), &circuits).is_err());
+
+        // Native check skip karke seedha circuit: proof banta hai, par dusre root ka
+        let circuit = circuits.membership(4).unwrap();
+        let mut pw = PartialWitness::new();
+        pw.set_hash_target(circuit.t_key, member_key("bob"));
+        for (level, (target, sibling)) in circuit.t_siblings.iter().zip(&path.siblings).enumerate() {
+            pw.set_hash_target(*target, *sibling);
+            pw.set_bool_target(circuit.t_index_bits[level], (path.index >> level) & 1 == 1);
+        }
+        let forged = circuit.data.prove(pw).unwrap();
+        assert_ne!(root_of(&forged).unwrap(), tree.root());
+
+        let bytes = bincode::serialize(&forged).unwrap();
+        let claimed = MembershipProof {
+            depth: 4,
+            root: tree.root(),
+            circuit_digest: digest_hex(&circuit.data),
+            proof: general_purpose::STANDARD.encode(bytes),
+        };
+        assert!(matches!(verify_membership(&claimed, &tree.root(), &circuits), Err(ZkpError::ProofRejected(_))));
+    }
+}
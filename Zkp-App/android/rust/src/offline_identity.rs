@@ -2,22 +2,17 @@
 // 🦀 RUST ZKP MOBILE MODULE (Production Grade)
 // ═══════════════════════════════════════════════════════════════════════════
 
-use jni::JNIEnv;
-use jni::objects::{JClass, JString};
-use jni::sys::jstring;
-use std::ffi::CString;
-use std::panic;
 use std::time::Instant;
-use std::cmp::min;
 
 // Logic & Serialization
-use anyhow::{Context, Result};
 use base64::{Engine as _, engine::general_purpose};
-use serde_json::json;
+use log::info;
 
-// Android Logging
-use android_logger::Config;
-use log::{info, error, LevelFilter};
+// Errors, envelopes & progress
+use crate::error::{ZkpError, ZkpResult};
+use crate::gadgets::comparison::{assert_gte_const, fits, MAX_COMPARE_BITS};
+use crate::proving_jobs::{Phase, ProgressSink};
+use crate::qr_stream;
 
 // Plonky2 Imports
 use plonky2::field::types::Field;
@@ -39,22 +34,17 @@ type C = PoseidonGoldilocksConfig;
 type F = <C as GenericConfig<D>>::F;
 
 // Constraints
-const MIN_REQUIRED_BALANCE: u64 = 10_000;
-const USER_REAL_BALANCE: u64 = 50_000; // In production, pass this from Java/Kotlin
-const QR_CHUNK_SIZE: usize = 750;
-
-fn init_logger() {
-    let _ = android_logger::init_once(
-        Config::default().with_max_level(LevelFilter::Info).with_tag("RustZKP"),
-    );
-}
+pub const MIN_REQUIRED_BALANCE: u64 = 10_000;
+/// Balances up to 2^63 - 1 are provable.
+const BALANCE_BITS: usize = MAX_COMPARE_BITS;
+pub(crate) const USER_REAL_BALANCE: u64 = 50_000; // In production, pass this from Java/Kotlin
 
 // ═══════════════════════════════════════════════════════════════════════════
 // 🧠 CIRCUIT LOGIC (Single Source of Truth)
 // ═══════════════════════════════════════════════════════════════════════════
 
-struct IdentityCircuit {
-    data: CircuitData<F, C, D>,
+pub(crate) struct IdentityCircuit {
+    pub(crate) data: CircuitData<F, C, D>,
     target_balance: Target,
     target_hash: HashOutTarget,
 }
@@ -62,7 +52,7 @@ struct IdentityCircuit {
 impl IdentityCircuit {
     /// Builds the circuit constraints.
     /// Returns the compiled CircuitData and the targets needed for the witness.
-    fn build() -> Result<Self> {
+    pub(crate) fn build() -> ZkpResult<Self> {
         let config = CircuitConfig::standard_recursion_config();
         let mut builder = CircuitBuilder::<F, D>::new(config);
 
@@ -80,12 +70,9 @@ impl IdentityCircuit {
         builder.register_public_input(expected_hash_target.elements[0]);
 
         // 4. Range/Threshold Constraint: balance >= 10,000
-        // We prove this by checking: (balance - 10000) is a valid number within range
-        let min_required = builder.constant(F::from_canonical_u64(MIN_REQUIRED_BALANCE));
-        let diff = builder.sub(balance_target, min_required);
-        
-        // Range check to ensure no underflow (implicitly checks >=)
-        builder.range_check(diff, 32); 
+        // Pehle `range_check(diff, 32)` tha: 2^32 + MIN se upar ke balance fail
+        // ho jaate the. Ab poori 63-bit width (gadgets/comparison.rs).
+        assert_gte_const(&mut builder, balance_target, MIN_REQUIRED_BALANCE, BALANCE_BITS);
 
         let data = builder.build::<C>();
         
@@ -98,143 +85,140 @@ impl IdentityCircuit {
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
-// 1️⃣ PROVER (JNI)
+// 1️⃣ PROVER
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[no_mangle]
-pub extern "C" fn Java_com_example_zkpapp_OfflineMenuActivity_stringFromRust(
-    env: JNIEnv,
-    _class: JClass,
-) -> jstring {
-    init_logger();
-    info!("🚀 PROVER: Request Received");
-
-    // Catch panics to prevent crashing the JVM
-    let result = panic::catch_unwind(|| -> Result<String> {
-        let overall_start = Instant::now();
+impl IdentityCircuit {
+    /// Proves `balance >= MIN_REQUIRED_BALANCE` on an already built circuit.
+    pub(crate) fn prove(
+        &self,
+        balance: u64,
+        progress: &mut dyn ProgressSink,
+    ) -> ZkpResult<ProofWithPublicInputs<F, C, D>> {
+        // Circuit `balance >= MIN` aur width check karta hai, so reject early instead of
+        // producing a proof that will never verify.
+        if balance < MIN_REQUIRED_BALANCE {
+            return Err(ZkpError::InvalidInput(format!(
+                "balance below required minimum {}",
+                MIN_REQUIRED_BALANCE
+            )));
+        }
+        if !fits(balance, BALANCE_BITS) {
+            return Err(ZkpError::InvalidInput(format!("balance must be < 2^{}", BALANCE_BITS)));
+        }
 
-        // 1. Build Circuit
-        let circuit = IdentityCircuit::build().context("Failed to build circuit")?;
-        
         // 2. Witness Generation
+        progress.enter(Phase::Witness)?;
         info!("🧬 generating witness...");
-        let my_real_balance = F::from_canonical_u64(USER_REAL_BALANCE);
+        let my_real_balance = F::from_canonical_u64(balance);
         let my_balance_hash = PoseidonHash::hash_no_pad(&[my_real_balance]);
 
         let mut pw = PartialWitness::new();
-        pw.set_target(circuit.target_balance, my_real_balance);
-        pw.set_hash_target(circuit.target_hash, my_balance_hash);
+        pw.set_target(self.target_balance, my_real_balance);
+        pw.set_hash_target(self.target_hash, my_balance_hash);
 
-        // 3. Prove
+        // 3. Prove (commit + FRI, one uninterruptible call)
+        progress.enter(Phase::Prove)?;
         info!("🔨 proving...");
         let proof_start = Instant::now();
-        let proof = circuit.data.prove(pw).context("Proof generation failed")?;
+        let proof = self
+            .data
+            .prove(pw)
+            .map_err(|e| ZkpError::ProvingFailed(e.to_string()))?;
         info!("✅ PROOF TIME: {:.2?}", proof_start.elapsed());
 
-        // 4. Serialization
-        let proof_bytes = bincode::serialize(&proof).context("Serialization failed")?;
-        let proof_base64 = general_purpose::STANDARD.encode(proof_bytes);
-        
-        // 5. Chunking (Fountain Code / QR Format)
-        let total_chunks = (proof_base64.len() + QR_CHUNK_SIZE - 1) / QR_CHUNK_SIZE;
-        info!("📦 PAYLOAD: {} bytes | {} chunks", proof_base64.len(), total_chunks);
-
-        // Efficient JSON array construction
-        let mut chunks = Vec::with_capacity(total_chunks);
-        for i in 0..total_chunks {
-            let start = i * QR_CHUNK_SIZE;
-            let end = min(start + QR_CHUNK_SIZE, proof_base64.len());
-            let slice = &proof_base64[start..end];
-            
-            // Format: "index/total|data"
-            chunks.push(format!("{}/{}|{}", i + 1, total_chunks, slice));
-        }
+        Ok(proof)
+    }
+}
 
-        info!("🎉 TOTAL TIME: {:.2?}", overall_start.elapsed());
-        
-        // Convert Vec<String> to JSON String
-        serde_json::to_string(&chunks).context("JSON serialization failed")
-    });
-
-    // Safe JNI Return
-    let output = match result {
-        Ok(Ok(json)) => json,
-        Ok(Err(e)) => {
-            error!("Logic Error: {:?}", e);
-            json!([format!("Error: {}", e)]).to_string()
-        },
-        Err(e) => {
-            error!("Panic: {:?}", e);
-            json!(["Error: Rust Critical Panic"]).to_string()
-        }
-    };
+/// Proves the balance statement and returns the QR chunk array as JSON.
+pub fn prove_balance_chunks(chunk_size: usize, progress: &mut dyn ProgressSink) -> ZkpResult<String> {
+    let overall_start = Instant::now();
+
+    // 1. Build Circuit
+    progress.enter(Phase::CircuitLoad)?;
+    let proof = IdentityCircuit::build()?.prove(USER_REAL_BALANCE, progress)?;
+
+    // 4. Serialization
+    progress.enter(Phase::Serialize)?;
+    let proof_bytes = bincode::serialize(&proof).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+    let proof_base64 = general_purpose::STANDARD.encode(proof_bytes);
+
+    // 5. Chunking (Fountain Code / QR Format)
+    let chunks = qr_stream::split(&proof_base64, chunk_size);
+    info!("📦 PAYLOAD: {} bytes | {} chunks", proof_base64.len(), chunks.len());
 
-    let c_str = CString::new(output).unwrap_or_else(|_| CString::new("[\"Error: CString\"]").unwrap());
-    env.new_string(c_str.to_str().unwrap_or("[]")).expect("JNI NewString Failed").into_raw()
+    info!("🎉 TOTAL TIME: {:.2?}", overall_start.elapsed());
+
+    // Convert Vec<String> to JSON String
+    serde_json::to_string(&chunks).map_err(|e| ZkpError::Serialization(e.to_string()))
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
-// 2️⃣ VERIFIER (JNI)
+// 2️⃣ VERIFIER
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[no_mangle]
-pub extern "C" fn Java_com_example_zkpapp_VerifierActivity_verifyProofFromRust(
-    mut env: JNIEnv,
-    _class: JClass,
-    proof_str: JString,
-) -> jstring { 
-    init_logger();
-
-    // Safe String Extraction
-    let proof_base64: String = match env.get_string(&proof_str) {
-        Ok(s) => s.into(),
-        Err(e) => {
-            error!("JNI String Error: {:?}", e);
-            return env.new_string("❌ Error: Invalid Input").unwrap().into_raw();
-        }
-    };
-
-    let result = panic::catch_unwind(|| -> String {
-        let start_time = Instant::now();
-
-        // 1. Decode Base64
-        let proof_bytes = match general_purpose::STANDARD.decode(&proof_base64) {
-            Ok(b) => b,
-            Err(_) => return "❌ Error: Invalid Base64".to_string(),
-        };
-
-        // 2. Deserialize Proof
-        // Note: Explicitly typing the proof helps the compiler
-        let proof: ProofWithPublicInputs<F, C, D> = match bincode::deserialize(&proof_bytes) {
-            Ok(p) => p,
-            Err(e) => return format!("❌ Error: Corrupt Proof Data ({})", e),
-        };
-
-        // 3. Rebuild Circuit (Must match Prover exactly)
-        // In a real production app, we would load a pre-computed "VerifierOnlyCircuitData" 
-        // from a file instead of rebuilding, but this ensures exact logic match.
-        let circuit = match IdentityCircuit::build() {
-            Ok(c) => c,
-            Err(_) => return "❌ Error: Circuit Build Fail".to_string(),
-        };
-
-        // 4. Verify
-        match circuit.data.verify(proof) {
-            Ok(_) => {
-                let duration = start_time.elapsed();
-                format!("✅ VERIFIED!\n⏱️ Time: {:.2?}", duration)
-            },
-            Err(e) => {
-                format!("⛔ REJECTED: Invalid Proof\nReason: {:?}", e)
-            }
-        }
-    });
+/// Checks a raw base64 balance proof (the reassembled QR payload).
+/// A proof that fails the math is `ProofRejected`; unreadable input is any other error.
+pub fn verify_balance_proof(proof_base64: &str) -> ZkpResult<()> {
+    // 1. Decode Base64
+    let proof_bytes = general_purpose::STANDARD.decode(proof_base64.trim())?;
+
+    // 2. Deserialize Proof
+    // Note: Explicitly typing the proof helps the compiler
+    let proof: ProofWithPublicInputs<F, C, D> = bincode::deserialize(&proof_bytes)
+        .map_err(|e| ZkpError::CorruptProof(e.to_string()))?;
+
+    // 3. Rebuild Circuit (Must match Prover exactly)
+    // In a real production app, we would load a pre-computed "VerifierOnlyCircuitData"
+    // from a file instead of rebuilding, but this ensures exact logic match.
+    let circuit = IdentityCircuit::build()?;
+
+    // 4. Verify
+    circuit
+        .data
+        .verify(proof)
+        .map_err(|e| ZkpError::ProofRejected(format!("{:?}", e)))
+}
+
+/// Text shown by `VerifierActivity`; rejection is a result, not an exception.
+pub fn verify_balance_report(proof_base64: &str) -> ZkpResult<String> {
+    let start_time = Instant::now();
+
+    match verify_balance_proof(proof_base64) {
+        Ok(()) => {
+            let duration = start_time.elapsed();
+            Ok(format!("✅ VERIFIED!\n⏱️ Time: {:.2?}", duration))
+        },
+        Err(ZkpError::ProofRejected(reason)) => {
+            Ok(format!("⛔ REJECTED: Invalid Proof\nReason: {}", reason))
+        },
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proving_jobs::NoProgress;
+
+    fn encoded(proof: &ProofWithPublicInputs<F, C, D>) -> String {
+        general_purpose::STANDARD.encode(bincode::serialize(proof).unwrap())
+    }
 
-    let final_msg = match result {
-        Ok(msg) => msg,
-        Err(_) => "💥 Error: Verifier Panic".to_string(),
-    };
+    #[test]
+    fn balance_above_32_bits_round_trips() {
+        let circuit = IdentityCircuit::build().unwrap();
+        let balance = (1u64 << 32) + MIN_REQUIRED_BALANCE + 1;
+        let proof = circuit.prove(balance, &mut NoProgress).unwrap();
+        verify_balance_proof(&encoded(&proof)).unwrap();
+    }
 
-    let c_str = CString::new(final_msg).unwrap();
-    env.new_string(c_str.to_str().unwrap()).expect("JNI NewString Failed").into_raw()
-}
\ No newline at end of file
+    #[test]
+    fn out_of_range_balances_are_refused() {
+        let circuit = IdentityCircuit::build().unwrap();
+        assert!(circuit.prove(MIN_REQUIRED_BALANCE - 1, &mut NoProgress).is_err());
+        assert!(circuit.prove(1u64 << BALANCE_BITS, &mut NoProgress).is_err());
+        assert!(circuit.prove(MIN_REQUIRED_BALANCE, &mut NoProgress).is_ok());
+    }
+}
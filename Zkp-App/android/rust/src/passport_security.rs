@@ -1,8 +1,4 @@
-use jni::JNIEnv;
-use jni::objects::{JClass, JString};
-use jni::sys::jstring;
-use android_logger::Config;
-use log::{info, LevelFilter}; // 🟢 Extra imports (debug, error) hata diye
+use log::info; // 🟢 Extra imports (debug, error) hata diye
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
 use rsa::{RsaPrivateKey, RsaPublicKey, Pkcs1v15Sign};
@@ -12,20 +8,38 @@ use rsa::{RsaPrivateKey, RsaPublicKey, Pkcs1v15Sign};
 use rand::rngs::OsRng;
 use hex; // 🟢 Hex encoding tool add kiya
 
-// 🟢 Local Logger
-fn init_logger() {
-    let _ = android_logger::init_once(
-        Config::default().with_max_level(LevelFilter::Info).with_tag("RustZKP_Passport"),
-    );
-}
+use plonky2::field::types::Field;
+use plonky2::hash::poseidon::PoseidonHash;
+use plonky2::iop::target::Target;
+use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::circuit_data::{CircuitConfig, CircuitData};
+use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+use plonky2::plonk::proof::ProofWithPublicInputs;
+
+use crate::error::{ZkpError, ZkpResult};
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// TD3 DG1 is 93 bytes; leave headroom for other document layouts.
+const MAX_DG1_BYTES: usize = 128;
+/// 7 bytes per word keeps every packed value below the Goldilocks modulus.
+const BYTES_PER_WORD: usize = 7;
+const DG1_WORDS: usize = MAX_DG1_BYTES.div_ceil(BYTES_PER_WORD);
 
 #[derive(Serialize, Deserialize, Debug)]
-struct PassportData {
-    first_name: String,
-    last_name: String,
-    document_number: String,
-    dg1_hex: String,
-    sod_hex: String,
+pub struct PassportData {
+    pub first_name: String,
+    pub last_name: String,
+    pub document_number: String,
+    #[serde(default)]
+    pub date_of_birth: String,
+    #[serde(default)]
+    pub expiry_date: String,
+    pub dg1_hex: String,
+    pub sod_hex: String,
 }
 
 // 🔍 Helper: Subsequence Finder
@@ -34,13 +48,93 @@ fn find_subsequence(haystack: &[u8], needle: &[u8]) -> bool {
     haystack.windows(needle.len()).any(|w| w == needle)
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧠 PASSPORT CIRCUIT
+// Public: Poseidon commitment over the packed DG1 bytes (+ length).
+// SOD integrity (SHA-256 of DG1 inside SOD) is checked natively before proving.
+// ═══════════════════════════════════════════════════════════════════════════
+
+pub(crate) struct PassportCircuit {
+    pub(crate) data: CircuitData<F, C, D>,
+    t_words: Vec<Target>,
+    t_len: Target,
+}
+
+impl PassportCircuit {
+    pub(crate) fn build() -> Self {
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let t_words = builder.add_virtual_targets(DG1_WORDS);
+        let t_len = builder.add_virtual_target();
+
+        // Har word max 7 bytes ka hai
+        for &w in &t_words {
+            builder.range_check(w, BYTES_PER_WORD * 8);
+        }
+        builder.range_check(t_len, 8);
+
+        let mut preimage = t_words.clone();
+        preimage.push(t_len);
+        let commitment = builder.hash_n_to_hash_no_pad::<PoseidonHash>(preimage);
+        builder.register_public_inputs(&commitment.elements);
+
+        Self { data: builder.build::<C>(), t_words, t_len }
+    }
+
+    pub(crate) fn prove(&self, dg1: &[u8]) -> ZkpResult<ProofWithPublicInputs<F, C, D>> {
+        let words = pack_dg1(dg1)?;
+
+        let mut pw = PartialWitness::new();
+        for (t, w) in self.t_words.iter().zip(words) {
+            pw.set_target(*t, w);
+        }
+        pw.set_target(self.t_len, F::from_canonical_usize(dg1.len()));
+
+        self.data
+            .prove(pw)
+            .map_err(|e| ZkpError::ProvingFailed(e.to_string()))
+    }
+}
+
+/// Packs DG1 into fixed-size little-endian 7-byte words (zero padded).
+fn pack_dg1(dg1: &[u8]) -> ZkpResult<Vec<F>> {
+    if dg1.is_empty() || dg1.len() > MAX_DG1_BYTES {
+        return Err(ZkpError::InvalidInput(format!(
+            "DG1 must be 1..={} bytes, got {}",
+            MAX_DG1_BYTES,
+            dg1.len()
+        )));
+    }
+
+    let mut words = vec![F::ZERO; DG1_WORDS];
+    for (i, chunk) in dg1.chunks(BYTES_PER_WORD).enumerate() {
+        let mut buf = [0u8; 8];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        words[i] = F::from_canonical_u64(u64::from_le_bytes(buf));
+    }
+    Ok(words)
+}
+
+/// Native SOD check: SHA-256(DG1) must appear inside the SOD. Returns the DG1 bytes.
+pub(crate) fn checked_dg1(data: &PassportData) -> ZkpResult<Vec<u8>> {
+    let dg1_bytes = hex::decode(&data.dg1_hex)?;
+    let sod_bytes = hex::decode(&data.sod_hex)?;
+
+    let calculated_hash = Sha256::digest(&dg1_bytes);
+    if !find_subsequence(&sod_bytes, &calculated_hash) {
+        return Err(ZkpError::IntegrityCheckFailed("DG1 hash not present in SOD".into()));
+    }
+    Ok(dg1_bytes)
+}
+
 // 🧠 Core Logic
-fn prove_passport_logic(data: PassportData) -> Result<String, anyhow::Error> {
+pub fn prove_passport_logic(data: PassportData) -> ZkpResult<String> {
     info!("🚀 Processing Passport: {}", data.document_number);
 
     // 1. Decode Hex
-    let dg1_bytes = hex::decode(&data.dg1_hex).map_err(|e| anyhow::anyhow!("Invalid DG1 Hex: {}", e))?;
-    let sod_bytes = hex::decode(&data.sod_hex).map_err(|e| anyhow::anyhow!("Invalid SOD Hex: {}", e))?;
+    let dg1_bytes = hex::decode(&data.dg1_hex)?;
+    let sod_bytes = hex::decode(&data.sod_hex)?;
 
     // 2. Hash Calculation
     let mut hasher = Sha256::new();
@@ -73,26 +167,28 @@ fn prove_passport_logic(data: PassportData) -> Result<String, anyhow::Error> {
 }
 
 // 🌉 JNI Bridge
-#[no_mangle]
-pub extern "system" fn Java_com_example_zkpapp_SecurityGate_generateProof(
-    mut env: JNIEnv,
-    _class: JClass,
-    json_payload: JString,
-) -> jstring {
-    init_logger();
-
-    let input: String = match env.get_string(&json_payload) {
-        Ok(s) => s.into(),
-        Err(_) => return env.new_string("JNI_ERROR").unwrap().into_raw(),
-    };
-
-    let passport_data: PassportData = match serde_json::from_str(&input) {
-        Ok(data) => data,
-        Err(e) => return env.new_string(format!("JSON_ERROR: {}", e)).unwrap().into_raw(),
-    };
-
-    match prove_passport_logic(passport_data) {
-        Ok(report) => env.new_string(report).unwrap().into_raw(),
-        Err(e) => env.new_string(format!("LOGIC_ERROR: {}", e)).unwrap().into_raw(),
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::jstring;
+
+    use super::PassportData;
+    use crate::jni_bridge::{guard, new_string, read_string};
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_SecurityGate_generateProof(
+        mut env: JNIEnv,
+        _class: JClass,
+        json_payload: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let input = read_string(env, &json_payload, "json_payload")?;
+            let passport_data: PassportData = serde_json::from_str(&input)?;
+            let report = super::prove_passport_logic(passport_data)?;
+            new_string(env, report)
+        })
     }
-}
\ No newline at end of file
+}
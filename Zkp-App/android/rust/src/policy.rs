@@ -0,0 +1,784 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 📋 VERIFICATION POLICY (declarative verifier rules)
+// Hard-coded rules (`MIN_REQUIRED_BALANCE`, age `cutoff`) ki jagah ek policy
+// document (JSON ya TOML):
+//   name    = "bar-entry"
+//   schema  = "zkp.kyc.v1"
+//   clauses = ["age >= 18", "nationality in [IN, NP]", "issuer in trusted_set", "not revoked"]
+//   + `trusted_issuers` / `revocation_root` jo clauses ko chahiye
+// Bade sets naam se: `sets = { eu = ["AT", "BE", ...] }` aur clause
+// "country in @eu". Chhote sets polynomial, bade Merkle root (gadgets/set_membership.rs).
+// Compile hone par policy batati hai kaunsa circuit chahiye (`CircuitSelection`)
+// aur proof ke public inputs se kya match hona chahiye (`Expectation`).
+// Attribute predicates circuit me constants hain, isliye policy badli =>
+// circuit digest badla; verifier wahi circuit rebuild karke check karta hai.
+// Report har clause ka pass / fail alag batata hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use base64::{Engine as _, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+
+use plonky2::field::types::Field;
+use plonky2::hash::hash_types::{HashOut, HashOutTarget};
+use plonky2::hash::poseidon::PoseidonHash;
+use plonky2::iop::target::{BoolTarget, Target};
+use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::circuit_data::{CircuitConfig, CircuitData};
+use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+use plonky2::plonk::proof::ProofWithPublicInputs;
+
+use crate::credential::{credential_gadget, field_value, text_value, Credential, CredentialTargets, MAX_ATTRIBUTES};
+use crate::engine::CircuitCache;
+use crate::envelope::digest_hex;
+use crate::error::{ZkpError, ZkpResult};
+use crate::gadgets::comparison::{assert_gte_const, assert_lte_const, fits, MAX_COMPARE_BITS};
+use crate::gadgets::set_membership::{assert_in_set, AllowedSet, SetMembershipTargets};
+use crate::issuer::TrustedIssuers;
+use crate::offline_identity::MIN_REQUIRED_BALANCE;
+use crate::revocation::{non_membership_gadget, NonMembershipTargets, RevocationRoot, SmtProof};
+use crate::schnorr::PublicKey;
+use crate::vc::policy_payload;
+use crate::zk_auth::hash_to_field;
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+// Public input order (DO NOT CHANGE): issuer pk (10), phir `not revoked` ho to root (4)
+const PI_ISSUER: usize = 0;
+const PI_ROOT: usize = 10;
+
+/// Comparison bounds and compared values must stay below 2^COMPARE_BITS.
+const COMPARE_BITS: usize = MAX_COMPARE_BITS;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📝 CLAUSES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Condition on one attribute value. Text values are stored as `text_value`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Predicate {
+    Gte(u64),
+    Lte(u64),
+    Eq(u64),
+    In(Vec<u64>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Clause {
+    /// `age >= 18`, `nationality in [IN, NP]`, ...
+    Attribute { name: String, predicate: Predicate },
+    /// `issuer in trusted_set`
+    TrustedIssuer,
+    /// `not revoked`
+    NotRevoked,
+}
+
+/// `18` stays a number; `IN` / `"IN"` becomes `text_value("IN")`.
+fn parse_value(raw: &str) -> ZkpResult<u64> {
+    let raw = raw.trim();
+    if let Ok(number) = raw.parse::<u64>() {
+        field_value(raw, number)?;
+        return Ok(number);
+    }
+    let text = raw.trim_matches(|c| c == '"' || c == '\'');
+    if text.is_empty() {
+        return Err(ZkpError::InvalidInput("empty value in policy clause".into()));
+    }
+    Ok(text_value(text))
+}
+
+fn parse_bound(raw: &str) -> ZkpResult<u64> {
+    let bound: u64 = raw
+        .trim()
+        .parse()
+        .map_err(|_| ZkpError::InvalidInput(format!("comparison bound must be a number, got {:?}", raw.trim())))?;
+    if !fits(bound, COMPARE_BITS) {
+        return Err(ZkpError::InvalidInput(format!("comparison bound must be < 2^{}", COMPARE_BITS)));
+    }
+    Ok(bound)
+}
+
+impl std::str::FromStr for Clause {
+    type Err = ZkpError;
+
+    fn from_str(text: &str) -> ZkpResult<Self> {
+        Clause::parse(text, &BTreeMap::new())
+    }
+}
+
+impl Clause {
+    /// Like `FromStr`, but `in @name` looks the set up in `sets` (the policy's named sets).
+    pub fn parse(text: &str, sets: &BTreeMap<String, Vec<String>>) -> ZkpResult<Self> {
+        let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        match normalized.to_ascii_lowercase().as_str() {
+            "issuer in trusted_set" => return Ok(Clause::TrustedIssuer),
+            "not revoked" => return Ok(Clause::NotRevoked),
+            _ => {}
+        }
+
+        // Pehle do-char operators, warna ">=" ka "=" alag match ho jaata
+        let (name, predicate) = if let Some((name, rhs)) = normalized.split_once(">=") {
+            (name, Predicate::Gte(parse_bound(rhs)?))
+        } else if let Some((name, rhs)) = normalized.split_once("<=") {
+            (name, Predicate::Lte(parse_bound(rhs)?))
+        } else if let Some((name, rhs)) = normalized.split_once("==") {
+            (name, Predicate::Eq(parse_value(rhs)?))
+        } else if let Some((name, rhs)) = normalized.split_once(" in ") {
+            let rhs = rhs.trim();
+            let values = if let Some(set_name) = rhs.strip_prefix('@') {
+                let members = sets
+                    .get(set_name)
+                    .ok_or_else(|| ZkpError::InvalidInput(format!("unknown set @{} in {:?}", set_name, text)))?;
+                if members.is_empty() {
+                    return Err(ZkpError::InvalidInput(format!("set @{} is empty", set_name)));
+                }
+                members.iter().map(|m| parse_value(m)).collect::<ZkpResult<Vec<_>>>()?
+            } else {
+                let list = rhs
+                    .strip_prefix('[')
+                    .and_then(|r| r.strip_suffix(']'))
+                    .ok_or_else(|| ZkpError::InvalidInput(format!("expected [..] list or @set in {:?}", text)))?;
+                list.split(',').map(parse_value).collect::<ZkpResult<Vec<_>>>()?
+            };
+            (name, Predicate::In(values))
+        } else {
+            return Err(ZkpError::InvalidInput(format!("unsupported policy clause {:?}", text)));
+        };
+
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(ZkpError::InvalidInput(format!("bad attribute name in {:?}", text)));
+        }
+        Ok(Clause::Attribute { name: name.to_string(), predicate })
+    }
+}
+
+impl Predicate {
+    pub fn holds(&self, value: u64) -> bool {
+        match self {
+            Predicate::Gte(bound) => fits(value, COMPARE_BITS) && value >= *bound,
+            Predicate::Lte(bound) => fits(value, COMPARE_BITS) && value <= *bound,
+            Predicate::Eq(expected) => value == *expected,
+            Predicate::In(set) => set.contains(&value),
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📄 DOCUMENT -> COMPILED POLICY
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// What verifiers write (JSON or TOML). Unknown keys are rejected so a typo
+/// cannot silently drop a rule.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyDocument {
+    pub name: String,
+    /// Credential schema the clauses talk about. Omit for the plain balance proof.
+    #[serde(default)]
+    pub schema: Option<String>,
+    pub clauses: Vec<String>,
+    /// Needed by `issuer in trusted_set`.
+    #[serde(default)]
+    pub trusted_issuers: Option<TrustedIssuers>,
+    /// Needed by `not revoked`.
+    #[serde(default)]
+    pub revocation_root: Option<RevocationRoot>,
+    /// Named allowed-value sets for `attr in @name` clauses.
+    #[serde(default)]
+    pub sets: BTreeMap<String, Vec<String>>,
+}
+
+/// The credential circuit a policy compiles to. Equal specs share one circuit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PolicyCircuitSpec {
+    pub schema: String,
+    /// Attribute predicates, in clause order.
+    pub predicates: Vec<(String, Predicate)>,
+    pub not_revoked: bool,
+}
+
+/// Which proof a policy is checked against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitSelection {
+    /// The legacy balance proof (`IdentityCircuit`, raw base64). It only ever
+    /// proves `balance >= MIN_REQUIRED_BALANCE`.
+    Balance,
+    /// A `PolicyCircuit` over an issuer-signed credential.
+    Credential(PolicyCircuitSpec),
+}
+
+/// What the proof's public inputs must match, outside the circuit.
+#[derive(Debug, Clone)]
+pub enum Expectation {
+    /// Issuer key (public input) is one of these.
+    IssuerIn(Vec<PublicKey>),
+    /// Revocation root (public input) is the published one.
+    RevocationRoot(RevocationRoot),
+}
+
+#[derive(Debug, Clone)]
+pub struct Policy {
+    pub name: String,
+    pub clauses: Vec<(String, Clause)>,
+    pub selection: CircuitSelection,
+    pub expectations: Vec<Expectation>,
+}
+
+impl PolicyDocument {
+    /// JSON if it starts with `{`, TOML otherwise.
+    pub fn parse(text: &str) -> ZkpResult<Self> {
+        if text.trim_start().starts_with('{') {
+            Ok(serde_json::from_str(text)?)
+        } else {
+            toml::from_str(text).map_err(|e| ZkpError::MalformedJson(format!("policy toml: {}", e)))
+        }
+    }
+}
+
+impl Policy {
+    /// `PolicyDocument::parse` + `compile`.
+    pub fn parse(text: &str) -> ZkpResult<Self> {
+        Self::compile(PolicyDocument::parse(text)?)
+    }
+
+    pub fn compile(document: PolicyDocument) -> ZkpResult<Self> {
+        if document.clauses.is_empty() {
+            return Err(ZkpError::InvalidInput("policy has no clauses".into()));
+        }
+        let clauses = document
+            .clauses
+            .iter()
+            .map(|text| Ok((text.clone(), Clause::parse(text, &document.sets)?)))
+            .collect::<ZkpResult<Vec<_>>>()?;
+
+        let selection = match &document.schema {
+            None => {
+                // Balance proof sirf `balance >= MIN_REQUIRED_BALANCE` prove karta hai
+                for (text, clause) in &clauses {
+                    match clause {
+                        Clause::Attribute { name, predicate: Predicate::Gte(bound) }
+                            if name == "balance" && *bound <= MIN_REQUIRED_BALANCE => {}
+                        _ => {
+                            return Err(ZkpError::InvalidInput(format!(
+                                "{:?} needs a credential schema (the balance proof only shows balance >= {})",
+                                text, MIN_REQUIRED_BALANCE
+                            )))
+                        }
+                    }
+                }
+                CircuitSelection::Balance
+            }
+            Some(schema) => {
+                let has = |wanted: &Clause| clauses.iter().any(|(_, c)| c == wanted);
+                // Bina trusted issuer ke koi bhi khud apna credential sign kar lega
+                if !has(&Clause::TrustedIssuer) {
+                    return Err(ZkpError::InvalidInput("credential policies must include `issuer in trusted_set`".into()));
+                }
+                let predicates = clauses
+                    .iter()
+                    .filter_map(|(_, c)| match c {
+                        Clause::Attribute { name, predicate } => Some((name.clone(), predicate.clone())),
+                        _ => None,
+                    })
+                    .collect();
+                CircuitSelection::Credential(PolicyCircuitSpec {
+                    schema: schema.clone(),
+                    predicates,
+                    not_revoked: has(&Clause::NotRevoked),
+                })
+            }
+        };
+
+        let mut expectations = Vec::new();
+        if clauses.iter().any(|(_, c)| *c == Clause::TrustedIssuer) {
+            let trusted = document
+                .trusted_issuers
+                .ok_or_else(|| ZkpError::InvalidInput("`issuer in trusted_set` needs `trusted_issuers`".into()))?;
+            expectations.push(Expectation::IssuerIn(trusted.keys()?));
+        }
+        if clauses.iter().any(|(_, c)| *c == Clause::NotRevoked) {
+            let root = document
+                .revocation_root
+                .ok_or_else(|| ZkpError::InvalidInput("`not revoked` needs `revocation_root`".into()))?;
+            expectations.push(Expectation::RevocationRoot(root));
+        }
+
+        Ok(Self { name: document.name, clauses, selection, expectations })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧠 POLICY CIRCUIT
+// credential gadget + har attribute ke liye one-hot slot selector + predicates
+// (+ non-revocation of Poseidon(message)). Issuer pk hamesha public.
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Constrains `value` to `predicate` (gadgets/comparison.rs, gadgets/set_membership.rs).
+/// `In` returns the set and its targets, the prover fills them later.
+pub(crate) fn assert_predicate(
+    builder: &mut CircuitBuilder<F, D>,
+    value: Target,
+    predicate: &Predicate,
+) -> ZkpResult<Option<(AllowedSet, SetMembershipTargets)>> {
+    match predicate {
+        Predicate::Gte(bound) => assert_gte_const(builder, value, *bound, COMPARE_BITS),
+        Predicate::Lte(bound) => assert_lte_const(builder, value, *bound, COMPARE_BITS),
+        Predicate::Eq(expected) => {
+            let expected = builder.constant(F::from_canonical_u64(*expected));
+            builder.connect(value, expected);
+        }
+        Predicate::In(values) => {
+            // `Predicate` pub hai, koi bhi khaali set bana sakta hai: panic nahi, error
+            let set = AllowedSet::new(values)?;
+            let targets = assert_in_set(builder, value, &set);
+            return Ok(Some((set, targets)));
+        }
+    }
+    Ok(None)
+}
+
+pub(crate) struct PolicyCircuit {
+    pub(crate) data: CircuitData<F, C, D>,
+    spec: PolicyCircuitSpec,
+    credential: CredentialTargets,
+    /// One-hot slot selector per distinct attribute name.
+    selectors: BTreeMap<String, Vec<BoolTarget>>,
+    /// `In` predicates: attribute name, allowed set, membership targets.
+    sets: Vec<(String, AllowedSet, SetMembershipTargets)>,
+    revocation: Option<(HashOutTarget, NonMembershipTargets)>,
+}
+
+impl PolicyCircuit {
+    pub(crate) fn build(spec: &PolicyCircuitSpec) -> ZkpResult<Self> {
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let credential = credential_gadget(&mut builder);
+        builder.register_public_inputs(&credential.issuer.elements());
+        let schema = builder.constant(hash_to_field(&spec.schema));
+        builder.connect(credential.schema, schema);
+
+        let mut selectors: BTreeMap<String, Vec<BoolTarget>> = BTreeMap::new();
+        let mut values: BTreeMap<String, Target> = BTreeMap::new();
+        let mut sets = Vec::new();
+        for (name, predicate) in &spec.predicates {
+            if !values.contains_key(name) {
+                let bits: Vec<BoolTarget> = (0..MAX_ATTRIBUTES).map(|_| builder.add_virtual_bool_target_safe()).collect();
+                let (mut count, mut slot_name, mut present, mut value) =
+                    (builder.zero(), builder.zero(), builder.zero(), builder.zero());
+                for (bit, slot) in bits.iter().zip(&credential.slots) {
+                    count = builder.add(count, bit.target);
+                    slot_name = builder.mul_add(bit.target, slot.name, slot_name);
+                    present = builder.mul_add(bit.target, slot.present.target, present);
+                    value = builder.mul_add(bit.target, slot.value, value);
+                }
+                // Exactly one slot, maujood, aur naam wahi jo clause kehta hai
+                let one = builder.one();
+                builder.connect(count, one);
+                builder.connect(present, one);
+                let expected_name = builder.constant(hash_to_field(name));
+                builder.connect(slot_name, expected_name);
+
+                selectors.insert(name.clone(), bits);
+                values.insert(name.clone(), value);
+            }
+            if let Some((set, targets)) = assert_predicate(&mut builder, values[name], predicate)? {
+                sets.push((name.clone(), set, targets));
+            }
+        }
+
+        let revocation = spec.not_revoked.then(|| {
+            let key = builder.hash_n_to_hash_no_pad::<PoseidonHash>(credential.message.elements.to_vec());
+            let root = builder.add_virtual_hash();
+            let exclusion = non_membership_gadget(&mut builder, key, root);
+            builder.register_public_inputs(&root.elements);
+            (root, exclusion)
+        });
+
+        Ok(Self { data: builder.build::<C>(), spec: spec.clone(), credential, selectors, sets, revocation })
+    }
+
+    pub(crate) fn prove(
+        &self,
+        credential: &Credential,
+        smt_proof: Option<&SmtProof>,
+    ) -> ZkpResult<ProofWithPublicInputs<F, C, D>> {
+        if credential.schema != self.spec.schema {
+            return Err(ZkpError::InvalidInput(format!("policy wants schema {}, credential is {}", self.spec.schema, credential.schema)));
+        }
+        // Natively pehle check: jo clause fail hoga uska naam error me
+        for (name, predicate) in &self.spec.predicates {
+            let attribute = credential
+                .attribute(name)
+                .ok_or_else(|| ZkpError::InvalidInput(format!("credential has no attribute {:?}", name)))?;
+            if !predicate.holds(attribute.value) {
+                return Err(ZkpError::InvalidInput(format!("credential does not satisfy {} {:?}", name, predicate)));
+            }
+        }
+
+        let mut pw = PartialWitness::new();
+        self.credential.set_witness(&mut pw, credential)?;
+        for (name, bits) in &self.selectors {
+            for (slot, bit) in bits.iter().enumerate() {
+                let selected = credential.attributes.get(slot).is_some_and(|a| &a.name == name);
+                pw.set_bool_target(*bit, selected);
+            }
+        }
+        for (name, set, targets) in &self.sets {
+            // attribute upar check ho chuka hai
+            let value = credential.attribute(name).map(|a| a.value).unwrap_or_default();
+            targets.set_witness(&mut pw, set, value)?;
+        }
+
+        if let Some((root, exclusion)) = &self.revocation {
+            let proof = smt_proof.ok_or_else(|| ZkpError::InvalidInput("policy needs a revocation (SMT) proof".into()))?;
+            if proof.key != credential.revocation_key()? {
+                return Err(ZkpError::InvalidInput("revocation proof is for a different credential".into()));
+            }
+            if !proof.verify(&proof.root) {
+                return Err(ZkpError::InvalidInput("revocation proof does not match its root".into()));
+            }
+            pw.set_hash_target(*root, proof.root);
+            exclusion.set_witness(&mut pw, proof)?;
+        }
+
+        self.data
+            .prove(pw)
+            .map_err(|e| ZkpError::ProvingFailed(e.to_string()))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📤 PROVE / VERIFY
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// JSON: `{"policy", "circuit_digest", "proof": base64}`. `policy` is a label.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PolicyPresentation {
+    pub policy: String,
+    pub circuit_digest: String,
+    pub proof: String,
+}
+
+impl PolicyPresentation {
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ClauseReport {
+    pub clause: String,
+    pub passed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// What `verifyProofFromRust` returns. `verified` = every clause passed.
+#[derive(Serialize, Debug, Clone)]
+pub struct PolicyReport {
+    pub policy: String,
+    /// `"balance"` or `"credential"`.
+    pub circuit: String,
+    pub verified: bool,
+    pub clauses: Vec<ClauseReport>,
+    pub elapsed_ms: u64,
+    /// Trust bundle the issuer keys / revocation root came from (engine verifier only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust_bundle_version: Option<u64>,
+}
+
+/// Holder side: proof that `credential` meets `policy`.
+pub fn prove_policy(
+    credential: &Credential,
+    policy: &Policy,
+    smt_proof: Option<&SmtProof>,
+    circuits: &CircuitCache,
+) -> ZkpResult<PolicyPresentation> {
+    let CircuitSelection::Credential(spec) = &policy.selection else {
+        return Err(ZkpError::InvalidInput("balance-only policies use the plain balance proof".into()));
+    };
+    let circuit = circuits.policy(spec)?;
+    let proof = circuit.prove(credential, smt_proof)?;
+
+    let bytes = bincode::serialize(&proof).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+    Ok(PolicyPresentation {
+        policy: policy.name.clone(),
+        circuit_digest: digest_hex(&circuit.data),
+        proof: general_purpose::STANDARD.encode(bytes),
+    })
+}
+
+fn read_hash(inputs: &[F], at: usize) -> ZkpResult<HashOut<F>> {
+    let elements = inputs
+        .get(at..at + 4)
+        .ok_or_else(|| ZkpError::CorruptProof("policy public inputs missing".into()))?;
+    Ok(HashOut { elements: elements.try_into().expect("4 elements") })
+}
+
+fn check_expectations(policy: &Policy, inputs: &[F]) -> ZkpResult<(Option<String>, Option<String>)> {
+    let (mut issuer_failure, mut revocation_failure) = (None, None);
+    for expectation in &policy.expectations {
+        match expectation {
+            Expectation::IssuerIn(keys) => {
+                let issuer = inputs
+                    .get(PI_ISSUER..PI_ROOT)
+                    .ok_or_else(|| ZkpError::CorruptProof("policy public inputs missing".into()))?;
+                if !keys.iter().any(|k| k.elements()[..] == *issuer) {
+                    issuer_failure = Some("issuer is not in the trusted set".to_string());
+                }
+            }
+            Expectation::RevocationRoot(published) => {
+                if read_hash(inputs, PI_ROOT)? != published.root {
+                    revocation_failure = Some(format!("stale revocation root (published version {})", published.version));
+                }
+            }
+        }
+    }
+    Ok((issuer_failure, revocation_failure))
+}
+
+/// Verifier side. `payload` is a `PolicyPresentation` JSON, or the raw base64
+/// balance proof for balance-only policies, or a W3C VP wrapping either (vc.rs).
+/// Malformed input is an error; a bad proof or a failed clause is a report with
+/// `verified: false`.
+pub fn verify_with_policy(payload: &str, policy: &Policy, circuits: &CircuitCache) -> ZkpResult<PolicyReport> {
+    let start = Instant::now();
+    let payload = policy_payload(payload)?;
+    let payload = payload.as_str();
+
+    let (circuit, math, issuer_failure, revocation_failure) = match &policy.selection {
+        CircuitSelection::Balance => {
+            let bytes = general_purpose::STANDARD.decode(payload)?;
+            let proof: ProofWithPublicInputs<F, C, D> =
+                bincode::deserialize(&bytes).map_err(|e| ZkpError::CorruptProof(e.to_string()))?;
+            let math = circuits.balance()?.data.verify(proof).map_err(|e| format!("{:?}", e));
+            ("balance", math, None, None)
+        }
+        CircuitSelection::Credential(spec) => {
+            let presentation = PolicyPresentation::from_json(payload)?;
+            let circuit: Arc<PolicyCircuit> = circuits.policy(spec)?;
+            let bytes = general_purpose::STANDARD.decode(presentation.proof.trim())?;
+            let proof: ProofWithPublicInputs<F, C, D> =
+                bincode::deserialize(&bytes).map_err(|e| ZkpError::CorruptProof(e.to_string()))?;
+
+            let (issuer_failure, revocation_failure) = check_expectations(policy, &proof.public_inputs)?;
+            let math = if presentation.circuit_digest != digest_hex(&circuit.data) {
+                Err("proof was made for a different policy circuit".to_string())
+            } else {
+                circuit.data.verify(proof).map_err(|e| format!("{:?}", e))
+            };
+            ("credential", math, issuer_failure, revocation_failure)
+        }
+    };
+
+    let clauses: Vec<ClauseReport> = policy
+        .clauses
+        .iter()
+        .map(|(text, clause)| {
+            let reason = match (&math, clause) {
+                (Err(e), _) => Some(format!("invalid proof: {}", e)),
+                (Ok(()), Clause::TrustedIssuer) => issuer_failure.clone(),
+                (Ok(()), Clause::NotRevoked) => revocation_failure.clone(),
+                // Untrusted issuer ke attributes ka koi matlab nahi
+                (Ok(()), Clause::Attribute { .. }) => issuer_failure.as_ref().map(|_| "issuer is not trusted".to_string()),
+            };
+            ClauseReport { clause: text.clone(), passed: reason.is_none(), reason }
+        })
+        .collect();
+
+    Ok(PolicyReport {
+        policy: policy.name.clone(),
+        circuit: circuit.to_string(),
+        verified: clauses.iter().all(|c| c.passed),
+        clauses,
+        elapsed_ms: start.elapsed().as_millis() as u64,
+        trust_bundle_version: None,
+    })
+}
+
+/// `verify_with_policy` with JSON in and out (JNI / C ABI shape).
+pub fn verify_with_policy_json(payload: &str, policy_text: &str, circuits: &CircuitCache) -> ZkpResult<String> {
+    let report = verify_with_policy(payload, &Policy::parse(policy_text)?, circuits)?;
+    serde_json::to_string(&report).map_err(|e| ZkpError::Serialization(e.to_string()))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::jstring;
+    use log::info;
+
+    use crate::capi::{self, call_json};
+    use crate::jni_bridge::{guard, new_string, read_string};
+
+    /// Replaces the text-only balance verifier: `policyJson` (JSON or TOML) in,
+    /// `PolicyReport` JSON out. `legacy-abi` keeps the old boolean symbol instead.
+    #[cfg(not(feature = "legacy-abi"))]
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_VerifierActivity_verifyProofFromRust(
+        mut env: JNIEnv,
+        _class: JClass,
+        proof_str: JString,
+        policy_str: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let payload = read_string(env, &proof_str, "proof")?;
+            let policy = read_string(env, &policy_str, "policy")?;
+
+            // SAFETY: both strings outlive the call.
+            let report = call_json(|out| unsafe {
+                capi::zkp_verify_with_policy(payload.as_ptr(), payload.len(), policy.as_ptr(), policy.len(), out)
+            })?;
+            info!("📋 Policy report ready");
+            new_string(env, report)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::issuer::TrustedIssuer;
+    use crate::revocation::RevocationRegistry;
+    use crate::schnorr::SecretKey;
+
+    const CLAUSES: [&str; 4] = ["age >= 18", "nationality in [IN, NP]", "issuer in trusted_set", "not revoked"];
+
+    fn trusted(keys: &[&SecretKey]) -> TrustedIssuers {
+        let issuers = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| TrustedIssuer { issuer_id: format!("issuer-{}", i), public_key: key.public_key().to_hex() })
+            .collect();
+        TrustedIssuers::new(1, issuers).unwrap()
+    }
+
+    fn policy(issuers: &TrustedIssuers, root: &RevocationRoot) -> Policy {
+        let document = json!({
+            "name": "bar-entry",
+            "schema": "zkp.kyc.v1",
+            "clauses": CLAUSES,
+            "trusted_issuers": issuers,
+            "revocation_root": root,
+        });
+        Policy::parse(&document.to_string()).unwrap()
+    }
+
+    /// Credential signed by `issuer`, proven against `policy` with an exclusion proof from `registry`.
+    fn presentation(issuer: &SecretKey, policy: &Policy, registry: &RevocationRegistry, circuits: &CircuitCache) -> String {
+        let credential =
+            Credential::issue("zkp.kyc.v1", &[("age", 21), ("nationality", text_value("IN"))], issuer).unwrap();
+        let exclusion = registry.proof_for_key(&credential.revocation_key().unwrap());
+        prove_policy(&credential, policy, Some(&exclusion), circuits).unwrap().to_json().unwrap()
+    }
+
+    fn failed(report: &PolicyReport) -> Vec<&str> {
+        report.clauses.iter().filter(|c| !c.passed).map(|c| c.clause.as_str()).collect()
+    }
+
+    #[test]
+    fn json_and_toml_compile_to_the_same_policy() {
+        let issuer = SecretKey::generate();
+        let issuers = trusted(&[&issuer]);
+        let root = RevocationRegistry::new().published_root();
+
+        let from_json = policy(&issuers, &root);
+        let toml = format!(
+            "name = \"bar-entry\"\nschema = \"zkp.kyc.v1\"\n\
+             clauses = [\"age >= 18\", \"nationality in @south_asia\", \"issuer in trusted_set\", \"not revoked\"]\n\
+             [sets]\nsouth_asia = [\"IN\", \"NP\"]\n\
+             [trusted_issuers]\nversion = 1\n\
+             [[trusted_issuers.issuers]]\nissuer_id = \"issuer-0\"\npublic_key = \"{}\"\n\
+             [revocation_root]\nversion = {}\nroot = {}\nrevoked = 0\n",
+            issuer.public_key().to_hex(),
+            root.version,
+            serde_json::to_value(&root).unwrap()["root"],
+        );
+        let from_toml = Policy::parse(&toml).unwrap();
+
+        assert_eq!(from_toml.name, "bar-entry");
+        assert_eq!(from_toml.selection, from_json.selection);
+        let clauses: Vec<&Clause> = from_toml.clauses.iter().map(|(_, c)| c).collect();
+        assert_eq!(clauses, from_json.clauses.iter().map(|(_, c)| c).collect::<Vec<_>>());
+        assert_eq!(
+            *clauses[1],
+            Clause::Attribute { name: "nationality".into(), predicate: Predicate::In(vec![text_value("IN"), text_value("NP")]) }
+        );
+
+        // Typo wali key silently rule nahi gira sakti
+        assert!(Policy::parse("{\"name\": \"x\", \"clause\": [\"balance >= 1\"], \"clauses\": []}").is_err());
+        // Credential policy bina trusted issuer ke
+        assert!(Policy::parse("{\"name\": \"x\", \"schema\": \"s\", \"clauses\": [\"age >= 18\"]}").is_err());
+    }
+
+    #[test]
+    fn stale_revocation_root_fails_only_not_revoked() {
+        let circuits = CircuitCache::default();
+        let issuer = SecretKey::generate();
+        let issuers = trusted(&[&issuer]);
+        let mut registry = RevocationRegistry::new();
+        let payload = presentation(&issuer, &policy(&issuers, &registry.published_root()), &registry, &circuits);
+
+        let current = verify_with_policy(&payload, &policy(&issuers, &registry.published_root()), &circuits).unwrap();
+        assert!(current.verified, "{:?}", current.clauses);
+        assert_eq!(current.circuit, "credential");
+        assert_eq!(current.clauses.len(), CLAUSES.len());
+
+        // Proof ke baad registry aage badh gayi: baaki clauses pass, sirf `not revoked` fail
+        registry.revoke("someone-else", 1_700_000_000, 1).unwrap();
+        let newer = registry.published_root();
+        let report = verify_with_policy(&payload, &policy(&issuers, &newer), &circuits).unwrap();
+        assert!(!report.verified);
+        assert_eq!(failed(&report), ["not revoked"]);
+        let reason = report.clauses[3].reason.as_deref().unwrap();
+        assert!(reason.contains("stale revocation root") && reason.contains(&newer.version.to_string()), "{}", reason);
+    }
+
+    #[test]
+    fn issuer_outside_the_allow_list_fails_its_attributes() {
+        let circuits = CircuitCache::default();
+        let (issuer, other) = (SecretKey::generate(), SecretKey::generate());
+        let registry = RevocationRegistry::new();
+        let root = registry.published_root();
+        let payload = presentation(&issuer, &policy(&trusted(&[&issuer]), &root), &registry, &circuits);
+
+        let listed = verify_with_policy(&payload, &policy(&trusted(&[&other, &issuer]), &root), &circuits).unwrap();
+        assert!(listed.verified, "{:?}", listed.clauses);
+
+        let report = verify_with_policy(&payload, &policy(&trusted(&[&other]), &root), &circuits).unwrap();
+        assert!(!report.verified);
+        assert_eq!(failed(&report), ["age >= 18", "nationality in [IN, NP]", "issuer in trusted_set"]);
+        assert_eq!(report.clauses[2].reason.as_deref(), Some("issuer is not in the trusted set"));
+    }
+
+    #[test]
+    fn empty_in_set_is_an_error_not_a_panic() {
+        let spec = PolicyCircuitSpec {
+            schema: "zkp.kyc.v1".into(),
+            predicates: vec![("nationality".into(), Predicate::In(Vec::new()))],
+            not_revoked: false,
+        };
+        assert!(matches!(PolicyCircuit::build(&spec), Err(ZkpError::InvalidInput(_))));
+    }
+}
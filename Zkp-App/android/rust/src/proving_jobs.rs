@@ -0,0 +1,278 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ⏳ ASYNC PROVING JOBS
+// Proving ab calling thread ko block nahi karta:
+//   start*(listener) -> handle   (job runs on a Rust-owned thread pool)
+//   cancel(handle)               (checked between phases)
+//   await(handle)    -> result   (blocks, then frees the handle)
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+
+use log::info;
+
+use crate::error::{ZkpError, ZkpResult};
+
+/// Proving threads are kept small: plonky2 already parallelises internally.
+const JOB_POOL_THREADS: usize = 2;
+
+/// Pipeline phases, in order. Numeric values are shared with `ProvingJobs.kt`.
+///
+/// plonky2 runs commitment and FRI inside one `prove` call that can't report
+/// or stop midway, so both are a single `Prove` phase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum Phase {
+    CircuitLoad = 0,
+    Witness = 1,
+    Prove = 2,
+    Serialize = 3,
+}
+
+impl Phase {
+    const COUNT: i32 = 4;
+
+    /// Overall progress (0-100) at the moment this phase starts.
+    pub fn percent(self) -> i32 {
+        (self as i32) * 100 / Self::COUNT
+    }
+}
+
+/// Receives phase transitions from a prover. Returning `Err` aborts the proof.
+/// `Send` because engines run provers inside their own rayon pool.
+pub trait ProgressSink: Send {
+    fn enter(&mut self, phase: Phase) -> ZkpResult<()>;
+}
+
+/// Sink for the old blocking entry points.
+pub struct NoProgress;
+
+impl ProgressSink for NoProgress {
+    fn enter(&mut self, _phase: Phase) -> ZkpResult<()> {
+        Ok(())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🗂️ JOB REGISTRY
+// ═══════════════════════════════════════════════════════════════════════════
+
+type Outcome = Arc<(Mutex<Option<ZkpResult<String>>>, Condvar)>;
+
+struct Job {
+    cancelled: Arc<AtomicBool>,
+    outcome: Outcome,
+}
+
+static NEXT_HANDLE: AtomicI64 = AtomicI64::new(1);
+
+fn jobs() -> &'static Mutex<HashMap<i64, Job>> {
+    static JOBS: OnceLock<Mutex<HashMap<i64, Job>>> = OnceLock::new();
+    JOBS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn pool() -> &'static rayon::ThreadPool {
+    static POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(JOB_POOL_THREADS)
+            .thread_name(|i| format!("zkp-prover-{}", i))
+            .build()
+            .expect("rayon pool with fixed thread count")
+    })
+}
+
+/// Queues `work` and returns its handle.
+pub fn spawn<W>(work: W) -> i64
+where
+    W: FnOnce(&AtomicBool) -> ZkpResult<String> + Send + 'static,
+{
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let outcome: Outcome = Arc::new((Mutex::new(None), Condvar::new()));
+
+    jobs().lock().unwrap_or_else(|p| p.into_inner()).insert(
+        handle,
+        Job { cancelled: cancelled.clone(), outcome: outcome.clone() },
+    );
+
+    pool().spawn(move || {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| work(&cancelled)))
+            .unwrap_or_else(|_| Err(ZkpError::Panic(format!("proving job {} panicked", handle))));
+
+        let (slot, ready) = &*outcome;
+        *slot.lock().unwrap_or_else(|p| p.into_inner()) = Some(result);
+        ready.notify_all();
+    });
+
+    info!("⏳ Job {} queued", handle);
+    handle
+}
+
+/// Requests cancellation. Unknown handles are ignored (job may already be awaited).
+pub fn cancel(handle: i64) {
+    if let Some(job) = jobs().lock().unwrap_or_else(|p| p.into_inner()).get(&handle) {
+        job.cancelled.store(true, Ordering::SeqCst);
+        info!("🛑 Job {} cancel requested", handle);
+    }
+}
+
+/// Blocks until the job finishes, then forgets the handle.
+pub fn wait(handle: i64) -> ZkpResult<String> {
+    let outcome = jobs()
+        .lock()
+        .unwrap_or_else(|p| p.into_inner())
+        .get(&handle)
+        .map(|job| job.outcome.clone())
+        .ok_or_else(|| ZkpError::InvalidInput(format!("unknown job handle {}", handle)))?;
+
+    let (slot, ready) = &*outcome;
+    let mut guard = slot.lock().unwrap_or_else(|p| p.into_inner());
+    while guard.is_none() {
+        guard = ready.wait(guard).unwrap_or_else(|p| p.into_inner());
+    }
+    let result = guard.take().unwrap_or_else(|| Err(ZkpError::InvalidInput("job already awaited".into())));
+    drop(guard);
+
+    jobs().lock().unwrap_or_else(|p| p.into_inner()).remove(&handle);
+    result
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS (com.example.zkpapp.ProvingJobs)
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use jni::objects::{GlobalRef, JClass, JObject, JString, JValue};
+    use jni::sys::{jlong, jstring};
+    use jni::{JNIEnv, JavaVM};
+    use log::warn;
+
+    use super::{cancel, spawn, wait, Phase, ProgressSink};
+    use crate::engine::jni_exports::engine_from_handle;
+    use crate::error::{ZkpError, ZkpResult};
+    use crate::identity_request::IdentityRequest;
+    use crate::jni_bridge::{guard, new_string, read_string};
+    use crate::offline_identity;
+    use crate::qr_stream::QR_CHUNK_SIZE;
+
+    /// Forwards phases to `ProofProgressListener.onProgress(phase, percent)`.
+    /// Holds the VM (not a `JNIEnv`) so the sink can hop onto engine pool threads.
+    struct JavaProgress<'a> {
+        vm: &'a JavaVM,
+        listener: &'a GlobalRef,
+        cancelled: &'a AtomicBool,
+    }
+
+    impl ProgressSink for JavaProgress<'_> {
+        fn enter(&mut self, phase: Phase) -> ZkpResult<()> {
+            if self.cancelled.load(Ordering::SeqCst) {
+                return Err(ZkpError::Cancelled(format!("before {:?}", phase)));
+            }
+            self.notify(phase as i32, phase.percent());
+            Ok(())
+        }
+    }
+
+    impl JavaProgress<'_> {
+        fn notify(&self, phase: i32, percent: i32) {
+            // Already-attached threads get their existing env back (cheap)
+            let Ok(mut env) = self.vm.attach_current_thread_permanently() else {
+                warn!("⚠️ Could not attach thread for progress {}", phase);
+                return;
+            };
+            let called = env.call_method(
+                self.listener.as_obj(),
+                "onProgress",
+                "(II)V",
+                &[JValue::Int(phase), JValue::Int(percent)],
+            );
+            // Listener ki exception proof ko fail nahi karegi
+            if called.is_err() || env.exception_check().unwrap_or(false) {
+                let _ = env.exception_clear();
+                warn!("⚠️ Progress listener threw for phase {}", phase);
+            }
+        }
+    }
+
+    fn run_with_listener(
+        vm: &JavaVM,
+        listener: &GlobalRef,
+        cancelled: &AtomicBool,
+        prove: impl FnOnce(&mut dyn ProgressSink) -> ZkpResult<String>,
+    ) -> ZkpResult<String> {
+        let mut sink = JavaProgress { vm, listener, cancelled };
+        let result = prove(&mut sink)?;
+        sink.notify(Phase::Serialize as i32, 100);
+        Ok(result)
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ProvingJobs_startBalanceProof(
+        mut env: JNIEnv,
+        _class: JClass,
+        listener: JObject,
+    ) -> jlong {
+        guard(&mut env, |env| {
+            let vm = env.get_java_vm()?;
+            let listener = env.new_global_ref(listener)?;
+
+            Ok(spawn(move |cancelled| {
+                run_with_listener(&vm, &listener, cancelled, |sink| {
+                    offline_identity::prove_balance_chunks(QR_CHUNK_SIZE, sink)
+                })
+            }))
+        })
+    }
+
+    /// Any `IdentityRequest` on a warm engine; `await` returns the envelope JSON.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ProvingJobs_startProof(
+        mut env: JNIEnv,
+        _class: JClass,
+        engine_handle: jlong,
+        request_json: JString,
+        listener: JObject,
+    ) -> jlong {
+        guard(&mut env, |env| {
+            let engine = engine_from_handle(engine_handle)?;
+            let request = IdentityRequest::from_json(&read_string(env, &request_json, "request_json")?)?;
+            let vm = env.get_java_vm()?;
+            let listener = env.new_global_ref(listener)?;
+
+            Ok(spawn(move |cancelled| {
+                run_with_listener(&vm, &listener, cancelled, |sink| {
+                    engine.prove(&request, sink)?.to_json()
+                })
+            }))
+        })
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ProvingJobs_cancel(
+        mut env: JNIEnv,
+        _class: JClass,
+        handle: jlong,
+    ) {
+        guard(&mut env, |_| {
+            cancel(handle);
+            Ok(())
+        })
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ProvingJobs_await(
+        mut env: JNIEnv,
+        _class: JClass,
+        handle: jlong,
+    ) -> jstring {
+        guard(&mut env, |env| {
+            let output = wait(handle)?;
+            new_string(env, output)
+        })
+    }
+}
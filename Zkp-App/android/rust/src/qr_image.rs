@@ -0,0 +1,239 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🖼️ QR IMAGES (feature = "qr-render")
+// qr_stream ke "i/n|data" frames ko PNG / SVG / animated GIF / APNG me render
+// karta hai, aur images (still ya animated) se frames wapas decode karta hai.
+// Phone ke bina pura transfer path test karne ke liye: CLI, backend, desktop.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::HashSet;
+use std::io::Cursor;
+use std::str::FromStr;
+
+use image::codecs::gif::{GifDecoder, GifEncoder, Repeat};
+use image::codecs::png::PngDecoder;
+use image::{AnimationDecoder, Delay, DynamicImage, Frame, GrayImage, ImageFormat, Luma};
+use qrcode::render::svg;
+use qrcode::{EcLevel, QrCode, Version};
+
+use crate::error::{ZkpError, ZkpResult};
+use crate::qr_stream;
+
+/// Largest QR version (177x177 modules).
+const MAX_QR_VERSION: i16 = 40;
+
+/// Error-correction level. `M` matches what the Android renderer uses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorCorrection {
+    L,
+    #[default]
+    M,
+    Q,
+    H,
+}
+
+impl ErrorCorrection {
+    fn level(self) -> EcLevel {
+        match self {
+            Self::L => EcLevel::L,
+            Self::M => EcLevel::M,
+            Self::Q => EcLevel::Q,
+            Self::H => EcLevel::H,
+        }
+    }
+}
+
+impl FromStr for ErrorCorrection {
+    type Err = ZkpError;
+
+    fn from_str(s: &str) -> ZkpResult<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "L" => Ok(Self::L),
+            "M" => Ok(Self::M),
+            "Q" => Ok(Self::Q),
+            "H" => Ok(Self::H),
+            other => Err(ZkpError::InvalidInput(format!("unknown QR error correction '{}'", other))),
+        }
+    }
+}
+
+/// How each frame is drawn.
+#[derive(Clone, Debug)]
+pub struct QrRenderOptions {
+    pub error_correction: ErrorCorrection,
+    /// Fixed QR version (1-40). `None` = smallest that fits; animations then
+    /// use the largest version any frame needs so all frames share one size.
+    pub version: Option<i16>,
+    /// Pixels per module.
+    pub module_px: u32,
+    pub quiet_zone: bool,
+}
+
+impl Default for QrRenderOptions {
+    fn default() -> Self {
+        Self { error_correction: ErrorCorrection::M, version: None, module_px: 8, quiet_zone: true }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🎨 RENDER
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn encode(frame: &str, version: Option<i16>, ec: ErrorCorrection) -> ZkpResult<QrCode> {
+    let code = match version {
+        Some(v) if !(1..=MAX_QR_VERSION).contains(&v) => {
+            return Err(ZkpError::InvalidInput(format!("QR version must be 1-{}, got {}", MAX_QR_VERSION, v)));
+        }
+        Some(v) => QrCode::with_version(frame.as_bytes(), Version::Normal(v), ec.level()),
+        None => QrCode::with_error_correction_level(frame.as_bytes(), ec.level()),
+    };
+    code.map_err(|e| ZkpError::InvalidInput(format!("frame does not fit in a QR code: {}", e)))
+}
+
+/// Encodes every frame at one shared version (see `QrRenderOptions::version`).
+fn encode_all<S: AsRef<str>>(frames: &[S], opts: &QrRenderOptions) -> ZkpResult<Vec<QrCode>> {
+    if frames.is_empty() {
+        return Err(ZkpError::InvalidInput("no QR frames to render".into()));
+    }
+    let ec = opts.error_correction;
+
+    let version = match opts.version {
+        Some(v) => v,
+        None => {
+            let mut widest = 1;
+            for frame in frames {
+                if let Version::Normal(v) = encode(frame.as_ref(), None, ec)?.version() {
+                    widest = widest.max(v);
+                }
+            }
+            widest
+        }
+    };
+
+    frames.iter().map(|f| encode(f.as_ref(), Some(version), ec)).collect()
+}
+
+fn raster(code: &QrCode, opts: &QrRenderOptions) -> GrayImage {
+    code.render::<Luma<u8>>()
+        .module_dimensions(opts.module_px, opts.module_px)
+        .quiet_zone(opts.quiet_zone)
+        .build()
+}
+
+/// One frame as a PNG.
+pub fn render_png(frame: &str, opts: &QrRenderOptions) -> ZkpResult<Vec<u8>> {
+    let image = raster(&encode(frame, opts.version, opts.error_correction)?, opts);
+
+    let mut png = Cursor::new(Vec::new());
+    image
+        .write_to(&mut png, ImageFormat::Png)
+        .map_err(|e| ZkpError::Serialization(format!("png encode: {}", e)))?;
+    Ok(png.into_inner())
+}
+
+/// One frame as an SVG document.
+pub fn render_svg(frame: &str, opts: &QrRenderOptions) -> ZkpResult<String> {
+    let code = encode(frame, opts.version, opts.error_correction)?;
+    Ok(code
+        .render::<svg::Color>()
+        .module_dimensions(opts.module_px, opts.module_px)
+        .quiet_zone(opts.quiet_zone)
+        .build())
+}
+
+/// Whole frame loop as an endlessly repeating GIF.
+pub fn render_gif<S: AsRef<str>>(frames: &[S], opts: &QrRenderOptions, delay_ms: u32) -> ZkpResult<Vec<u8>> {
+    let gif_err = |e: image::ImageError| ZkpError::Serialization(format!("gif encode: {}", e));
+    let delay = Delay::from_numer_denom_ms(delay_ms, 1);
+
+    let mut gif = Vec::new();
+    {
+        let mut encoder = GifEncoder::new_with_speed(&mut gif, 10);
+        encoder.set_repeat(Repeat::Infinite).map_err(gif_err)?;
+        for code in encode_all(frames, opts)? {
+            let rgba = DynamicImage::ImageLuma8(raster(&code, opts)).to_rgba8();
+            encoder.encode_frame(Frame::from_parts(rgba, 0, 0, delay)).map_err(gif_err)?;
+        }
+    }
+    Ok(gif)
+}
+
+/// Whole frame loop as an endlessly repeating APNG (lossless, smaller than GIF).
+pub fn render_apng<S: AsRef<str>>(frames: &[S], opts: &QrRenderOptions, delay_ms: u32) -> ZkpResult<Vec<u8>> {
+    let png_err = |e: png::EncodingError| ZkpError::Serialization(format!("apng encode: {}", e));
+    let images: Vec<GrayImage> = encode_all(frames, opts)?.iter().map(|c| raster(c, opts)).collect();
+    let (width, height) = images[0].dimensions();
+
+    let mut apng = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut apng, width, height);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_animated(images.len() as u32, 0).map_err(png_err)?;
+        encoder.set_frame_delay(delay_ms.min(u16::MAX as u32) as u16, 1000).map_err(png_err)?;
+
+        let mut writer = encoder.write_header().map_err(png_err)?;
+        for image in &images {
+            writer.write_image_data(image.as_raw()).map_err(png_err)?;
+        }
+        writer.finish().map_err(png_err)?;
+    }
+    Ok(apng)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔍 DECODE
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn decode_err(e: image::ImageError) -> ZkpError {
+    ZkpError::InvalidEncoding(format!("image: {}", e))
+}
+
+/// Every QR code found in one still image.
+fn scan(image: &GrayImage) -> Vec<String> {
+    let mut prepared = rqrr::PreparedImage::prepare_from_greyscale(
+        image.width() as usize,
+        image.height() as usize,
+        |x, y| image.get_pixel(x as u32, y as u32).0[0],
+    );
+    prepared
+        .detect_grids()
+        .into_iter()
+        .filter_map(|grid| grid.decode().ok().map(|(_, content)| content))
+        .collect()
+}
+
+fn gray_frames(frames: image::Frames<'_>) -> ZkpResult<Vec<GrayImage>> {
+    frames
+        .map(|f| f.map(|f| DynamicImage::ImageRgba8(f.into_buffer()).to_luma8()).map_err(decode_err))
+        .collect()
+}
+
+/// Decodes every QR frame in a PNG / APNG / GIF / JPEG. Animated frames are
+/// all scanned; repeats from the loop are dropped, order is kept.
+pub fn decode_image(bytes: &[u8]) -> ZkpResult<Vec<String>> {
+    let images = match image::guess_format(bytes).map_err(decode_err)? {
+        ImageFormat::Gif => gray_frames(GifDecoder::new(Cursor::new(bytes)).map_err(decode_err)?.into_frames())?,
+        ImageFormat::Png => {
+            let decoder = PngDecoder::new(Cursor::new(bytes)).map_err(decode_err)?;
+            if decoder.is_apng().map_err(decode_err)? {
+                gray_frames(decoder.apng().map_err(decode_err)?.into_frames())?
+            } else {
+                vec![DynamicImage::from_decoder(decoder).map_err(decode_err)?.to_luma8()]
+            }
+        }
+        _ => vec![image::load_from_memory(bytes).map_err(decode_err)?.to_luma8()],
+    };
+
+    let mut seen = HashSet::new();
+    Ok(images.iter().flat_map(scan).filter(|content| seen.insert(content.clone())).collect())
+}
+
+/// Decodes a set of images (any order, any mix of still/animated) and
+/// reassembles the payload with `qr_stream::join`.
+pub fn decode_payload<B: AsRef<[u8]>>(images: &[B]) -> ZkpResult<String> {
+    let mut frames = Vec::new();
+    for image in images {
+        frames.extend(decode_image(image.as_ref())?);
+    }
+    qr_stream::join(&frames)
+}
@@ -0,0 +1,136 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 📦 QR STREAM CHUNKING
+// Frame format: "index/total|data" (1-based). VerifierActivity yahi parse
+// karta hai; optional trailing "|checksum" ko join ignore karta hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use crate::error::{ZkpError, ZkpResult};
+
+/// Chunk size used by the current app.
+pub const QR_CHUNK_SIZE: usize = 750;
+/// Chunk size of the old `rust_layer` build (`legacy-abi`).
+pub const LEGACY_QR_CHUNK_SIZE: usize = 500;
+/// Upper bound on `total` in a frame header (~3 MB at `QR_CHUNK_SIZE`).
+/// Header untrusted hai: bina cap ke `1/99999999999|x` allocation abort karta.
+pub const MAX_FRAMES: usize = 4096;
+
+/// Splits a payload into numbered frames of at most `chunk_size` bytes.
+/// Cuts only on `char` boundaries, so non-ASCII JSON survives the round trip.
+pub fn split(payload: &str, chunk_size: usize) -> Vec<String> {
+    let chunk_size = chunk_size.max(1);
+    let mut chunks = Vec::new();
+    let mut rest = payload;
+    while !rest.is_empty() {
+        let mut end = chunk_size.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            // chunk_size ek multi-byte char se chhota hai
+            end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    if chunks.is_empty() {
+        chunks.push("");
+    }
+
+    let total = chunks.len();
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| format!("{}/{}|{}", i + 1, total, chunk))
+        .collect()
+}
+
+/// Parses one frame into `(index, total, data)`.
+pub fn parse_frame(frame: &str) -> ZkpResult<(usize, usize, &str)> {
+    let bad = || ZkpError::InvalidInput(format!("bad QR frame header: {:.16}", frame));
+
+    // Sirf scanner ka noise hatao: data ke andar/kinare ke spaces payload ka hissa hain
+    let mut parts = frame.trim_start().trim_end_matches(['\r', '\n']).splitn(3, '|');
+    let header = parts.next().ok_or_else(bad)?;
+    let data = parts.next().ok_or_else(bad)?;
+
+    let (index, total) = header.split_once('/').ok_or_else(bad)?;
+    let index: usize = index.parse().map_err(|_| bad())?;
+    let total: usize = total.parse().map_err(|_| bad())?;
+
+    if index == 0 || total == 0 || index > total {
+        return Err(bad());
+    }
+    if total > MAX_FRAMES {
+        return Err(ZkpError::InvalidInput(format!("QR stream of {} frames exceeds {}", total, MAX_FRAMES)));
+    }
+    Ok((index, total, data))
+}
+
+/// Reassembles frames in any order; duplicates are fine, gaps are an error.
+pub fn join<S: AsRef<str>>(frames: &[S]) -> ZkpResult<String> {
+    let mut slots: Vec<Option<String>> = Vec::new();
+
+    for frame in frames {
+        let (index, total, data) = parse_frame(frame.as_ref())?;
+        if slots.is_empty() {
+            slots = vec![None; total];
+        } else if slots.len() != total {
+            return Err(ZkpError::InvalidInput(format!(
+                "frames disagree on total ({} vs {})",
+                slots.len(),
+                total
+            )));
+        }
+        slots[index - 1].get_or_insert_with(|| data.to_string());
+    }
+
+    if slots.is_empty() {
+        return Err(ZkpError::InvalidInput("no QR frames".into()));
+    }
+
+    let mut payload = String::new();
+    for (i, slot) in slots.into_iter().enumerate() {
+        payload.push_str(&slot.ok_or_else(|| ZkpError::InvalidInput(format!("missing frame {}", i + 1)))?);
+    }
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_out_of_order_with_duplicates() {
+        let payload = "A".repeat(2 * QR_CHUNK_SIZE + 1);
+        let mut frames = split(&payload, QR_CHUNK_SIZE);
+        assert_eq!(frames.len(), 3);
+        frames.reverse();
+        frames.push(frames[0].clone());
+        assert_eq!(join(&frames).unwrap(), payload);
+    }
+
+    #[test]
+    fn non_ascii_survives_split() {
+        let payload = r#"{"issuer_id":"बैंक ऑफ़ इंडिया 🏦","version":1}"#;
+        for chunk_size in [1, 2, 3, 5, 7] {
+            let frames = split(payload, chunk_size);
+            assert!(frames.iter().all(|f| !f.contains('\u{FFFD}')));
+            assert_eq!(join(&frames).unwrap(), payload);
+        }
+    }
+
+    #[test]
+    fn huge_total_is_rejected_not_allocated() {
+        assert!(join(&["1/99999999999|x"]).is_err());
+        assert!(join(&[format!("1/{}|x", MAX_FRAMES + 1)]).is_err());
+        assert!(parse_frame(&format!("1/{}|x", MAX_FRAMES)).is_ok());
+    }
+
+    #[test]
+    fn gaps_and_mismatched_totals_fail() {
+        assert!(join(&["1/3|a", "3/3|c"]).is_err());
+        assert!(join(&["1/2|a", "2/3|b"]).is_err());
+        assert!(join::<&str>(&[]).is_err());
+    }
+}
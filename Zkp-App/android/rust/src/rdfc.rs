@@ -0,0 +1,441 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧾 RDF DATASET CANONICALIZATION (RDFC-1.0, W3C REC 2024 = URDNA2015)
+// Same RDF graph, chahe blank node labels ya quad order kuch bhi ho, ek hi
+// canonical N-Quads string deta hai: wahi bytes jo JSON-LD Data Integrity
+// suites (`rdfc`) hash / sign karte hain.
+//   1. har blank node ka "first degree" hash (uske quads, khud = _:a, baaki = _:z)
+//   2. unique hash wale nodes ko seedha `c14n<N>` milta hai, hash order me
+//   3. barabar hash wale nodes: "N-degree" hash, padosiyon ke permutations pe
+//      (spec ka poison-graph guard: calls ki limit `MAX_NDEGREE_CALLS`)
+//   4. relabel + canonical N-Quads lines, code point order me
+// JSON-LD -> quads jsonld.rs me hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use sha2::{Digest, Sha256};
+
+use crate::error::{ZkpError, ZkpResult};
+
+pub const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+pub const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+pub const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+pub const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+
+/// Upper bound on "hash N-degree quads" calls per dataset. Legit documents
+/// need a handful; crafted ones (many isomorphic blank nodes) blow up factorially.
+const MAX_NDEGREE_CALLS: usize = 4_096;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📦 DATA MODEL
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Term {
+    Iri(String),
+    /// Label without the `_:`.
+    Blank(String),
+    Literal { value: String, datatype: String, language: Option<String> },
+}
+
+impl Term {
+    pub fn literal(value: impl Into<String>, datatype: &str) -> Self {
+        Term::Literal { value: value.into(), datatype: datatype.to_string(), language: None }
+    }
+
+    fn blank_label(&self) -> Option<&str> {
+        match self {
+            Term::Blank(label) => Some(label),
+            _ => None,
+        }
+    }
+}
+
+/// `graph == None` is the default graph.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Quad {
+    pub subject: Term,
+    pub predicate: String,
+    pub object: Term,
+    pub graph: Option<Term>,
+}
+
+impl Quad {
+    fn blank_nodes(&self) -> impl Iterator<Item = &str> {
+        [Some(&self.subject), Some(&self.object), self.graph.as_ref()]
+            .into_iter()
+            .flatten()
+            .filter_map(Term::blank_label)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📝 CANONICAL N-QUADS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Literal escaping of canonical N-Triples (RDF 1.2): ECHAR for \b \t \n \f \r " \,
+/// uppercase UCHAR for the other controls, everything else verbatim.
+fn escape_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\u{8}' => out.push_str("\\b"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\u{c}' => out.push_str("\\f"),
+            '\r' => out.push_str("\\r"),
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c <= '\u{1f}' || c == '\u{7f}' => out.push_str(&format!("\\u{:04X}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn write_term(term: &Term, label: &dyn Fn(&str) -> String) -> String {
+    match term {
+        Term::Iri(iri) => format!("<{}>", iri),
+        Term::Blank(id) => format!("_:{}", label(id)),
+        Term::Literal { value, language: Some(language), .. } => {
+            format!("\"{}\"@{}", escape_literal(value), language)
+        }
+        Term::Literal { value, datatype, .. } if datatype == XSD_STRING || datatype == RDF_LANG_STRING => {
+            format!("\"{}\"", escape_literal(value))
+        }
+        Term::Literal { value, datatype, .. } => format!("\"{}\"^^<{}>", escape_literal(value), datatype),
+    }
+}
+
+/// One N-Quads line (with the trailing newline); blank labels go through `label`.
+fn write_quad(quad: &Quad, label: &dyn Fn(&str) -> String) -> String {
+    let mut line = format!("{} <{}> {}", write_term(&quad.subject, label), quad.predicate, write_term(&quad.object, label));
+    if let Some(graph) = &quad.graph {
+        line.push(' ');
+        line.push_str(&write_term(graph, label));
+    }
+    line.push_str(" .\n");
+    line
+}
+
+/// Quads as N-Quads, labels untouched (not canonical; for debugging).
+pub fn to_nquads(quads: &[Quad]) -> String {
+    quads.iter().map(|q| write_quad(q, &|id| id.to_string())).collect()
+}
+
+fn sha256_hex(data: &str) -> String {
+    hex::encode(Sha256::digest(data.as_bytes()))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔁 ALGORITHM
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Blank node identifier issuer: `prefix0`, `prefix1`, ... in issue order.
+#[derive(Clone)]
+struct Issuer {
+    prefix: &'static str,
+    issued: HashMap<String, String>,
+    order: Vec<String>,
+}
+
+impl Issuer {
+    fn new(prefix: &'static str) -> Self {
+        Self { prefix, issued: HashMap::new(), order: Vec::new() }
+    }
+
+    fn get(&self, id: &str) -> Option<&String> {
+        self.issued.get(id)
+    }
+
+    fn issue(&mut self, id: &str) -> String {
+        if let Some(existing) = self.issued.get(id) {
+            return existing.clone();
+        }
+        let fresh = format!("{}{}", self.prefix, self.order.len());
+        self.issued.insert(id.to_string(), fresh.clone());
+        self.order.push(id.to_string());
+        fresh
+    }
+}
+
+struct Canonicalizer<'a> {
+    quads: &'a [Quad],
+    /// Blank node -> indexes of the quads that mention it.
+    mentions: HashMap<&'a str, Vec<usize>>,
+    first_degree: HashMap<&'a str, String>,
+    canonical: Issuer,
+    ndegree_calls: usize,
+}
+
+impl<'a> Canonicalizer<'a> {
+    fn hash_first_degree(&self, id: &str) -> String {
+        let label = |other: &str| if other == id { "a".to_string() } else { "z".to_string() };
+        let mut lines: Vec<String> = self.mentions[id].iter().map(|&i| write_quad(&self.quads[i], &label)).collect();
+        lines.sort();
+        sha256_hex(&lines.concat())
+    }
+
+    fn hash_related(&self, related: &str, quad: &Quad, issuer: &Issuer, position: char) -> String {
+        let identifier = match self.canonical.get(related).or_else(|| issuer.get(related)) {
+            Some(issued) => format!("_:{}", issued),
+            None => self.first_degree[related].clone(),
+        };
+        let mut input = position.to_string();
+        if position != 'g' {
+            input.push_str(&format!("<{}>", quad.predicate));
+        }
+        input.push_str(&identifier);
+        sha256_hex(&input)
+    }
+
+    fn hash_n_degree(&mut self, id: &str, mut issuer: Issuer) -> ZkpResult<(String, Issuer)> {
+        self.ndegree_calls += 1;
+        if self.ndegree_calls > MAX_NDEGREE_CALLS {
+            return Err(ZkpError::InvalidInput("RDF canonicalization exceeded its work limit".into()));
+        }
+
+        // hash -> related blank nodes (BTreeMap: hashes in code point order)
+        let mut by_hash: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for &i in &self.mentions[id] {
+            let quad = &self.quads[i];
+            let positions = [(Some(&quad.subject), 's'), (Some(&quad.object), 'o'), (quad.graph.as_ref(), 'g')];
+            for (term, position) in positions {
+                if let Some(related) = term.and_then(Term::blank_label).filter(|r| *r != id) {
+                    let hash = self.hash_related(related, quad, &issuer, position);
+                    by_hash.entry(hash).or_default().push(related.to_string());
+                }
+            }
+        }
+
+        let mut data = String::new();
+        for (hash, mut related) in by_hash {
+            data.push_str(&hash);
+            let mut chosen: Option<(String, Issuer)> = None;
+
+            related.sort();
+            loop {
+                if let Some((path, issuer_copy)) = self.try_permutation(&related, &issuer, chosen.as_ref().map(|c| c.0.as_str()))? {
+                    chosen = Some((path, issuer_copy));
+                }
+                if !next_permutation(&mut related) {
+                    break;
+                }
+            }
+
+            let (path, chosen_issuer) = chosen.expect("at least one permutation");
+            data.push_str(&path);
+            issuer = chosen_issuer;
+        }
+        Ok((sha256_hex(&data), issuer))
+    }
+
+    /// Path of one permutation, or `None` once it is already worse than `chosen`.
+    fn try_permutation(
+        &mut self,
+        permutation: &[String],
+        issuer: &Issuer,
+        chosen: Option<&str>,
+    ) -> ZkpResult<Option<(String, Issuer)>> {
+        let worse = |path: &str| chosen.is_some_and(|c| path > c);
+        let mut issuer_copy = issuer.clone();
+        let mut path = String::new();
+        let mut recursion = Vec::new();
+
+        for related in permutation {
+            match self.canonical.get(related) {
+                Some(issued) => path.push_str(&format!("_:{}", issued)),
+                None => {
+                    if issuer_copy.get(related).is_none() {
+                        recursion.push(related.clone());
+                    }
+                    path.push_str(&format!("_:{}", issuer_copy.issue(related)));
+                }
+            }
+            if worse(&path) {
+                return Ok(None);
+            }
+        }
+
+        for related in recursion {
+            let (hash, result_issuer) = self.hash_n_degree(&related, issuer_copy.clone())?;
+            path.push_str(&format!("_:{}<{}>", issuer_copy.issue(&related), hash));
+            issuer_copy = result_issuer;
+            if worse(&path) {
+                return Ok(None);
+            }
+        }
+
+        Ok(match chosen {
+            Some(c) if path.as_str() >= c => None,
+            _ => Some((path, issuer_copy)),
+        })
+    }
+}
+
+/// Lexicographic next permutation in place; false after the last one.
+fn next_permutation(items: &mut [String]) -> bool {
+    let Some(i) = (1..items.len()).rev().find(|&i| items[i - 1] < items[i]) else {
+        return false;
+    };
+    let j = (i..items.len()).rev().find(|&j| items[i - 1] < items[j]).expect("pivot has a successor");
+    items.swap(i - 1, j);
+    items[i..].reverse();
+    true
+}
+
+/// Canonical N-Quads of the dataset (duplicate quads collapse, as in RDF).
+pub fn canonicalize(quads: &[Quad]) -> ZkpResult<String> {
+    let mut seen = HashSet::new();
+    let quads: Vec<Quad> = quads.iter().filter(|q| seen.insert(*q)).cloned().collect();
+
+    let mut state = Canonicalizer {
+        quads: &quads,
+        mentions: HashMap::new(),
+        first_degree: HashMap::new(),
+        canonical: Issuer::new("c14n"),
+        ndegree_calls: 0,
+    };
+    let mut blank_nodes: Vec<&str> = Vec::new();
+    for (i, quad) in quads.iter().enumerate() {
+        for id in quad.blank_nodes() {
+            let entry = state.mentions.entry(id).or_default();
+            if entry.is_empty() {
+                blank_nodes.push(id);
+            }
+            if entry.last() != Some(&i) {
+                entry.push(i);
+            }
+        }
+    }
+
+    // 1 + 2: first-degree hashes; unique ones get canonical labels right away
+    let mut by_hash: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for &id in &blank_nodes {
+        let hash = state.hash_first_degree(id);
+        state.first_degree.insert(id, hash.clone());
+        by_hash.entry(hash).or_default().push(id);
+    }
+    for ids in by_hash.values().filter(|ids| ids.len() == 1) {
+        state.canonical.issue(ids[0]);
+    }
+
+    // 3: shared hashes, broken by N-degree hashes
+    for ids in by_hash.values().filter(|ids| ids.len() > 1) {
+        let mut results = Vec::new();
+        for &id in ids {
+            if state.canonical.get(id).is_some() {
+                continue;
+            }
+            let mut temporary = Issuer::new("b");
+            temporary.issue(id);
+            results.push(state.hash_n_degree(id, temporary)?);
+        }
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+        for (_, issuer) in results {
+            for id in &issuer.order {
+                state.canonical.issue(id);
+            }
+        }
+    }
+
+    // 4: relabel and sort
+    let canonical = &state.canonical;
+    let label = |id: &str| canonical.get(id).expect("every blank node is labelled").clone();
+    let mut lines: Vec<String> = quads.iter().map(|q| write_quad(q, &label)).collect();
+    lines.sort();
+    Ok(lines.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iri(value: &str) -> Term {
+        Term::Iri(value.to_string())
+    }
+
+    fn blank(label: &str) -> Term {
+        Term::Blank(label.to_string())
+    }
+
+    fn quad(s: Term, p: &str, o: Term) -> Quad {
+        Quad { subject: s, predicate: p.to_string(), object: o, graph: None }
+    }
+
+    const NEXT: &str = "http://example.org/vocab#next";
+    const PREV: &str = "http://example.org/vocab#prev";
+
+    /// Three nodes in a next/prev ring: all first-degree hashes are equal.
+    fn ring(labels: [&str; 3]) -> Vec<Quad> {
+        let [a, b, c] = labels;
+        vec![
+            quad(blank(a), NEXT, blank(b)),
+            quad(blank(a), PREV, blank(c)),
+            quad(blank(b), NEXT, blank(c)),
+            quad(blank(b), PREV, blank(a)),
+            quad(blank(c), NEXT, blank(a)),
+            quad(blank(c), PREV, blank(b)),
+        ]
+    }
+
+    #[test]
+    fn ground_dataset_is_sorted_nquads() {
+        let quads = vec![
+            quad(iri("http://example.org/b"), "http://example.org/p", Term::literal("x", XSD_STRING)),
+            quad(iri("http://example.org/a"), "http://example.org/p", Term::literal("1", XSD_INTEGER)),
+        ];
+        assert_eq!(
+            canonicalize(&quads).unwrap(),
+            "<http://example.org/a> <http://example.org/p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n\
+             <http://example.org/b> <http://example.org/p> \"x\" .\n"
+        );
+    }
+
+    #[test]
+    fn single_blank_node_becomes_c14n0() {
+        let quads = vec![quad(blank("whatever"), "http://example.org/p", Term::literal("a\"b\\c\n\u{1}", XSD_STRING))];
+        assert_eq!(canonicalize(&quads).unwrap(), "_:c14n0 <http://example.org/p> \"a\\\"b\\\\c\\n\\u0001\" .\n");
+    }
+
+    #[test]
+    fn labels_and_order_do_not_matter() {
+        let first = canonicalize(&ring(["e0", "e1", "e2"])).unwrap();
+        let mut shuffled = ring(["x", "q", "m"]);
+        shuffled.reverse();
+        shuffled.swap(1, 4);
+        assert_eq!(canonicalize(&shuffled).unwrap(), first);
+        assert!(first.contains("_:c14n2") && !first.contains("_:c14n3"));
+        assert!(!first.contains("_:e"));
+    }
+
+    #[test]
+    fn duplicates_collapse_and_graphs_are_kept() {
+        let mut quads = vec![quad(blank("a"), "http://example.org/p", blank("b"))];
+        quads.push(quads[0].clone());
+        quads.push(Quad { graph: Some(blank("g")), ..quad(blank("b"), "http://example.org/q", iri("http://example.org/o")) });
+        let out = canonicalize(&quads).unwrap();
+        assert_eq!(out.lines().count(), 2);
+        assert_eq!(out.lines().filter(|l| l.matches("_:c14n").count() == 2).count(), 2);
+    }
+
+    #[test]
+    fn different_graphs_give_different_output() {
+        let ring_out = canonicalize(&ring(["a", "b", "c"])).unwrap();
+        let mut broken = ring(["a", "b", "c"]);
+        broken[0].predicate = PREV.to_string();
+        assert_ne!(canonicalize(&broken).unwrap(), ring_out);
+    }
+
+    #[test]
+    fn next_permutation_walks_all_orders() {
+        let mut items: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let mut count = 1;
+        while next_permutation(&mut items) {
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        assert_eq!(items, ["c", "b", "a"]);
+    }
+}
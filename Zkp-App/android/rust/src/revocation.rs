@@ -0,0 +1,785 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🚫 REVOCATION REGISTRY (Poseidon sparse Merkle tree, 256-bit keys)
+// Har revoked credential ka key = Poseidon(credential_id) (4 Goldilocks
+// elements = 256 bits) aur woh bits hi leaf ka rasta hain (bit i = level i,
+// merkle.rs wali convention). Khaali leaf = 0, bhara leaf = Poseidon(key || value).
+//   • inclusion: "yeh credential revoked hai" (registry / audit ke liye)
+//   • exclusion: "mera credential revoked NAHI hai" -> circuit gadget, jo
+//     identity / nullifier circuits me jod sakte hain
+// Verifier ko sirf `RevocationRoot` chahiye (chhota JSON, QR se offline).
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+use base64::{Engine as _, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+
+use plonky2::field::types::{Field, PrimeField64};
+use plonky2::hash::hash_types::{HashOut, HashOutTarget};
+use plonky2::hash::poseidon::PoseidonHash;
+use plonky2::iop::target::BoolTarget;
+use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::circuit_data::{CircuitConfig, CircuitData};
+use plonky2::plonk::config::{GenericConfig, Hasher, PoseidonGoldilocksConfig};
+use plonky2::plonk::proof::ProofWithPublicInputs;
+
+use crate::engine::CircuitCache;
+use crate::error::{ZkpError, ZkpResult};
+use crate::merkle::{hash_pair, hex_hash, hex_hashes, merkle_root_gadget};
+use crate::qr_stream;
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// Tree height = key size in bits.
+pub const SMT_DEPTH: usize = 256;
+
+// Public input order (DO NOT CHANGE)
+const PI_ROOT: usize = 0;
+const PI_COMMITMENT: usize = 4;
+
+/// Tree key for a credential ID.
+pub fn credential_key(credential_id: &str) -> HashOut<F> {
+    let bytes: Vec<F> = credential_id.bytes().map(F::from_canonical_u8).collect();
+    PoseidonHash::hash_no_pad(&bytes)
+}
+
+fn leaf_hash(key: &HashOut<F>, value: &HashOut<F>) -> HashOut<F> {
+    hash_pair(key, value)
+}
+
+/// `zeros[h]` = root of an empty subtree of height `h`.
+fn zero_hashes() -> Vec<HashOut<F>> {
+    let mut zeros = vec![HashOut::ZERO];
+    for h in 0..SMT_DEPTH {
+        zeros.push(hash_pair(&zeros[h], &zeros[h]));
+    }
+    zeros
+}
+
+/// 256-bit key as an integer (little-endian limbs) so subtrees are `BTreeMap` ranges.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+struct U256([u64; 4]);
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl U256 {
+    fn from_hash(hash: &HashOut<F>) -> Self {
+        Self(hash.elements.map(|x| x.to_canonical_u64()))
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        (self.0[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    fn flip_bit0(self) -> Self {
+        let mut limbs = self.0;
+        limbs[0] ^= 1;
+        Self(limbs)
+    }
+
+    fn shr(&self, n: usize) -> Self {
+        let (words, bits) = (n / 64, n % 64);
+        let mut out = [0u64; 4];
+        for (i, limb) in out.iter_mut().enumerate() {
+            let src = i + words;
+            if src < 4 {
+                *limb = self.0[src] >> bits;
+                if bits > 0 && src + 1 < 4 {
+                    *limb |= self.0[src + 1] << (64 - bits);
+                }
+            }
+        }
+        Self(out)
+    }
+
+    fn shl(&self, n: usize) -> Self {
+        let (words, bits) = (n / 64, n % 64);
+        let mut out = [0u64; 4];
+        for (i, limb) in out.iter_mut().enumerate() {
+            if i >= words {
+                let src = i - words;
+                *limb = self.0[src] << bits;
+                if bits > 0 && src > 0 {
+                    *limb |= self.0[src - 1] >> (64 - bits);
+                }
+            }
+        }
+        Self(out)
+    }
+
+    /// Smallest and largest key under the subtree `(height, prefix)`.
+    fn subtree_range(prefix: &Self, height: usize) -> (Self, Self) {
+        if height >= SMT_DEPTH {
+            return (Self([0; 4]), Self([u64::MAX; 4]));
+        }
+        let low = prefix.shl(height);
+        let mut high = low;
+        for i in 0..height {
+            high.0[i / 64] |= 1 << (i % 64);
+        }
+        (low, high)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌲 SPARSE MERKLE TREE
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    value: HashOut<F>,
+    leaf: HashOut<F>,
+}
+
+/// Inclusion (`value` set) or exclusion (`value` null) proof for one key.
+/// JSON: `{"key", "root", "value", "siblings": [256 hex]}`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SmtProof {
+    #[serde(with = "hex_hash")]
+    pub key: HashOut<F>,
+    #[serde(with = "hex_hash")]
+    pub root: HashOut<F>,
+    #[serde(with = "hex_hash_opt", default)]
+    pub value: Option<HashOut<F>>,
+    /// Leaf level first.
+    #[serde(with = "hex_hashes")]
+    pub siblings: Vec<HashOut<F>>,
+}
+
+impl SmtProof {
+    pub fn is_inclusion(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// Native check against `root` (what the circuit gadget proves for exclusion).
+    pub fn verify(&self, root: &HashOut<F>) -> bool {
+        if self.siblings.len() != SMT_DEPTH || self.root != *root {
+            return false;
+        }
+        let key = U256::from_hash(&self.key);
+        let leaf = self.value.map(|v| leaf_hash(&self.key, &v)).unwrap_or(HashOut::ZERO);
+        let computed = self.siblings.iter().enumerate().fold(leaf, |node, (level, sibling)| {
+            if key.bit(level) {
+                hash_pair(sibling, &node)
+            } else {
+                hash_pair(&node, sibling)
+            }
+        });
+        computed == *root
+    }
+
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+}
+
+/// Poseidon SMT. Sirf leaves aur woh nodes store hote hain jinke neeche 2+ keys
+/// hain; ek-key wale subtree ka hash on demand (<= 256 hashes) banta hai, isliye
+/// memory ~O(n) hai, O(256 * n) nahi.
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree {
+    entries: BTreeMap<U256, Entry>,
+    nodes: HashMap<(u16, U256), HashOut<F>>,
+    zeros: Vec<HashOut<F>>,
+}
+
+impl Default for SparseMerkleTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparseMerkleTree {
+    pub fn new() -> Self {
+        Self { entries: BTreeMap::new(), nodes: HashMap::new(), zeros: zero_hashes() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn root(&self) -> HashOut<F> {
+        self.node(SMT_DEPTH, &U256::default())
+    }
+
+    pub fn get(&self, key: &HashOut<F>) -> Option<HashOut<F>> {
+        self.entries.get(&U256::from_hash(key)).map(|e| e.value)
+    }
+
+    pub fn contains(&self, key: &HashOut<F>) -> bool {
+        self.entries.contains_key(&U256::from_hash(key))
+    }
+
+    /// Adds a new key; fails if it is already present.
+    pub fn insert(&mut self, key: HashOut<F>, value: HashOut<F>) -> ZkpResult<()> {
+        if self.contains(&key) {
+            return Err(ZkpError::InvalidInput("key already in the tree".into()));
+        }
+        self.write(key, Some(value));
+        Ok(())
+    }
+
+    /// Changes the value of an existing key.
+    pub fn update(&mut self, key: HashOut<F>, value: HashOut<F>) -> ZkpResult<()> {
+        if !self.contains(&key) {
+            return Err(ZkpError::InvalidInput("key not in the tree".into()));
+        }
+        self.write(key, Some(value));
+        Ok(())
+    }
+
+    /// Removes a key (e.g. un-revoke); returns its old value.
+    pub fn delete(&mut self, key: &HashOut<F>) -> ZkpResult<HashOut<F>> {
+        let old = self.get(key).ok_or_else(|| ZkpError::InvalidInput("key not in the tree".into()))?;
+        self.write(*key, None);
+        Ok(old)
+    }
+
+    /// Inclusion proof if `key` is present, exclusion proof otherwise.
+    pub fn prove(&self, key: &HashOut<F>) -> SmtProof {
+        let k = U256::from_hash(key);
+        let siblings = (0..SMT_DEPTH).map(|level| self.node(level, &k.shr(level).flip_bit0())).collect();
+        SmtProof { key: *key, root: self.root(), value: self.get(key), siblings }
+    }
+
+    fn write(&mut self, key: HashOut<F>, value: Option<HashOut<F>>) {
+        let k = U256::from_hash(&key);
+        match value {
+            Some(value) => {
+                self.entries.insert(k, Entry { value, leaf: leaf_hash(&key, &value) });
+            }
+            None => {
+                self.entries.remove(&k);
+            }
+        }
+
+        // Neeche se upar: sirf 2+ key wale subtrees cache me
+        for height in 1..=SMT_DEPTH {
+            let prefix = k.shr(height);
+            let id = (height as u16, prefix);
+            if self.keys_under(height, &prefix) >= 2 {
+                let child = k.shr(height - 1);
+                let (left, right) = if child.bit(0) { (child.flip_bit0(), child) } else { (child, child.flip_bit0()) };
+                let hash = hash_pair(&self.node(height - 1, &left), &self.node(height - 1, &right));
+                self.nodes.insert(id, hash);
+            } else {
+                self.nodes.remove(&id);
+            }
+        }
+    }
+
+    /// 0, 1 or 2 (= "two or more").
+    fn keys_under(&self, height: usize, prefix: &U256) -> usize {
+        let (low, high) = U256::subtree_range(prefix, height);
+        self.entries.range(low..=high).take(2).count()
+    }
+
+    fn node(&self, height: usize, prefix: &U256) -> HashOut<F> {
+        if height == 0 {
+            return self.entries.get(prefix).map(|e| e.leaf).unwrap_or(HashOut::ZERO);
+        }
+        if let Some(hash) = self.nodes.get(&(height as u16, *prefix)) {
+            return *hash;
+        }
+
+        let (low, high) = U256::subtree_range(prefix, height);
+        let mut under = self.entries.range(low..=high);
+        match (under.next(), under.next()) {
+            (None, _) => self.zeros[height],
+            (Some((k, entry)), None) => (0..height).fold(entry.leaf, |node, level| {
+                if k.bit(level) {
+                    hash_pair(&self.zeros[level], &node)
+                } else {
+                    hash_pair(&node, &self.zeros[level])
+                }
+            }),
+            // `write` har 2+ key wala node cache karta hai; yeh sirf safety net hai
+            _ => hash_pair(&self.node(height - 1, &prefix.shl(1)), &self.node(height - 1, &prefix.shl(1).flip_bit0())),
+        }
+    }
+}
+
+mod hex_hash_opt {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(hash: &Option<HashOut<F>>, s: S) -> Result<S::Ok, S::Error> {
+        match hash {
+            Some(hash) => s.serialize_some(&crate::merkle::hash_hex(hash)),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, De: Deserializer<'de>>(d: De) -> Result<Option<HashOut<F>>, De::Error> {
+        Option::<String>::deserialize(d)?
+            .map(|v| crate::merkle::hash_from_hex(&v).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📮 REVOCATION REGISTRY (issuer side)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// What verifiers need offline: the current root and a monotonic version.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RevocationRoot {
+    pub version: u64,
+    #[serde(with = "hex_hash")]
+    pub root: HashOut<F>,
+    pub revoked: u64,
+}
+
+impl RevocationRoot {
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+
+    /// Same `i/n|data` frames as proofs (qr_stream.rs).
+    pub fn to_qr_frames(&self) -> ZkpResult<Vec<String>> {
+        Ok(qr_stream::split(&self.to_json()?, qr_stream::QR_CHUNK_SIZE))
+    }
+
+    pub fn from_qr_frames<S: AsRef<str>>(frames: &[S]) -> ZkpResult<Self> {
+        Self::from_json(&qr_stream::join(frames)?)
+    }
+}
+
+/// Revoked credential IDs; value = `[revoked_at, reason, 0, 0]`.
+#[derive(Debug, Clone, Default)]
+pub struct RevocationRegistry {
+    tree: SparseMerkleTree,
+    version: u64,
+}
+
+impl RevocationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn revoke(&mut self, credential_id: &str, revoked_at: u64, reason: u64) -> ZkpResult<()> {
+        self.revoke_key(credential_key(credential_id), revoked_at, reason)
+    }
+
+    /// Same as `revoke`, for keys derived in-circuit (signed credentials, see
+    /// `Credential::revocation_key`).
+    pub fn revoke_key(&mut self, key: HashOut<F>, revoked_at: u64, reason: u64) -> ZkpResult<()> {
+        let value = HashOut {
+            elements: [F::from_noncanonical_u64(revoked_at), F::from_noncanonical_u64(reason), F::ZERO, F::ZERO],
+        };
+        self.tree.insert(key, value)?;
+        self.version += 1;
+        Ok(())
+    }
+
+    pub fn unrevoke(&mut self, credential_id: &str) -> ZkpResult<()> {
+        self.unrevoke_key(&credential_key(credential_id))
+    }
+
+    pub fn unrevoke_key(&mut self, key: &HashOut<F>) -> ZkpResult<()> {
+        self.tree.delete(key)?;
+        self.version += 1;
+        Ok(())
+    }
+
+    pub fn is_revoked(&self, credential_id: &str) -> bool {
+        self.tree.contains(&credential_key(credential_id))
+    }
+
+    /// Exclusion proof for a holder (inclusion if the credential is revoked).
+    pub fn proof_for(&self, credential_id: &str) -> SmtProof {
+        self.tree.prove(&credential_key(credential_id))
+    }
+
+    pub fn proof_for_key(&self, key: &HashOut<F>) -> SmtProof {
+        self.tree.prove(key)
+    }
+
+    pub fn published_root(&self) -> RevocationRoot {
+        RevocationRoot { version: self.version, root: self.tree.root(), revoked: self.tree.len() as u64 }
+    }
+
+    pub fn tree(&self) -> &SparseMerkleTree {
+        &self.tree
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧠 NON-MEMBERSHIP GADGET
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// 256 LE bits of a hash (SMT key path, Schnorr challenge). `split_le(x, 64)`
+/// akela unique nahi hai (x aur x + p dono 64 bits me aa sakte hain), isliye
+/// canonical check: high 32 bits sab 1 hon to low 32 bits 0 hone chahiye
+/// (p = 2^64 - 2^32 + 1). Warna prover key ka doosra rasta chun kar "khaali"
+/// leaf dikha deta.
+pub(crate) fn canonical_bits(builder: &mut CircuitBuilder<F, D>, key: HashOutTarget) -> Vec<BoolTarget> {
+    let mut out = Vec::with_capacity(SMT_DEPTH);
+    for element in key.elements {
+        let bits = builder.split_le(element, 64);
+        assert_canonical_u64(builder, &bits);
+        out.extend(bits);
+    }
+    out
+}
+
+/// 64 LE bits encode a value < p.
+fn assert_canonical_u64(builder: &mut CircuitBuilder<F, D>, bits: &[BoolTarget]) {
+    let low = builder.le_sum(bits[..32].iter());
+    let mut high_all_ones = builder._true();
+    for bit in &bits[32..] {
+        high_all_ones = builder.and(high_all_ones, *bit);
+    }
+    let overflow = builder.mul(high_all_ones.target, low);
+    builder.assert_zero(overflow);
+}
+
+/// Witness slots of one `non_membership_gadget` instance.
+pub(crate) struct NonMembershipTargets {
+    siblings: Vec<HashOutTarget>,
+}
+
+impl NonMembershipTargets {
+    pub(crate) fn set_witness(&self, pw: &mut PartialWitness<F>, proof: &SmtProof) -> ZkpResult<()> {
+        if proof.is_inclusion() {
+            return Err(ZkpError::InvalidInput("credential is revoked".into()));
+        }
+        if proof.siblings.len() != SMT_DEPTH {
+            return Err(ZkpError::InvalidInput(format!("revocation proof needs {} siblings", SMT_DEPTH)));
+        }
+        for (target, sibling) in self.siblings.iter().zip(&proof.siblings) {
+            pw.set_hash_target(*target, *sibling);
+        }
+        Ok(())
+    }
+}
+
+/// Constrains "`key` is NOT in the tree with `root`" (leaf at `key` is empty).
+/// Drop it into any circuit that already has the credential key as a target.
+pub(crate) fn non_membership_gadget(
+    builder: &mut CircuitBuilder<F, D>,
+    key: HashOutTarget,
+    root: HashOutTarget,
+) -> NonMembershipTargets {
+    let bits = canonical_bits(builder, key);
+    let siblings = builder.add_virtual_hashes(SMT_DEPTH);
+    let empty = builder.constant_hash(HashOut::ZERO);
+    let computed = merkle_root_gadget(builder, empty, &siblings, &bits);
+    builder.connect_hashes(computed, root);
+    NonMembershipTargets { siblings }
+}
+
+/// Standalone "not revoked" proof. Public: revocation root + Poseidon(key), so the
+/// statement can be tied to whatever else commits to the same credential.
+pub(crate) struct NonRevocationCircuit {
+    pub(crate) data: CircuitData<F, C, D>,
+    t_key: HashOutTarget,
+    t_root: HashOutTarget,
+    exclusion: NonMembershipTargets,
+}
+
+impl NonRevocationCircuit {
+    pub(crate) fn build() -> Self {
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let t_key = builder.add_virtual_hash();
+        let t_root = builder.add_virtual_hash();
+        let exclusion = non_membership_gadget(&mut builder, t_key, t_root);
+        let commitment = builder.hash_n_to_hash_no_pad::<PoseidonHash>(t_key.elements.to_vec());
+
+        builder.register_public_inputs(&t_root.elements);
+        builder.register_public_inputs(&commitment.elements);
+
+        Self { data: builder.build::<C>(), t_key, t_root, exclusion }
+    }
+
+    pub(crate) fn prove(&self, credential_id: &str, proof: &SmtProof) -> ZkpResult<ProofWithPublicInputs<F, C, D>> {
+        let key = credential_key(credential_id);
+        if proof.key != key {
+            return Err(ZkpError::InvalidInput("revocation proof is for a different credential".into()));
+        }
+        if !proof.verify(&proof.root) {
+            return Err(ZkpError::InvalidInput("revocation proof does not match its root".into()));
+        }
+
+        let mut pw = PartialWitness::new();
+        pw.set_hash_target(self.t_key, key);
+        pw.set_hash_target(self.t_root, proof.root);
+        self.exclusion.set_witness(&mut pw, proof)?;
+
+        self.data
+            .prove(pw)
+            .map_err(|e| ZkpError::ProvingFailed(e.to_string()))
+    }
+}
+
+/// Public side of a verified non-revocation proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonRevocationPublic {
+    pub root: HashOut<F>,
+    pub commitment: HashOut<F>,
+}
+
+impl NonRevocationPublic {
+    fn from_proof(proof: &ProofWithPublicInputs<F, C, D>) -> ZkpResult<Self> {
+        let hash = |at: usize| -> ZkpResult<HashOut<F>> {
+            let elements = proof
+                .public_inputs
+                .get(at..at + 4)
+                .ok_or_else(|| ZkpError::CorruptProof("revocation public inputs missing".into()))?;
+            Ok(HashOut { elements: elements.try_into().expect("4 elements") })
+        };
+        Ok(Self { root: hash(PI_ROOT)?, commitment: hash(PI_COMMITMENT)? })
+    }
+
+    pub fn commits_to(&self, credential_id: &str) -> bool {
+        self.commitment == PoseidonHash::hash_no_pad(&credential_key(credential_id).elements)
+    }
+}
+
+/// Holder side: base64 proof that `credential_id` is not under `proof.root`.
+pub fn prove_not_revoked(credential_id: &str, proof: &SmtProof, circuits: &CircuitCache) -> ZkpResult<String> {
+    let proof = circuits.revocation().prove(credential_id, proof)?;
+    let bytes = bincode::serialize(&proof).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+    Ok(general_purpose::STANDARD.encode(bytes))
+}
+
+/// Verifier side: checks the proof against the root it got offline. An older
+/// root is rejected, so a credential revoked since then cannot slip through.
+pub fn verify_not_revoked(
+    proof_b64: &str,
+    published: &RevocationRoot,
+    circuits: &CircuitCache,
+) -> ZkpResult<NonRevocationPublic> {
+    let bytes = general_purpose::STANDARD.decode(proof_b64.trim())?;
+    let proof: ProofWithPublicInputs<F, C, D> =
+        bincode::deserialize(&bytes).map_err(|e| ZkpError::CorruptProof(e.to_string()))?;
+
+    let public = NonRevocationPublic::from_proof(&proof)?;
+    if public.root != published.root {
+        return Err(ZkpError::ProofRejected(format!("stale revocation root (published version {})", published.version)));
+    }
+    circuits
+        .revocation()
+        .data
+        .verify(proof)
+        .map_err(|e| ZkpError::ProofRejected(format!("{:?}", e)))?;
+    Ok(public)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::{jboolean, jstring, JNI_FALSE, JNI_TRUE};
+    use log::info;
+
+    use crate::engine::CircuitCache;
+    use crate::error::ZkpError;
+    use crate::jni_bridge::{guard, new_string, read_string};
+
+    /// `smtProofJson` = the issuer's exclusion proof for this credential; returns base64.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Revocation_proveNotRevoked(
+        mut env: JNIEnv,
+        _class: JClass,
+        credential_id: JString,
+        smt_proof_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let credential_id = read_string(env, &credential_id, "credential_id")?;
+            let smt_proof = super::SmtProof::from_json(&read_string(env, &smt_proof_json, "smt_proof_json")?)?;
+
+            let proof = super::prove_not_revoked(&credential_id, &smt_proof, CircuitCache::shared())?;
+            info!("✅ Non-revocation proof generated");
+            new_string(env, proof)
+        })
+    }
+
+    /// `rootJson` = `RevocationRoot` the verifier got offline (QR / bundle).
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Revocation_verifyNotRevoked(
+        mut env: JNIEnv,
+        _class: JClass,
+        proof_b64: JString,
+        root_json: JString,
+    ) -> jboolean {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let proof = read_string(env, &proof_b64, "proof_b64")?;
+            let published = super::RevocationRoot::from_json(&read_string(env, &root_json, "root_json")?)?;
+
+            match super::verify_not_revoked(&proof, &published, CircuitCache::shared()) {
+                Ok(_) => Ok(JNI_TRUE),
+                Err(ZkpError::ProofRejected(reason)) => {
+                    info!("❌ Non-revocation rejected: {}", reason);
+                    Ok(JNI_FALSE)
+                }
+                Err(e) => Err(e),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::proves;
+
+    fn key(n: u64) -> HashOut<F> {
+        credential_key(&format!("cred-{}", n))
+    }
+
+    fn value(n: u64) -> HashOut<F> {
+        HashOut { elements: [F::from_canonical_u64(n), F::ZERO, F::ZERO, F::ZERO] }
+    }
+
+    #[test]
+    fn root_is_order_independent_and_delete_restores_it() {
+        let empty = SparseMerkleTree::new().root();
+        let mut a = SparseMerkleTree::new();
+        let mut b = SparseMerkleTree::new();
+        for n in 0..20 {
+            a.insert(key(n), value(n)).unwrap();
+        }
+        for n in (0..20).rev() {
+            b.insert(key(n), value(n)).unwrap();
+        }
+        assert_eq!(a.root(), b.root());
+        assert!(a.insert(key(3), value(9)).is_err());
+
+        let before = a.root();
+        a.update(key(3), value(99)).unwrap();
+        assert_ne!(a.root(), before);
+        a.update(key(3), value(3)).unwrap();
+        assert_eq!(a.root(), before);
+
+        for n in 0..20 {
+            a.delete(&key(n)).unwrap();
+        }
+        assert_eq!(a.root(), empty);
+        assert!(a.delete(&key(0)).is_err());
+    }
+
+    #[test]
+    fn inclusion_and_exclusion_proofs_verify_natively() {
+        let mut tree = SparseMerkleTree::new();
+        for n in 0..8 {
+            tree.insert(key(n), value(n)).unwrap();
+        }
+        let inclusion = tree.prove(&key(5));
+        assert!(inclusion.is_inclusion() && inclusion.verify(&tree.root()));
+        let exclusion = tree.prove(&key(100));
+        assert!(!exclusion.is_inclusion() && exclusion.verify(&tree.root()));
+
+        // Inclusion proof ko "khaali" bata ke exclusion nahi banta
+        let lie = SmtProof { value: None, ..inclusion.clone() };
+        assert!(!lie.verify(&tree.root()));
+        assert!(!exclusion.verify(&SparseMerkleTree::new().root()));
+        assert_eq!(SmtProof::from_json(&exclusion.to_json().unwrap()).unwrap(), exclusion);
+    }
+
+    #[test]
+    fn canonical_check_rejects_the_plus_p_alias() {
+        const P: u64 = 0xFFFF_FFFF_0000_0001;
+        let check = |value: u64| {
+            let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+            let bits: Vec<BoolTarget> = (0..64).map(|_| builder.add_virtual_bool_target_safe()).collect();
+            assert_canonical_u64(&mut builder, &bits);
+            let data = builder.build::<C>();
+            let mut pw = PartialWitness::new();
+            for (i, bit) in bits.iter().enumerate() {
+                pw.set_bool_target(*bit, (value >> i) & 1 == 1);
+            }
+            proves(&data, pw)
+        };
+        assert!(check(0));
+        assert!(check(P - 1));
+        assert!(check(u64::MAX << 32)); // = p - 1
+        // x + p for small x: same field element, different bits (a different SMT path)
+        assert!(!check(5 + P));
+        assert!(!check(u64::MAX));
+    }
+
+    #[test]
+    fn not_revoked_round_trip() {
+        let circuits = CircuitCache::default();
+        let mut registry = RevocationRegistry::new();
+        registry.revoke("cred-other", 1_700_000_000, 1).unwrap();
+
+        let proof = prove_not_revoked("cred-alice", &registry.proof_for("cred-alice"), &circuits).unwrap();
+        let public = verify_not_revoked(&proof, &registry.published_root(), &circuits).unwrap();
+        assert!(public.commits_to("cred-alice"));
+        assert!(!public.commits_to("cred-bob"));
+    }
+
+    #[test]
+    fn revoked_credential_cannot_prove_exclusion() {
+        let circuits = CircuitCache::default();
+        let mut registry = RevocationRegistry::new();
+        registry.revoke("cred-other", 1_700_000_000, 1).unwrap();
+        registry.revoke("cred-alice", 1_700_000_000, 2).unwrap();
+
+        let inclusion = registry.proof_for("cred-alice");
+        assert!(prove_not_revoked("cred-alice", &inclusion, &circuits).is_err());
+        let lie = SmtProof { value: None, ..inclusion.clone() };
+        assert!(prove_not_revoked("cred-alice", &lie, &circuits).is_err());
+
+        // Native checks skip karke seedha circuit: empty leaf wala rasta current root tak nahi pahunchta
+        let circuit = circuits.revocation();
+        let mut pw = PartialWitness::new();
+        pw.set_hash_target(circuit.t_key, credential_key("cred-alice"));
+        pw.set_hash_target(circuit.t_root, registry.published_root().root);
+        for (target, sibling) in circuit.exclusion.siblings.iter().zip(&inclusion.siblings) {
+            pw.set_hash_target(*target, *sibling);
+        }
+        assert!(!proves(&circuit.data, pw));
+    }
+
+    #[test]
+    fn proof_against_a_stale_root_is_rejected() {
+        let circuits = CircuitCache::default();
+        let mut registry = RevocationRegistry::new();
+        let old = registry.proof_for("cred-alice");
+        let proof = prove_not_revoked("cred-alice", &old, &circuits).unwrap();
+
+        registry.revoke("cred-alice", 1_700_000_000, 1).unwrap();
+        let published = registry.published_root();
+        assert_eq!(published.version, 1);
+        assert!(matches!(verify_not_revoked(&proof, &published, &circuits), Err(ZkpError::ProofRejected(_))));
+    }
+}
@@ -0,0 +1,269 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ✍️ SCHNORR SIGNATURES over ecgfp5 (issuer keys)
+// Message = ek Poseidon HashOut (credential ka digest). Challenge bhi Poseidon:
+//   e = Poseidon(R.x || R.y || PK.x || PK.y || msg)   (256 bits, 4 elements)
+//   s = k + e * sk (mod n),  verify: s*G - e*PK == R
+// Sab kuch Goldilocks-native hai, isliye wahi check circuit ke andar bhi sasta
+// hai (`schnorr_gadget`): proof keh sakta hai "issuer ne yeh attributes sign kiye".
+// ═══════════════════════════════════════════════════════════════════════════
+
+use num_bigint::BigUint;
+use sha2::{Digest, Sha512};
+
+use plonky2::field::types::PrimeField64;
+use plonky2::hash::hash_types::{HashOut, HashOutTarget};
+use plonky2::hash::poseidon::PoseidonHash;
+use plonky2::iop::target::BoolTarget;
+use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::config::{GenericConfig, Hasher, PoseidonGoldilocksConfig};
+
+use crate::ecgfp5::gadgets::{
+    add_virtual_affine, affine_to_point, connect_affine, constant_point, curve_neg, double_scalar_mul,
+    set_affine_target, AffinePointTarget,
+};
+use crate::ecgfp5::{order, AffinePoint, Point, Scalar, SCALAR_BITS};
+use crate::error::{ZkpError, ZkpResult};
+use crate::revocation::canonical_bits;
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// Issuer public key (validated: on the curve, prime-order subgroup).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PublicKey(pub(crate) AffinePoint);
+
+/// Issuer secret key. `Debug` is redacted.
+#[derive(Clone)]
+pub struct SecretKey(Scalar);
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Signature {
+    pub(crate) r: AffinePoint,
+    pub(crate) s: Scalar,
+}
+
+impl std::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretKey(..)")
+    }
+}
+
+fn challenge(r: &AffinePoint, pk: &AffinePoint, msg: &HashOut<F>) -> HashOut<F> {
+    let inputs: Vec<F> = [r.x.0, r.y.0, pk.x.0, pk.y.0].concat().into_iter().chain(msg.elements).collect();
+    PoseidonHash::hash_no_pad(&inputs)
+}
+
+/// The challenge as an integer (< 2^256, no reduction: the circuit uses its bits).
+fn challenge_int(e: &HashOut<F>) -> BigUint {
+    e.elements
+        .iter()
+        .rev()
+        .fold(BigUint::from(0u32), |acc, x| (acc << 64) + x.to_canonical_u64())
+}
+
+impl SecretKey {
+    pub fn generate() -> Self {
+        Self(Scalar::random())
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        let point = Point::generator().mul_secret(&self.0 .0).to_affine().expect("sk != 0");
+        PublicKey(point)
+    }
+
+    /// Hedged nonce: SHA-512(sk || msg || fresh randomness), so a bad RNG alone
+    /// cannot leak the key through a repeated `k`.
+    pub fn sign(&self, msg: &HashOut<F>) -> Signature {
+        use rand::RngCore;
+        let mut fresh = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut fresh);
+
+        let mut hasher = Sha512::new();
+        hasher.update(b"zkp-schnorr-ecgfp5-nonce");
+        hasher.update(self.0.to_bytes());
+        for x in msg.elements {
+            hasher.update(x.to_canonical_u64().to_le_bytes());
+        }
+        hasher.update(fresh);
+        let k = Scalar::from_biguint(&BigUint::from_bytes_le(&hasher.finalize()));
+
+        let pk = self.public_key();
+        let r = Point::generator().mul_secret(&k.0).to_affine().expect("k != 0 w.h.p.");
+        let e = Scalar::from_biguint(&challenge_int(&challenge(&r, &pk.0, msg)));
+        Signature { r, s: k.add(&e.mul(&self.0)) }
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0.to_bytes())
+    }
+
+    pub fn from_hex(value: &str) -> ZkpResult<Self> {
+        let scalar = Scalar::from_bytes(&hex::decode(value.trim())?)?;
+        if scalar.is_zero() {
+            return Err(ZkpError::InvalidInput("secret key must not be zero".into()));
+        }
+        Ok(Self(scalar))
+    }
+}
+
+impl PublicKey {
+    pub fn verify(&self, msg: &HashOut<F>, signature: &Signature) -> bool {
+        if !signature.r.is_on_curve() {
+            return false;
+        }
+        let e = challenge_int(&challenge(&signature.r, &self.0, msg)) % order();
+        let g = Point::generator();
+        let lhs = g.mul(&signature.s.0) + (-Point::from(self.0)).mul(&e);
+        lhs == Point::from(signature.r)
+    }
+
+    /// 80 bytes hex (x then y, little-endian limbs).
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0.to_bytes())
+    }
+
+    pub fn from_hex(value: &str) -> ZkpResult<Self> {
+        Ok(Self(AffinePoint::from_bytes(&hex::decode(value.trim())?)?))
+    }
+
+    /// The 10 field elements a circuit exposes as public inputs.
+    pub fn elements(&self) -> Vec<F> {
+        [self.0.x.0, self.0.y.0].concat()
+    }
+}
+
+impl Signature {
+    /// 120 bytes hex: R (80) || s (40).
+    pub fn to_hex(&self) -> String {
+        let mut bytes = self.r.to_bytes();
+        bytes.extend(self.s.to_bytes());
+        hex::encode(bytes)
+    }
+
+    pub fn from_hex(value: &str) -> ZkpResult<Self> {
+        let bytes = hex::decode(value.trim())?;
+        if bytes.len() != 120 {
+            return Err(ZkpError::InvalidInput(format!("signature must be 120 bytes, got {}", bytes.len())));
+        }
+        Ok(Self { r: AffinePoint::from_bytes(&bytes[..80])?, s: Scalar::from_bytes(&bytes[80..])? })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧠 SIGNATURE GADGET
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Witness slots of one `schnorr_gadget` (the signature stays private).
+pub(crate) struct SignatureTargets {
+    r: AffinePointTarget,
+    s_bits: Vec<BoolTarget>,
+}
+
+impl SignatureTargets {
+    pub(crate) fn set_witness(&self, pw: &mut PartialWitness<F>, signature: &Signature) {
+        set_affine_target(pw, &self.r, &signature.r);
+        for (target, bit) in self.s_bits.iter().zip(signature.s.bits_le()) {
+            pw.set_bool_target(*target, bit);
+        }
+    }
+}
+
+/// Constrains "`pk` signed `msg`". `pk` MUST be pinned by the verifier (public
+/// input compared against a trusted key): the formulas assume a subgroup point.
+pub(crate) fn schnorr_gadget(
+    builder: &mut CircuitBuilder<F, D>,
+    pk: &AffinePointTarget,
+    msg: HashOutTarget,
+) -> SignatureTargets {
+    let r = add_virtual_affine(builder);
+    let s_bits: Vec<BoolTarget> = (0..SCALAR_BITS).map(|_| builder.add_virtual_bool_target_safe()).collect();
+
+    let mut inputs = r.elements();
+    inputs.extend(pk.elements());
+    inputs.extend(msg.elements);
+    let e = builder.hash_n_to_hash_no_pad::<PoseidonHash>(inputs);
+    let e_bits = canonical_bits(builder, e);
+
+    // s*G + e*(-PK) == R
+    let g = constant_point(builder, &Point::generator());
+    let pk_point = affine_to_point(builder, pk);
+    let neg_pk = curve_neg(builder, pk_point);
+    let lhs = double_scalar_mul(builder, &s_bits, g, &e_bits, neg_pk);
+    connect_affine(builder, lhs, &r);
+
+    SignatureTargets { r, s_bits }
+}
+
+/// Sets a public-key target (e.g. the issuer slot of a credential circuit).
+pub(crate) fn set_public_key_target(pw: &mut PartialWitness<F>, target: &AffinePointTarget, pk: &PublicKey) {
+    set_affine_target(pw, target, &pk.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use plonky2::field::types::Field;
+    use plonky2::plonk::circuit_data::CircuitConfig;
+
+    use super::*;
+    use crate::test_util::proves;
+
+    fn msg(n: u64) -> HashOut<F> {
+        PoseidonHash::hash_no_pad(&[F::from_canonical_u64(n)])
+    }
+
+    #[test]
+    fn sign_verify_round_trip() {
+        let sk = SecretKey::generate();
+        let pk = sk.public_key();
+        let signature = sk.sign(&msg(1));
+        assert!(pk.verify(&msg(1), &signature));
+        assert!(!pk.verify(&msg(2), &signature));
+        assert!(!SecretKey::generate().public_key().verify(&msg(1), &signature));
+
+        let sk_again = SecretKey::from_hex(&sk.to_hex()).unwrap();
+        assert_eq!(sk_again.public_key(), pk);
+        assert_eq!(PublicKey::from_hex(&pk.to_hex()).unwrap(), pk);
+        assert_eq!(Signature::from_hex(&signature.to_hex()).unwrap(), signature);
+    }
+
+    #[test]
+    fn flipped_signature_bit_is_rejected() {
+        let sk = SecretKey::generate();
+        let pk = sk.public_key();
+        let signature = sk.sign(&msg(7));
+
+        let mut s_flipped = signature.clone();
+        s_flipped.s = Scalar::from_biguint(&(&signature.s.0 ^ BigUint::from(1u32)));
+        assert!(!pk.verify(&msg(7), &s_flipped));
+
+        // R me bit flip: ya to parse fail, ya verify fail
+        let mut bytes = hex::decode(signature.to_hex()).unwrap();
+        bytes[3] ^= 0x10;
+        if let Ok(tampered) = Signature::from_hex(&hex::encode(bytes)) {
+            assert!(!pk.verify(&msg(7), &tampered));
+        }
+    }
+
+    #[test]
+    fn gadget_accepts_valid_and_rejects_other_message() {
+        let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+        let pk_target = add_virtual_affine(&mut builder);
+        let msg_target = builder.add_virtual_hash();
+        let signature_targets = schnorr_gadget(&mut builder, &pk_target, msg_target);
+        let data = builder.build::<C>();
+
+        let sk = SecretKey::generate();
+        let signature = sk.sign(&msg(3));
+        let witness = |m: HashOut<F>| {
+            let mut pw = PartialWitness::new();
+            set_public_key_target(&mut pw, &pk_target, &sk.public_key());
+            pw.set_hash_target(msg_target, m);
+            signature_targets.set_witness(&mut pw, &signature);
+            pw
+        };
+        assert!(proves(&data, witness(msg(3))));
+        assert!(!proves(&data, witness(msg(4))));
+    }
+}
@@ -0,0 +1,1016 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🪪 SD-JWT VC (selective disclosure JWT) CREDENTIALS
+// Wallet se `<JWT>~<disclosure>~<disclosure>~[KB-JWT]` aata hai:
+//   • JWT issuer ki key se signed (ES256 / EdDSA), payload me `_sd` digests
+//   • disclosure = base64url(JSON `[salt, name, value]`), digest =
+//     base64url(SHA-256(disclosure ka ASCII))
+// Native verify: signature (trusted issuer JWK list) -> exp / nbf -> har
+// disclosure ka digest payload me (nested `_sd` / array `...` bhi) -> claims.
+// ZK wrap: ek numeric claim hidden rakhkar predicate prove karo. Circuit
+// disclosure ka SHA-256 khud nikalta hai (gadgets/sha256.rs), digest public
+// input banta hai, aur verifier use signed JWT ke top-level `_sd` me dhoondta
+// hai. Value base64url decode karke `"name",<digits>]` se padhi jaati hai.
+// KB-JWT (holder binding) yahan check nahi hota, sirf saath chalta hai.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use base64::{Engine as _, engine::general_purpose};
+use p256::ecdsa::signature::Verifier;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use plonky2::field::types::{Field, PrimeField64};
+use plonky2::iop::target::{BoolTarget, Target};
+use plonky2::iop::witness::{PartialWitness, WitnessWrite};
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::plonk::circuit_data::{CircuitConfig, CircuitData};
+use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+use plonky2::plonk::proof::ProofWithPublicInputs;
+
+use crate::engine::CircuitCache;
+use crate::envelope::digest_hex;
+use crate::error::{ZkpError, ZkpResult};
+use crate::gadgets::comparison::assert_lte_const;
+use crate::gadgets::set_membership::{AllowedSet, SetMembershipTargets};
+use crate::gadgets::sha256::{self, sha256_compress, u32_from_be_bytes, u32_value, U32Target};
+use crate::policy::{assert_predicate, Clause, Predicate};
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// The only `_sd_alg` we accept (also the one the circuit implements).
+pub const SD_ALG: &str = "sha-256";
+
+/// SHA-256 blocks the circuit hashes; the disclosure plus padding must fit.
+const MAX_BLOCKS: usize = 3;
+/// Longest encoded disclosure a predicate can be proven over (183 chars).
+pub const MAX_DISCLOSURE_CHARS: usize = MAX_BLOCKS * 64 - 9;
+/// Whole bytes in the decoded disclosure.
+const MAX_DECODED: usize = MAX_DISCLOSURE_CHARS * 6 / 8;
+/// Integer claims up to 18 digits (< 2^60, inside the comparison width).
+pub const MAX_DIGITS: usize = 18;
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn b64url(what: &str, text: &str) -> ZkpResult<Vec<u8>> {
+    general_purpose::URL_SAFE_NO_PAD
+        .decode(text)
+        .map_err(|e| ZkpError::InvalidEncoding(format!("{}: {}", what, e)))
+}
+
+fn json_part<T: serde::de::DeserializeOwned>(what: &str, text: &str) -> ZkpResult<T> {
+    serde_json::from_slice(&b64url(what, text)?).map_err(|e| ZkpError::MalformedJson(format!("{}: {}", what, e)))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🏛️ TRUSTED ISSUERS (JWK)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Public JWK: `EC` / `P-256` (ES256) or `OKP` / `Ed25519` (EdDSA).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Jwk {
+    pub kty: String,
+    pub crv: String,
+    pub x: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+impl Jwk {
+    fn verify(&self, alg: &str, signing_input: &[u8], signature: &[u8]) -> ZkpResult<()> {
+        match (alg, self.kty.as_str(), self.crv.as_str()) {
+            ("ES256", "EC", "P-256") => {
+                let y = self.y.as_deref().ok_or_else(|| ZkpError::InvalidInput("P-256 JWK without y".into()))?;
+                let mut point = vec![0x04];
+                point.extend(b64url("jwk.x", &self.x)?);
+                point.extend(b64url("jwk.y", y)?);
+                let key = p256::ecdsa::VerifyingKey::from_sec1_bytes(&point)
+                    .map_err(|e| ZkpError::InvalidInput(format!("P-256 JWK: {}", e)))?;
+                // JWS: raw r || s, DER nahi
+                let signature = p256::ecdsa::Signature::from_slice(signature)
+                    .map_err(|e| ZkpError::SignatureInvalid(e.to_string()))?;
+                key.verify(signing_input, &signature)
+                    .map_err(|e| ZkpError::SignatureInvalid(format!("ES256: {}", e)))
+            }
+            ("EdDSA", "OKP", "Ed25519") => {
+                let x: [u8; 32] = b64url("jwk.x", &self.x)?
+                    .try_into()
+                    .map_err(|_| ZkpError::InvalidInput("Ed25519 JWK must be 32 bytes".into()))?;
+                let key = ed25519_dalek::VerifyingKey::from_bytes(&x)
+                    .map_err(|e| ZkpError::InvalidInput(format!("Ed25519 JWK: {}", e)))?;
+                let signature = ed25519_dalek::Signature::from_slice(signature)
+                    .map_err(|e| ZkpError::SignatureInvalid(e.to_string()))?;
+                key.verify_strict(signing_input, &signature)
+                    .map_err(|e| ZkpError::SignatureInvalid(format!("EdDSA: {}", e)))
+            }
+            (alg, kty, crv) => Err(ZkpError::SignatureInvalid(format!("unsupported alg {} for a {} / {} key", alg, kty, crv))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SdJwtIssuer {
+    /// Must equal the JWT's `iss`.
+    pub iss: String,
+    pub jwk: Jwk,
+}
+
+/// JSON: `{"version": 1, "issuers": [{"iss", "jwk": {...}}]}`. Local, no `.well-known` lookups.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SdJwtIssuers {
+    pub version: u64,
+    pub issuers: Vec<SdJwtIssuer>,
+}
+
+impl SdJwtIssuers {
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Key for `iss`; with a JWT `kid`, the JWK's `kid` must match too.
+    fn find(&self, iss: &str, kid: Option<&str>) -> Option<&SdJwtIssuer> {
+        self.issuers
+            .iter()
+            .filter(|i| i.iss == iss)
+            .find(|i| kid.is_none() || i.jwk.kid.is_none() || i.jwk.kid.as_deref() == kid)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📦 PARSING
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone)]
+pub struct Disclosure {
+    /// Exactly as received; the digest is over these ASCII bytes.
+    pub encoded: String,
+    pub salt: String,
+    /// `None` for array element disclosures (`[salt, value]`).
+    pub name: Option<String>,
+    pub value: Value,
+}
+
+impl Disclosure {
+    pub fn parse(encoded: &str) -> ZkpResult<Self> {
+        let array: Vec<Value> = json_part("disclosure", encoded)?;
+        let (salt, name, value) = match array.as_slice() {
+            [Value::String(salt), Value::String(name), value] => {
+                if name == "_sd" || name == "..." {
+                    return Err(ZkpError::InvalidEncoding(format!("disclosure uses reserved name {:?}", name)));
+                }
+                (salt.clone(), Some(name.clone()), value.clone())
+            }
+            [Value::String(salt), value] => (salt.clone(), None, value.clone()),
+            _ => return Err(ZkpError::InvalidEncoding("disclosure must be [salt, name, value] or [salt, value]".into())),
+        };
+        Ok(Self { encoded: encoded.to_string(), salt, name, value })
+    }
+
+    /// base64url(SHA-256(encoded)), as listed in `_sd`.
+    pub fn digest(&self) -> String {
+        general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(self.encoded.as_bytes()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SdJwt {
+    pub jwt: String,
+    pub disclosures: Vec<Disclosure>,
+    /// Carried along, not verified here.
+    pub key_binding: Option<String>,
+}
+
+impl FromStr for SdJwt {
+    type Err = ZkpError;
+
+    /// `<JWT>~<D1>~...~<Dn>~[<KB-JWT>]`
+    fn from_str(compact: &str) -> ZkpResult<Self> {
+        let mut parts: Vec<&str> = compact.trim().split('~').collect();
+        if parts.len() < 2 {
+            return Err(ZkpError::InvalidEncoding("SD-JWT must contain `~`".into()));
+        }
+        let key_binding = parts.pop().filter(|kb| !kb.is_empty()).map(str::to_string);
+        let jwt = parts.remove(0).to_string();
+        let disclosures = parts.into_iter().map(Disclosure::parse).collect::<ZkpResult<_>>()?;
+        Ok(Self { jwt, disclosures, key_binding })
+    }
+}
+
+impl SdJwt {
+    pub fn to_compact(&self) -> String {
+        let mut compact = self.jwt.clone();
+        for disclosure in &self.disclosures {
+            compact.push('~');
+            compact.push_str(&disclosure.encoded);
+        }
+        compact.push('~');
+        compact.push_str(self.key_binding.as_deref().unwrap_or(""));
+        compact
+    }
+
+    /// The disclosure of top-level claim `name`.
+    pub fn disclosure(&self, name: &str) -> Option<&Disclosure> {
+        self.disclosures.iter().find(|d| d.name.as_deref() == Some(name))
+    }
+
+    /// Same SD-JWT with the `name` disclosure dropped (the claim stays hidden).
+    pub fn without(&self, name: &str) -> Self {
+        let disclosures = self.disclosures.iter().filter(|d| d.name.as_deref() != Some(name)).cloned().collect();
+        Self { jwt: self.jwt.clone(), disclosures, key_binding: self.key_binding.clone() }
+    }
+
+    /// Payload without checking the signature (holder side).
+    fn unverified_payload(&self) -> ZkpResult<Map<String, Value>> {
+        let payload = self.jwt.split('.').nth(1).ok_or_else(|| ZkpError::InvalidEncoding("JWT must have 3 parts".into()))?;
+        json_part("JWT payload", payload)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ✅ NATIVE VERIFICATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Serialize, Debug, Clone)]
+pub struct VerifiedSdJwt {
+    pub issuer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vct: Option<String>,
+    /// Payload with every disclosure put back; no `_sd` / `_sd_alg` left.
+    pub claims: Map<String, Value>,
+    /// Clauses over hidden claims, proven in zero knowledge.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub proven: Vec<String>,
+    /// Top-level `_sd` digests (hidden claims are proven against these).
+    #[serde(skip)]
+    digests: Vec<String>,
+}
+
+/// `_sd` / `...` digests -> disclosures, each used at most once.
+struct Resolver<'a> {
+    by_digest: HashMap<String, &'a Disclosure>,
+    seen: HashSet<String>,
+    used: HashSet<String>,
+}
+
+impl Resolver<'_> {
+    fn take(&mut self, digest: &Value) -> ZkpResult<Option<Disclosure>> {
+        let digest = digest.as_str().ok_or_else(|| ZkpError::InvalidEncoding("digests must be strings".into()))?;
+        if !self.seen.insert(digest.to_string()) {
+            return Err(ZkpError::IntegrityCheckFailed(format!("digest {} appears twice", digest)));
+        }
+        let Some(disclosure) = self.by_digest.get(digest) else { return Ok(None) };
+        self.used.insert(digest.to_string());
+        Ok(Some((*disclosure).clone()))
+    }
+
+    fn resolve(&mut self, value: &mut Value) -> ZkpResult<()> {
+        match value {
+            Value::Object(map) => {
+                let digests = match map.remove("_sd") {
+                    None => Vec::new(),
+                    Some(Value::Array(digests)) => digests,
+                    Some(_) => return Err(ZkpError::InvalidEncoding("`_sd` must be an array".into())),
+                };
+                for nested in map.values_mut() {
+                    self.resolve(nested)?;
+                }
+                for digest in &digests {
+                    let Some(disclosure) = self.take(digest)? else { continue };
+                    let Some(name) = disclosure.name else {
+                        return Err(ZkpError::IntegrityCheckFailed("array element disclosure used for an object property".into()));
+                    };
+                    let mut claim = disclosure.value;
+                    self.resolve(&mut claim)?;
+                    if map.insert(name.clone(), claim).is_some() {
+                        return Err(ZkpError::IntegrityCheckFailed(format!("claim {} disclosed twice", name)));
+                    }
+                }
+            }
+            Value::Array(items) => {
+                let mut resolved = Vec::with_capacity(items.len());
+                for mut item in items.drain(..) {
+                    // `{"...": digest}` = hidden array element
+                    let digest = item.as_object().filter(|o| o.len() == 1).and_then(|o| o.get("...")).cloned();
+                    if let Some(digest) = digest {
+                        if let Some(disclosure) = self.take(&digest)? {
+                            if disclosure.name.is_some() {
+                                return Err(ZkpError::IntegrityCheckFailed("object property disclosure used for an array element".into()));
+                            }
+                            let mut element = disclosure.value;
+                            self.resolve(&mut element)?;
+                            resolved.push(element);
+                        }
+                        continue;
+                    }
+                    self.resolve(&mut item)?;
+                    resolved.push(item);
+                }
+                *items = resolved;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Claims `verify_sd_jwt` checks itself; they must be in the signed payload.
+const SIGNED_ONLY_CLAIMS: [&str; 3] = ["iss", "exp", "nbf"];
+
+/// Verifies `sd_jwt` against `issuers` at time `now` (unix seconds) and rebuilds the claims.
+pub fn verify_sd_jwt(sd_jwt: &SdJwt, issuers: &SdJwtIssuers, now: u64) -> ZkpResult<VerifiedSdJwt> {
+    // 1. JWT signature
+    let [header_b64, payload_b64, signature_b64] = sd_jwt.jwt.split('.').collect::<Vec<_>>()[..] else {
+        return Err(ZkpError::InvalidEncoding("JWT must have 3 parts".into()));
+    };
+    let header: Map<String, Value> = json_part("JWT header", header_b64)?;
+    let mut payload: Map<String, Value> = json_part("JWT payload", payload_b64)?;
+    if let Some(typ) = header.get("typ").and_then(Value::as_str) {
+        if !typ.ends_with("sd-jwt") {
+            return Err(ZkpError::InvalidInput(format!("JWT typ {} is not an SD-JWT", typ)));
+        }
+    }
+    let alg = header.get("alg").and_then(Value::as_str).unwrap_or_default();
+    let iss = payload
+        .get("iss")
+        .and_then(Value::as_str)
+        .ok_or_else(|| ZkpError::InvalidEncoding("JWT has no iss".into()))?
+        .to_string();
+    let issuer = issuers
+        .find(&iss, header.get("kid").and_then(Value::as_str))
+        .ok_or_else(|| ZkpError::ProofRejected(format!("issuer {} is not trusted", iss)))?;
+    let signing_input = format!("{}.{}", header_b64, payload_b64);
+    issuer.jwk.verify(alg, signing_input.as_bytes(), &b64url("JWT signature", signature_b64)?)?;
+
+    // 2. Validity window. NumericDate fractional bhi ho sakta hai (`1700000000.5`)
+    let time = |claim: &str| -> ZkpResult<Option<f64>> {
+        match payload.get(claim) {
+            None => Ok(None),
+            Some(value) => value
+                .as_f64()
+                .map(Some)
+                .ok_or_else(|| ZkpError::InvalidEncoding(format!("{} must be a NumericDate", claim))),
+        }
+    };
+    let now_f = now as f64;
+    if time("exp")?.is_some_and(|exp| now_f >= exp) || time("nbf")?.is_some_and(|nbf| now_f < nbf) {
+        return Err(ZkpError::ProofRejected("SD-JWT is expired or not yet valid".into()));
+    }
+
+    // 3. Disclosures
+    match payload.remove("_sd_alg") {
+        None => {}
+        Some(Value::String(alg)) if alg == SD_ALG => {}
+        Some(other) => return Err(ZkpError::InvalidInput(format!("unsupported _sd_alg {}", other))),
+    }
+    let digests: Vec<String> = match payload.get("_sd") {
+        Some(Value::Array(digests)) => digests.iter().filter_map(Value::as_str).map(str::to_string).collect(),
+        _ => Vec::new(),
+    };
+    let mut resolver = Resolver {
+        by_digest: sd_jwt.disclosures.iter().map(|d| (d.digest(), d)).collect(),
+        seen: HashSet::new(),
+        used: HashSet::new(),
+    };
+    if resolver.by_digest.len() != sd_jwt.disclosures.len() {
+        return Err(ZkpError::InvalidInput("same disclosure sent twice".into()));
+    }
+    // Upar inhi ko signed payload se check kiya; disclosure inhe chupke se nahi la sakta
+    for digest in &digests {
+        if let Some(name) = resolver.by_digest.get(digest.as_str()).and_then(|d| d.name.as_deref()) {
+            if SIGNED_ONLY_CLAIMS.contains(&name) {
+                return Err(ZkpError::IntegrityCheckFailed(format!("{} must not be selectively disclosable", name)));
+            }
+        }
+    }
+    let mut claims = Value::Object(payload);
+    resolver.resolve(&mut claims)?;
+    if resolver.used.len() != sd_jwt.disclosures.len() {
+        return Err(ZkpError::IntegrityCheckFailed("disclosure digest is not in the signed JWT".into()));
+    }
+    let Value::Object(claims) = claims else { unreachable!("resolve keeps objects") };
+
+    Ok(VerifiedSdJwt {
+        issuer: iss,
+        vct: claims.get("vct").and_then(Value::as_str).map(str::to_string),
+        claims,
+        proven: Vec::new(),
+        digests,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧠 HIDDEN CLAIM PREDICATE CIRCUIT
+// Private: disclosure ke base64url 6-bit values + prefix / block / position
+// selectors. Public: disclosure ka SHA-256 (8 x u32, big-endian words).
+// Claim name aur predicate circuit me constants hain (spec badla = digest badla).
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SdJwtPredicateSpec {
+    pub claim: String,
+    pub predicate: Predicate,
+}
+
+impl SdJwtPredicateSpec {
+    /// `age >= 18`, `score in [1, 2, 3]`, ... over an integer claim.
+    pub fn parse(clause: &str) -> ZkpResult<Self> {
+        match Clause::from_str(clause)? {
+            Clause::Attribute { name, predicate } => Ok(Self { claim: name, predicate }),
+            _ => Err(ZkpError::InvalidInput(format!("{:?} is not a claim predicate", clause))),
+        }
+    }
+}
+
+/// `decoded[start + offset]` for the one-hot `selector`, 0 outside the bytes.
+fn select_at(builder: &mut CircuitBuilder<F, D>, selector: &[BoolTarget], bytes: &[Target], offset: isize) -> Target {
+    let mut selected = builder.zero();
+    for (m, bit) in selector.iter().enumerate() {
+        let at = m as isize + offset;
+        if at >= 0 && (at as usize) < bytes.len() {
+            selected = builder.mul_add(bit.target, bytes[at as usize], selected);
+        }
+    }
+    selected
+}
+
+/// Both JSON spellings of the bytes before the value: `","age",` and `", "age", `.
+fn value_prefixes(claim: &str) -> [Vec<u8>; 2] {
+    [format!("\",\"{}\",", claim).into_bytes(), format!("\", \"{}\", ", claim).into_bytes()]
+}
+
+pub(crate) struct SdJwtPredicateCircuit {
+    pub(crate) data: CircuitData<F, C, D>,
+    spec: SdJwtPredicateSpec,
+    sextets: Vec<Target>,
+    /// f_i = 1 for i < disclosure length.
+    in_message: Vec<BoolTarget>,
+    /// One-hot: which SHA-256 block holds the padding and length.
+    last_block: Vec<BoolTarget>,
+    /// One-hot: decoded byte where the value's digits start.
+    value_start: Vec<BoolTarget>,
+    spaced: BoolTarget,
+    /// g_w = 1 for w < number of digits.
+    in_value: Vec<BoolTarget>,
+    set: Option<(AllowedSet, SetMembershipTargets)>,
+}
+
+impl SdJwtPredicateCircuit {
+    pub(crate) fn build(spec: &SdJwtPredicateSpec) -> ZkpResult<Self> {
+        let config = CircuitConfig::standard_recursion_config();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let zero = builder.zero();
+        let one = builder.one();
+
+        // 1. Encoded disclosure: 6-bit values, prefix flags (length = sum)
+        let table: Vec<Target> = BASE64URL.iter().map(|&c| builder.constant(F::from_canonical_u8(c))).collect();
+        let sextets: Vec<Target> = (0..MAX_DISCLOSURE_CHARS).map(|_| builder.add_virtual_target()).collect();
+        let in_message: Vec<BoolTarget> = (0..MAX_DISCLOSURE_CHARS).map(|_| builder.add_virtual_bool_target_safe()).collect();
+        let mut length = zero;
+        let mut sextet_bits = Vec::with_capacity(MAX_DISCLOSURE_CHARS);
+        let mut chars = Vec::with_capacity(MAX_DISCLOSURE_CHARS);
+        for i in 0..MAX_DISCLOSURE_CHARS {
+            if i > 0 {
+                // f_i = 1 => f_{i-1} = 1
+                let both = builder.and(in_message[i], in_message[i - 1]);
+                builder.connect(both.target, in_message[i].target);
+            }
+            length = builder.add(length, in_message[i].target);
+            // Message ke bahar value 0, taaki decode me kachra na aaye
+            let kept = builder.mul(in_message[i].target, sextets[i]);
+            builder.connect(kept, sextets[i]);
+            sextet_bits.push(builder.split_le(sextets[i], 6));
+            chars.push(builder.random_access(sextets[i], table.clone()));
+        }
+
+        // 2. SHA-256 padding: 0x80 right after the message, bit length at the end of block k
+        let last_block: Vec<BoolTarget> = (0..MAX_BLOCKS).map(|_| builder.add_virtual_bool_target_safe()).collect();
+        let (mut count, mut k) = (zero, zero);
+        for (j, bit) in last_block.iter().enumerate() {
+            count = builder.add(count, bit.target);
+            k = builder.mul_const_add(F::from_canonical_usize(j), bit.target, k);
+        }
+        builder.connect(count, one);
+        // 64k + 55 - length in [0, 64): padding fits block k but not block k - 1
+        let room = builder.mul_const(F::from_canonical_usize(64), k);
+        let room = builder.add_const(room, F::from_canonical_usize(55));
+        let room = builder.sub(room, length);
+        builder.range_check(room, 6);
+        let bit_length = builder.mul_const(F::from_canonical_usize(8), length);
+        let bit_length = builder.split_le(bit_length, 16);
+        let length_hi = builder.le_sum(bit_length[8..].iter());
+        let length_lo = builder.le_sum(bit_length[..8].iter());
+        let pad = builder.constant(F::from_canonical_u8(0x80));
+
+        let mut message = Vec::with_capacity(MAX_BLOCKS * 64);
+        for p in 0..MAX_BLOCKS * 64 {
+            let flag = |i: usize| in_message.get(i).map_or(zero, |f| f.target);
+            let here = flag(p);
+            let before = if p == 0 { one } else { flag(p - 1) };
+            // 1 sirf p == length par
+            let end = builder.sub(before, here);
+            let mut byte = builder.mul(end, pad);
+            if let Some(&c) = chars.get(p) {
+                byte = builder.mul_add(here, c, byte);
+            }
+            let block = last_block[p / 64].target;
+            match p % 64 {
+                62 => byte = builder.mul_add(block, length_hi, byte),
+                63 => byte = builder.mul_add(block, length_lo, byte),
+                _ => {}
+            }
+            message.push(builder.split_le(byte, 8));
+        }
+
+        // 3. Compress every block, keep the state after block k
+        let mut state = sha256::iv(&mut builder);
+        let mut digest = [zero; 8];
+        for (j, bit) in last_block.iter().enumerate() {
+            let block: [U32Target; 16] = std::array::from_fn(|w| {
+                let at = j * 64 + w * 4;
+                u32_from_be_bytes([&message[at], &message[at + 1], &message[at + 2], &message[at + 3]])
+            });
+            state = sha256_compress(&mut builder, &state, &block);
+            for (word, out) in state.iter().zip(digest.iter_mut()) {
+                let value = u32_value(&mut builder, word);
+                *out = builder.mul_add(bit.target, value, *out);
+            }
+        }
+        builder.register_public_inputs(&digest);
+
+        // 4. base64url decode: har value ke 6 bits MSB first, phir 8-bit bytes
+        let stream: Vec<BoolTarget> = sextet_bits.iter().flat_map(|bits| bits.iter().rev().copied()).collect();
+        let decoded: Vec<Target> = stream.chunks_exact(8).map(|byte| builder.le_sum(byte.iter().rev())).collect();
+
+        // 5. `","<claim>",` (ya spaced) ke turant baad value
+        let value_start: Vec<BoolTarget> = (0..MAX_DECODED).map(|_| builder.add_virtual_bool_target_safe()).collect();
+        let spaced = builder.add_virtual_bool_target_safe();
+        let mut count = zero;
+        for bit in &value_start {
+            count = builder.add(count, bit.target);
+        }
+        builder.connect(count, one);
+        let compact = builder.not(spaced);
+        let prefixes = value_prefixes(&spec.claim);
+        for (prefix, enabled) in prefixes.iter().zip([compact, spaced]) {
+            for (w, &expected) in prefix.iter().enumerate() {
+                let actual = select_at(&mut builder, &value_start, &decoded, w as isize - prefix.len() as isize);
+                let diff = builder.add_const(actual, -F::from_canonical_u8(expected));
+                let gated = builder.mul(enabled.target, diff);
+                builder.assert_zero(gated);
+            }
+        }
+
+        // 5b. Anchor: disclosure `["<salt>` se shuru ho aur salt ke baad ka pehla `"`
+        // hi prefix ho. Warna `["s","tags",["x","age",99]]` jaisi kisi aur claim ki
+        // value ke andar ka `","age",` bhi chal jaata.
+        for (at, &expected) in b"[\"".iter().enumerate() {
+            let diff = builder.add_const(decoded[at], -F::from_canonical_u8(expected));
+            builder.assert_zero(diff);
+        }
+        let quote = builder.constant(F::from_canonical_u8(b'"'));
+        let mut reached_prefix = zero;
+        for (m, &byte) in decoded.iter().enumerate() {
+            // prefix ka pehla byte (salt ka closing quote) m par?
+            let mut prefix_here = zero;
+            for (prefix, enabled) in prefixes.iter().zip([compact, spaced]) {
+                if let Some(bit) = value_start.get(m + prefix.len()) {
+                    let both = builder.and(*bit, enabled);
+                    prefix_here = builder.add(prefix_here, both.target);
+                }
+            }
+            reached_prefix = builder.add(reached_prefix, prefix_here);
+            if m < 2 {
+                continue;
+            }
+            let in_salt = builder.sub(one, reached_prefix);
+            let is_quote = builder.is_equal(byte, quote);
+            let gated = builder.mul(in_salt, is_quote.target);
+            builder.assert_zero(gated);
+        }
+
+        // 6. 1..=MAX_DIGITS decimal digits, phir `]` (array ka end, to value poori hai)
+        let in_value: Vec<BoolTarget> = (0..MAX_DIGITS).map(|_| builder.add_virtual_bool_target_safe()).collect();
+        builder.connect(in_value[0].target, one);
+        let close = builder.constant(F::from_canonical_u8(b']'));
+        let mut value = zero;
+        for w in 0..=MAX_DIGITS {
+            let byte = select_at(&mut builder, &value_start, &decoded, w as isize);
+            let here = in_value.get(w).map_or(zero, |g| g.target);
+            let before = if w == 0 { one } else { in_value[w - 1].target };
+            if w > 0 && w < MAX_DIGITS {
+                let both = builder.and(in_value[w], in_value[w - 1]);
+                builder.connect(both.target, in_value[w].target);
+            }
+            let end = builder.sub(before, here);
+            let diff = builder.sub(byte, close);
+            let gated = builder.mul(end, diff);
+            builder.assert_zero(gated);
+
+            if let Some(&g) = in_value.get(w) {
+                let digit = builder.add_const(byte, -F::from_canonical_u8(b'0'));
+                let digit = builder.mul(here, digit);
+                assert_lte_const(&mut builder, digit, 9, 4);
+                let next = builder.mul_const_add(F::from_canonical_u64(10), value, digit);
+                value = builder.select(g, next, value);
+            }
+        }
+
+        let set = assert_predicate(&mut builder, value, &spec.predicate)?;
+
+        Ok(Self { data: builder.build::<C>(), spec: spec.clone(), sextets, in_message, last_block, value_start, spaced, in_value, set })
+    }
+
+    pub(crate) fn prove(&self, disclosure: &Disclosure) -> ZkpResult<ProofWithPublicInputs<F, C, D>> {
+        let claim = &self.spec.claim;
+        if disclosure.name.as_deref() != Some(claim.as_str()) {
+            return Err(ZkpError::InvalidInput(format!("disclosure is not for claim {}", claim)));
+        }
+        let value = disclosure
+            .value
+            .as_u64()
+            .ok_or_else(|| ZkpError::InvalidInput(format!("claim {} must be a non-negative integer", claim)))?;
+        if !self.spec.predicate.holds(value) {
+            return Err(ZkpError::InvalidInput(format!("claim does not satisfy {} {:?}", claim, self.spec.predicate)));
+        }
+
+        let encoded = disclosure.encoded.as_bytes();
+        if encoded.len() > MAX_DISCLOSURE_CHARS {
+            return Err(ZkpError::InvalidInput(format!("disclosure longer than {} chars", MAX_DISCLOSURE_CHARS)));
+        }
+        let sextets = encoded
+            .iter()
+            .map(|c| BASE64URL.iter().position(|b| b == c).map(|v| v as u64))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| ZkpError::InvalidEncoding("disclosure is not unpadded base64url".into()))?;
+
+        // Value kahan hai: circuit wali hi byte positions (prefix salt ke turant baad)
+        let decoded = b64url("disclosure", &disclosure.encoded)?;
+        let layout = || ZkpError::InvalidInput("disclosure JSON layout is not supported".into());
+        if !decoded.starts_with(b"[\"") {
+            return Err(layout());
+        }
+        let salt_end = decoded[2..].iter().position(|&b| b == b'"').ok_or_else(layout)? + 2;
+        let (start, spaced) = value_prefixes(claim)
+            .iter()
+            .zip([false, true])
+            .find_map(|(prefix, spaced)| decoded[salt_end..].starts_with(prefix).then(|| (salt_end + prefix.len(), spaced)))
+            .ok_or_else(layout)?;
+        let digits = decoded[start..].iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 || digits > MAX_DIGITS || decoded.get(start + digits) != Some(&b']') {
+            return Err(ZkpError::InvalidInput(format!("claim {} must be written as 1..={} plain digits", claim, MAX_DIGITS)));
+        }
+
+        let pw = self.witness(&sextets, start, spaced, digits, value)?;
+        self.data
+            .prove(pw)
+            .map_err(|e| ZkpError::ProvingFailed(e.to_string()))
+    }
+
+    /// Raw witness; `prove` picks `start`/`spaced`, tests can force others.
+    fn witness(&self, sextets: &[u64], start: usize, spaced: bool, digits: usize, value: u64) -> ZkpResult<PartialWitness<F>> {
+        let mut pw = PartialWitness::new();
+        for (i, (&sextet, &flag)) in self.sextets.iter().zip(&self.in_message).enumerate() {
+            pw.set_target(sextet, F::from_canonical_u64(sextets.get(i).copied().unwrap_or(0)));
+            pw.set_bool_target(flag, i < sextets.len());
+        }
+        let last = (sextets.len() + 8) / 64;
+        for (j, bit) in self.last_block.iter().enumerate() {
+            pw.set_bool_target(*bit, j == last);
+        }
+        for (m, bit) in self.value_start.iter().enumerate() {
+            pw.set_bool_target(*bit, m == start);
+        }
+        pw.set_bool_target(self.spaced, spaced);
+        for (w, bit) in self.in_value.iter().enumerate() {
+            pw.set_bool_target(*bit, w < digits);
+        }
+        if let Some((set, targets)) = &self.set {
+            targets.set_witness(&mut pw, set, value)?;
+        }
+        Ok(pw)
+    }
+}
+
+/// The public digest words back to the `_sd` string form.
+fn digest_from_inputs(inputs: &[F]) -> ZkpResult<String> {
+    let words = inputs.get(..8).ok_or_else(|| ZkpError::CorruptProof("SD-JWT public inputs missing".into()))?;
+    let mut bytes = Vec::with_capacity(32);
+    for word in words {
+        let word = u32::try_from(word.to_canonical_u64()).map_err(|_| ZkpError::CorruptProof("digest word is not 32-bit".into()))?;
+        bytes.extend(word.to_be_bytes());
+    }
+    Ok(general_purpose::URL_SAFE_NO_PAD.encode(bytes))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📤 PROVE / VERIFY
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// JSON: `{"sd_jwt", "clause", "circuit_digest", "proof": base64}`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SdJwtPresentation {
+    /// The SD-JWT without the hidden claim's disclosure.
+    pub sd_jwt: String,
+    /// Label, e.g. `age >= 18`; the verifier checks its own clause.
+    pub clause: String,
+    pub circuit_digest: String,
+    pub proof: String,
+}
+
+impl SdJwtPresentation {
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+}
+
+/// Holder side: hides the claim named in `clause` and proves `clause` over it.
+pub fn prove_sd_jwt_predicate(sd_jwt: &SdJwt, clause: &str, circuits: &CircuitCache) -> ZkpResult<SdJwtPresentation> {
+    let spec = SdJwtPredicateSpec::parse(clause)?;
+    let disclosure = sd_jwt
+        .disclosure(&spec.claim)
+        .ok_or_else(|| ZkpError::InvalidInput(format!("SD-JWT has no disclosure for {}", spec.claim)))?;
+    // Verifier sirf top-level `_sd` dekhta hai; nested claims yahan nahi chalenge
+    let top_level = sd_jwt.unverified_payload()?.get("_sd").and_then(Value::as_array).cloned().unwrap_or_default();
+    if !top_level.iter().any(|d| d.as_str() == Some(disclosure.digest().as_str())) {
+        return Err(ZkpError::InvalidInput(format!("{} is not a top-level selectively disclosable claim", spec.claim)));
+    }
+
+    let circuit = circuits.sd_jwt_predicate(&spec)?;
+    let proof = circuit.prove(disclosure)?;
+    let bytes = bincode::serialize(&proof).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+    Ok(SdJwtPresentation {
+        sd_jwt: sd_jwt.without(&spec.claim).to_compact(),
+        clause: clause.trim().to_string(),
+        circuit_digest: digest_hex(&circuit.data),
+        proof: general_purpose::STANDARD.encode(bytes),
+    })
+}
+
+/// Verifier side: the SD-JWT verifies natively, the proof's digest is one of
+/// its signed top-level `_sd` entries, and the hidden value satisfies `clause`.
+pub fn verify_sd_jwt_predicate(
+    presentation: &SdJwtPresentation,
+    clause: &str,
+    issuers: &SdJwtIssuers,
+    circuits: &CircuitCache,
+    now: u64,
+) -> ZkpResult<VerifiedSdJwt> {
+    let spec = SdJwtPredicateSpec::parse(clause)?;
+    let mut verified = verify_sd_jwt(&presentation.sd_jwt.parse()?, issuers, now)?;
+
+    let bytes = general_purpose::STANDARD.decode(presentation.proof.trim())?;
+    let proof: ProofWithPublicInputs<F, C, D> =
+        bincode::deserialize(&bytes).map_err(|e| ZkpError::CorruptProof(e.to_string()))?;
+    let digest = digest_from_inputs(&proof.public_inputs)?;
+    if !verified.digests.contains(&digest) {
+        return Err(ZkpError::ProofRejected("proven disclosure is not part of this SD-JWT".into()));
+    }
+
+    let circuit = circuits.sd_jwt_predicate(&spec)?;
+    if presentation.circuit_digest != digest_hex(&circuit.data) {
+        return Err(ZkpError::ProofRejected("proof was made for a different clause".into()));
+    }
+    circuit
+        .data
+        .verify(proof)
+        .map_err(|e| ZkpError::ProofRejected(format!("{:?}", e)))?;
+
+    verified.proven.push(clause.trim().to_string());
+    Ok(verified)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::jstring;
+    use log::info;
+
+    use crate::engine::CircuitCache;
+    use crate::error::ZkpError;
+    use crate::issuer::now_unix;
+    use crate::jni_bridge::{guard, new_string, read_string};
+
+    use super::{SdJwt, SdJwtIssuers, SdJwtPresentation};
+
+    /// Compact SD-JWT + issuer JWK list in, `VerifiedSdJwt` JSON out.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_SdJwt_verify(
+        mut env: JNIEnv,
+        _class: JClass,
+        sd_jwt: JString,
+        issuers_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            // ⚠️ Claims personal data hain: log mat karna
+            let sd_jwt: SdJwt = read_string(env, &sd_jwt, "sd_jwt")?.parse()?;
+            let issuers = SdJwtIssuers::from_json(&read_string(env, &issuers_json, "issuers")?)?;
+
+            let verified = super::verify_sd_jwt(&sd_jwt, &issuers, now_unix())?;
+            info!("✅ SD-JWT verified ({} claims)", verified.claims.len());
+            let json = serde_json::to_string(&verified).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+            new_string(env, json)
+        })
+    }
+
+    /// Holder: `clause` like `age >= 18`; returns `SdJwtPresentation` JSON.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_SdJwt_provePredicate(
+        mut env: JNIEnv,
+        _class: JClass,
+        sd_jwt: JString,
+        clause: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let sd_jwt: SdJwt = read_string(env, &sd_jwt, "sd_jwt")?.parse()?;
+            let clause = read_string(env, &clause, "clause")?;
+
+            let presentation = super::prove_sd_jwt_predicate(&sd_jwt, &clause, CircuitCache::shared())?;
+            info!("✅ SD-JWT predicate proven ({})", presentation.clause);
+            new_string(env, presentation.to_json()?)
+        })
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_SdJwt_verifyPredicate(
+        mut env: JNIEnv,
+        _class: JClass,
+        presentation_json: JString,
+        clause: JString,
+        issuers_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let presentation = SdJwtPresentation::from_json(&read_string(env, &presentation_json, "presentation")?)?;
+            let clause = read_string(env, &clause, "clause")?;
+            let issuers = SdJwtIssuers::from_json(&read_string(env, &issuers_json, "issuers")?)?;
+
+            let verified = super::verify_sd_jwt_predicate(&presentation, &clause, &issuers, CircuitCache::shared(), now_unix())?;
+            info!("✅ SD-JWT predicate verified");
+            let json = serde_json::to_string(&verified).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+            new_string(env, json)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_util::proves;
+
+    fn disclosure(json: &str) -> Disclosure {
+        Disclosure::parse(&general_purpose::URL_SAFE_NO_PAD.encode(json)).unwrap()
+    }
+
+    const ISS: &str = "https://issuer.example";
+
+    /// EdDSA-signed SD-JWT over `payload` (+ `_sd` for `disclosures`) and the issuer set that trusts `key`.
+    fn issue(key: &ed25519_dalek::SigningKey, mut payload: Value, disclosures: &[&str]) -> (SdJwt, SdJwtIssuers) {
+        use ed25519_dalek::Signer;
+
+        let disclosures: Vec<Disclosure> = disclosures.iter().map(|json| disclosure(json)).collect();
+        payload["_sd"] = json!(disclosures.iter().map(Disclosure::digest).collect::<Vec<_>>());
+        payload["_sd_alg"] = json!(SD_ALG);
+        let b64 = |value: &Value| general_purpose::URL_SAFE_NO_PAD.encode(value.to_string());
+        let signing_input = format!("{}.{}", b64(&json!({ "alg": "EdDSA", "typ": "dc+sd-jwt" })), b64(&payload));
+        let signature = general_purpose::URL_SAFE_NO_PAD.encode(key.sign(signing_input.as_bytes()).to_bytes());
+
+        let jwk = Jwk {
+            kty: "OKP".into(),
+            crv: "Ed25519".into(),
+            x: general_purpose::URL_SAFE_NO_PAD.encode(key.verifying_key().to_bytes()),
+            y: None,
+            kid: None,
+        };
+        let issuers = SdJwtIssuers { version: 1, issuers: vec![SdJwtIssuer { iss: ISS.into(), jwk }] };
+        let sd_jwt = SdJwt { jwt: format!("{}.{}", signing_input, signature), disclosures, key_binding: None };
+        (sd_jwt, issuers)
+    }
+
+    fn key(seed: u8) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[seed; 32])
+    }
+
+    #[test]
+    fn signed_sd_jwt_verifies_and_a_bad_signature_does_not() {
+        let (sd_jwt, issuers) = issue(&key(1), json!({ "iss": ISS, "vct": "kyc" }), &["[\"salt\",\"age\",21]"]);
+        let verified = verify_sd_jwt(&sd_jwt.to_compact().parse().unwrap(), &issuers, 1_000).unwrap();
+        assert_eq!(verified.claims["age"], json!(21));
+        assert_eq!(verified.vct.as_deref(), Some("kyc"));
+        assert!(!verified.claims.contains_key("_sd") && !verified.claims.contains_key("_sd_alg"));
+
+        let (_, other) = issue(&key(2), json!({ "iss": ISS }), &[]);
+        assert!(matches!(verify_sd_jwt(&sd_jwt, &other, 1_000), Err(ZkpError::SignatureInvalid(_))));
+
+        let mut forged = sd_jwt.clone();
+        let parts: Vec<&str> = sd_jwt.jwt.split('.').collect();
+        let payload = general_purpose::URL_SAFE_NO_PAD.encode(json!({ "iss": ISS, "vct": "admin" }).to_string());
+        forged.jwt = format!("{}.{}.{}", parts[0], payload, parts[2]);
+        assert!(matches!(verify_sd_jwt(&forged, &issuers, 1_000), Err(ZkpError::SignatureInvalid(_))));
+    }
+
+    #[test]
+    fn disclosure_outside_sd_is_rejected() {
+        let (mut sd_jwt, issuers) = issue(&key(1), json!({ "iss": ISS }), &["[\"salt\",\"age\",21]"]);
+        sd_jwt.disclosures.push(disclosure("[\"salt\",\"admin\",true]"));
+        assert!(matches!(verify_sd_jwt(&sd_jwt, &issuers, 1_000), Err(ZkpError::IntegrityCheckFailed(_))));
+    }
+
+    #[test]
+    fn validity_window_is_enforced() {
+        let at = |payload: Value, now: u64| {
+            let (sd_jwt, issuers) = issue(&key(1), payload, &[]);
+            verify_sd_jwt(&sd_jwt, &issuers, now)
+        };
+        let expired = |result: ZkpResult<VerifiedSdJwt>| matches!(result, Err(ZkpError::ProofRejected(_)));
+
+        assert!(at(json!({ "iss": ISS, "exp": 1_000 }), 999).is_ok());
+        assert!(expired(at(json!({ "iss": ISS, "exp": 1_000 }), 1_000)));
+        assert!(expired(at(json!({ "iss": ISS, "nbf": 500 }), 499)));
+        assert!(at(json!({ "iss": ISS, "nbf": 500 }), 500).is_ok());
+
+        // Fractional NumericDate pehle `as_u64` me gum ho jaata tha: token kabhi expire nahi hota
+        assert!(at(json!({ "iss": ISS, "exp": 1_000.5 }), 1_000).is_ok());
+        assert!(expired(at(json!({ "iss": ISS, "exp": 1_000.5 }), 1_001)));
+        assert!(matches!(at(json!({ "iss": ISS, "exp": "1000" }), 0), Err(ZkpError::InvalidEncoding(_))));
+    }
+
+    #[test]
+    fn checked_claims_cannot_be_selectively_disclosed() {
+        for claim in SIGNED_ONLY_CLAIMS {
+            let json = format!("[\"salt\",\"{}\",1]", claim);
+            let (sd_jwt, issuers) = issue(&key(1), json!({ "iss": ISS }), &[&json]);
+            assert!(matches!(verify_sd_jwt(&sd_jwt, &issuers, 1_000), Err(ZkpError::IntegrityCheckFailed(_))), "{}", claim);
+        }
+    }
+
+    fn sextets(encoded: &str) -> Vec<u64> {
+        encoded.bytes().map(|c| BASE64URL.iter().position(|&b| b == c).unwrap() as u64).collect()
+    }
+
+    #[test]
+    fn three_block_disclosure_proves_its_sha256_digest() {
+        // 2 + 124 + 8 + 3 = 137 decoded bytes = 183 chars, teeno blocks
+        let json = format!("[\"{}\",\"age\",21]", "s".repeat(124));
+        let disclosure = disclosure(&json);
+        assert_eq!(disclosure.encoded.len(), MAX_DISCLOSURE_CHARS);
+
+        let circuit = SdJwtPredicateCircuit::build(&SdJwtPredicateSpec::parse("age >= 18").unwrap()).unwrap();
+        let proof = circuit.prove(&disclosure).unwrap();
+        assert_eq!(digest_from_inputs(&proof.public_inputs).unwrap(), disclosure.digest());
+        circuit.data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn both_json_spellings_prove() {
+        let circuit = SdJwtPredicateCircuit::build(&SdJwtPredicateSpec::parse("age >= 18").unwrap()).unwrap();
+        for json in ["[\"salt\",\"age\",21]", "[\"salt\", \"age\", 21]"] {
+            let disclosure = disclosure(json);
+            let proof = circuit.prove(&disclosure).unwrap();
+            assert_eq!(digest_from_inputs(&proof.public_inputs).unwrap(), disclosure.digest());
+        }
+        assert!(circuit.prove(&disclosure("[\"salt\",\"age\",17]")).is_err());
+    }
+
+    #[test]
+    fn value_nested_in_another_claim_is_rejected() {
+        let circuit = SdJwtPredicateCircuit::build(&SdJwtPredicateSpec::parse("age >= 18").unwrap()).unwrap();
+        let disclosure = disclosure("[\"salt\",\"tags\",[\"x\",\"age\",99]]");
+        assert!(circuit.prove(&disclosure).is_err());
+
+        // Native check ko bypass karke witness seedha `","age",` ke baad rakho
+        let decoded = b64url("disclosure", &disclosure.encoded).unwrap();
+        let prefix = &value_prefixes("age")[0];
+        let start = decoded.windows(prefix.len()).position(|w| w == prefix.as_slice()).unwrap() + prefix.len();
+        let pw = circuit.witness(&sextets(&disclosure.encoded), start, false, 2, 99).unwrap();
+        assert!(!proves(&circuit.data, pw));
+
+        // Wahi witness, sahi anchored disclosure par, chalta hai
+        let honest = self::disclosure("[\"salt\",\"age\",99]");
+        let decoded = b64url("disclosure", &honest.encoded).unwrap();
+        assert_eq!(&decoded[6..6 + prefix.len()], prefix.as_slice());
+        let pw = circuit.witness(&sextets(&honest.encoded), 6 + prefix.len(), false, 2, 99).unwrap();
+        assert!(proves(&circuit.data, pw));
+    }
+}
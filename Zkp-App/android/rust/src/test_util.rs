@@ -0,0 +1,22 @@
+// 🧪 Shared helpers for the soundness tests. plonky2 witness generation kabhi
+// Err deta hai, kabhi panic karta hai (conflicting copy constraints); test ke
+// liye dono ka matlab ek hi hai: proof nahi bana.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use plonky2::iop::witness::PartialWitness;
+use plonky2::plonk::circuit_data::CircuitData;
+use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+/// True if `pw` yields a proof that also verifies.
+pub(crate) fn proves(data: &CircuitData<F, C, D>, pw: PartialWitness<F>) -> bool {
+    panic::catch_unwind(AssertUnwindSafe(|| match data.prove(pw) {
+        Ok(proof) => data.verify(proof).is_ok(),
+        Err(_) => false,
+    }))
+    .unwrap_or(false)
+}
@@ -0,0 +1,424 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧳 OFFLINE VERIFIER TRUST BUNDLE
+// Verifier phone offline hai, to saara trust ek signed file me aata hai:
+//   trusted issuer keys + CSCA certs + revocation root + accepted circuit digests
+// CSCA list hi mdoc ka IACA trust list bhi hai (mdoc.rs, `ZkpEngine::verify_mdoc`):
+// IACA = mDL ka CSCA, dono document-signer certs ke roots hain. Passport proof
+// me SOD / DS cert verifier tak aata hi nahi (DG1 check prover phone par), to
+// verifier side pe CSCA sirf mdoc chain me lagte hain.
+// Policy verify me bundle policy ko narrow karta hai, override nahi hota.
+// File = CBOR `{"bundle": bstr(CBOR TrustBundle), "publisher": bstr(32), "signature": bstr(64)}`,
+// Ed25519 signature over `SIGNING_CONTEXT || bundle`. Publisher keys app me
+// pinned hain (`EngineConfig.trust_bundle_publishers`).
+// Rules: version strictly badhna chahiye (rollback nahi), `expires_at` ke baad
+// bundle kisi verify me use nahi hota. Transfer: base64 -> same `i/n|data`
+// QR frames (qr_stream.rs). Reports me `trust_bundle_version` dikhta hai.
+// CLI: `zkp-cli bundle keygen | sign | inspect`.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use base64::{Engine as _, engine::general_purpose};
+use ciborium::value::Value;
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ZkpError, ZkpResult};
+use crate::issuer::{now_unix, TrustedIssuers};
+use crate::qr_stream;
+use crate::revocation::RevocationRoot;
+
+/// Bumped only for incompatible layout changes.
+pub const BUNDLE_FORMAT: u64 = 1;
+
+/// Domain separation: a publisher signature over anything else never verifies as a bundle.
+const SIGNING_CONTEXT: &[u8] = b"zkp-app/trust-bundle/v1";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📦 BUNDLE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Document-signing root: passport CSCA or mdoc IACA. DS certs must chain to one.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CscaEntry {
+    pub name: String,
+    #[serde(default)]
+    pub country: Option<String>,
+    /// PEM, or base64 of the DER.
+    pub certificate: String,
+}
+
+/// Everything an offline verifier trusts. Signed as a whole.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TrustBundle {
+    pub format: u64,
+    /// Strictly increasing; a verifier never goes back to a lower version.
+    pub version: u64,
+    /// Unix seconds.
+    pub issued_at: u64,
+    pub expires_at: u64,
+    #[serde(default)]
+    pub trusted_issuers: Option<TrustedIssuers>,
+    #[serde(default)]
+    pub csca: Vec<CscaEntry>,
+    #[serde(default)]
+    pub revocation_root: Option<RevocationRoot>,
+    /// Accepted circuit digests (hex). Empty = keep the engine config's list.
+    #[serde(default)]
+    pub circuit_digests: Vec<String>,
+}
+
+impl TrustBundle {
+    pub fn new(version: u64, issued_at: u64, expires_at: u64) -> Self {
+        Self {
+            format: BUNDLE_FORMAT,
+            version,
+            issued_at,
+            expires_at,
+            trusted_issuers: None,
+            csca: Vec::new(),
+            revocation_root: None,
+            circuit_digests: Vec::new(),
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+
+    fn check(&self) -> ZkpResult<()> {
+        if self.format != BUNDLE_FORMAT {
+            return Err(ZkpError::InvalidInput(format!("unsupported trust bundle format {}", self.format)));
+        }
+        if self.expires_at <= self.issued_at {
+            return Err(ZkpError::InvalidInput("trust bundle expires before it is issued".into()));
+        }
+        if let Some(issuers) = &self.trusted_issuers {
+            TrustedIssuers::new(issuers.version, issuers.issuers.clone())?;
+        }
+        if let Some(bad) = self.csca.iter().find(|c| c.certificate.trim().is_empty()) {
+            return Err(ZkpError::InvalidInput(format!("CSCA {} has no certificate", bad.name)));
+        }
+        if let Some(bad) = self.circuit_digests.iter().find(|d| hex::decode(d).is_err()) {
+            return Err(ZkpError::InvalidEncoding(format!("circuit digest {:?} is not hex", bad)));
+        }
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ✍️ PUBLISHER (backend side, holds the Ed25519 secret)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// JSON key file. Contains the SECRET key.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PublisherKeyFile {
+    pub publisher: String,
+    pub secret_key: String,
+    pub public_key: String,
+    pub created_at: u64,
+}
+
+pub struct Publisher {
+    name: String,
+    key: SigningKey,
+}
+
+impl Publisher {
+    pub fn generate(name: &str) -> ZkpResult<Self> {
+        if name.trim().is_empty() {
+            return Err(ZkpError::InvalidInput("publisher name must not be empty".into()));
+        }
+        let mut secret = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut secret);
+        Ok(Self { name: name.to_string(), key: SigningKey::from_bytes(&secret) })
+    }
+
+    pub fn from_key_file(json: &str) -> ZkpResult<Self> {
+        let file: PublisherKeyFile = serde_json::from_str(json)?;
+        let secret: [u8; 32] = hex::decode(&file.secret_key)?
+            .try_into()
+            .map_err(|_| ZkpError::InvalidInput("publisher secret_key must be 32 bytes".into()))?;
+        let publisher = Self { name: file.publisher, key: SigningKey::from_bytes(&secret) };
+        if publisher.public_key_hex() != file.public_key.to_lowercase() {
+            return Err(ZkpError::InvalidInput("key file public_key does not match its secret_key".into()));
+        }
+        Ok(publisher)
+    }
+
+    pub fn to_key_file(&self) -> PublisherKeyFile {
+        PublisherKeyFile {
+            publisher: self.name.clone(),
+            secret_key: hex::encode(self.key.to_bytes()),
+            public_key: self.public_key_hex(),
+            created_at: now_unix(),
+        }
+    }
+
+    /// What verifiers pin (`EngineConfig.trust_bundle_publishers`).
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.key.verifying_key().to_bytes())
+    }
+
+    /// Signed CBOR file bytes.
+    pub fn sign(&self, bundle: &TrustBundle) -> ZkpResult<Vec<u8>> {
+        bundle.check()?;
+        let mut payload = Vec::new();
+        ciborium::ser::into_writer(bundle, &mut payload).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+        let signature = self.key.sign(&[SIGNING_CONTEXT, &payload].concat());
+
+        let signed = Value::Map(vec![
+            (Value::Text("bundle".into()), Value::Bytes(payload)),
+            (Value::Text("publisher".into()), Value::Bytes(self.key.verifying_key().to_bytes().to_vec())),
+            (Value::Text("signature".into()), Value::Bytes(signature.to_bytes().to_vec())),
+        ]);
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(&signed, &mut bytes).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+        Ok(bytes)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔓 LOADING
+// ═══════════════════════════════════════════════════════════════════════════
+
+pub fn publisher_key_from_hex(hex_key: &str) -> ZkpResult<VerifyingKey> {
+    let bytes: [u8; 32] = hex::decode(hex_key.trim())?
+        .try_into()
+        .map_err(|_| ZkpError::InvalidInput("publisher key must be 32 bytes".into()))?;
+    VerifyingKey::from_bytes(&bytes).map_err(|e| ZkpError::InvalidInput(format!("publisher key: {}", e)))
+}
+
+fn field<'a>(map: &'a [(Value, Value)], key: &str) -> ZkpResult<&'a [u8]> {
+    map.iter()
+        .find(|(k, _)| k.as_text() == Some(key))
+        .and_then(|(_, v)| v.as_bytes())
+        .map(Vec::as_slice)
+        .ok_or_else(|| ZkpError::InvalidEncoding(format!("trust bundle: missing {}", key)))
+}
+
+/// Signature by one of `publishers` + well-formed content. Version and expiry
+/// are the `TrustStore`'s job.
+pub fn open(bytes: &[u8], publishers: &[VerifyingKey]) -> ZkpResult<TrustBundle> {
+    let signed: Value = ciborium::de::from_reader(bytes).map_err(|e| ZkpError::InvalidEncoding(format!("trust bundle: {}", e)))?;
+    let map = signed.as_map().ok_or_else(|| ZkpError::InvalidEncoding("trust bundle must be a CBOR map".into()))?;
+    let payload = field(map, "bundle")?;
+    let publisher = field(map, "publisher")?;
+    let signature = ed25519_dalek::Signature::from_slice(field(map, "signature")?)
+        .map_err(|e| ZkpError::SignatureInvalid(e.to_string()))?;
+
+    let key = publishers
+        .iter()
+        .find(|k| k.as_bytes().as_slice() == publisher)
+        .ok_or_else(|| ZkpError::SignatureInvalid("trust bundle publisher is not pinned".into()))?;
+    key.verify_strict(&[SIGNING_CONTEXT, payload].concat(), &signature)
+        .map_err(|e| ZkpError::SignatureInvalid(format!("trust bundle: {}", e)))?;
+
+    let bundle: TrustBundle =
+        ciborium::de::from_reader(payload).map_err(|e| ZkpError::InvalidEncoding(format!("trust bundle payload: {}", e)))?;
+    bundle.check()?;
+    Ok(bundle)
+}
+
+/// Text form of the signed file (base64), for JSON / intents / JNI.
+pub fn encode_text(signed: &[u8]) -> String {
+    general_purpose::STANDARD.encode(signed)
+}
+
+/// QR transfer: base64 of the signed file, split like proofs.
+pub fn to_qr_frames(signed: &[u8]) -> Vec<String> {
+    qr_stream::split(&encode_text(signed), qr_stream::QR_CHUNK_SIZE)
+}
+
+/// Signed file bytes from base64, a JSON array of QR frames, or one frame per line.
+pub fn decode_text(text: &str) -> ZkpResult<Vec<u8>> {
+    let trimmed = text.trim();
+    let first_line = trimmed.lines().next().unwrap_or("");
+    let base64 = if trimmed.starts_with('[') {
+        let frames: Vec<String> = serde_json::from_str(trimmed)?;
+        qr_stream::join(&frames)?
+    } else if qr_stream::parse_frame(first_line).is_ok() {
+        let frames: Vec<&str> = trimmed.lines().filter(|l| !l.trim().is_empty()).collect();
+        qr_stream::join(&frames)?
+    } else {
+        trimmed.to_string()
+    };
+    Ok(general_purpose::STANDARD.decode(base64.trim())?)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🗄️ TRUST STORE (verifier side)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Installed bundle + pinned publishers. Versions only move forward.
+#[derive(Debug, Clone, Default)]
+pub struct TrustStore {
+    publishers: Vec<VerifyingKey>,
+    /// Highest version ever accepted, persisted by the app across restarts.
+    min_version: u64,
+    current: Option<TrustBundle>,
+}
+
+impl TrustStore {
+    pub fn new(publisher_keys: &[String], min_version: u64) -> ZkpResult<Self> {
+        let publishers = publisher_keys.iter().map(|k| publisher_key_from_hex(k)).collect::<ZkpResult<_>>()?;
+        Ok(Self { publishers, min_version, current: None })
+    }
+
+    /// Installs a newer bundle. Re-installing the installed one (same QR scanned
+    /// twice, app restart) is a no-op; anything older is a rollback and rejected.
+    /// With nothing installed, `min_version` itself is accepted: after a restart
+    /// the app re-installs the bundle whose version it persisted.
+    pub fn install(&mut self, bytes: &[u8], now: u64) -> ZkpResult<&TrustBundle> {
+        if self.publishers.is_empty() {
+            return Err(ZkpError::InvalidInput("no trust bundle publisher keys configured".into()));
+        }
+        let bundle = open(bytes, &self.publishers)?;
+        if bundle.is_expired(now) {
+            return Err(ZkpError::ProofRejected(format!("trust bundle version {} has expired", bundle.version)));
+        }
+        let installed = self.current.as_ref().map_or(self.min_version, |c| c.version);
+        let same = self.current.as_ref() == Some(&bundle);
+        if !same && (bundle.version < installed || (bundle.version == installed && self.current.is_some())) {
+            return Err(ZkpError::ProofRejected(format!(
+                "trust bundle version {} is not newer than installed {}",
+                bundle.version, installed
+            )));
+        }
+        Ok(self.current.insert(bundle))
+    }
+
+    pub fn current(&self) -> Option<&TrustBundle> {
+        self.current.as_ref()
+    }
+
+    /// The installed bundle if it is still valid at `now`; `None` if none is installed.
+    pub fn active(&self, now: u64) -> ZkpResult<Option<&TrustBundle>> {
+        match &self.current {
+            Some(bundle) if bundle.is_expired(now) => {
+                Err(ZkpError::ProofRejected(format!("trust bundle version {} has expired", bundle.version)))
+            }
+            current => Ok(current.as_ref()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_000_000;
+
+    fn bundle(version: u64) -> TrustBundle {
+        let mut bundle = TrustBundle::new(version, NOW - 100, NOW + 100);
+        bundle.circuit_digests = vec!["abcd".into()];
+        bundle
+    }
+
+    fn store(publisher: &Publisher, min_version: u64) -> TrustStore {
+        TrustStore::new(&[publisher.public_key_hex()], min_version).unwrap()
+    }
+
+    fn rejected(result: ZkpResult<&TrustBundle>) -> bool {
+        matches!(result, Err(ZkpError::ProofRejected(_)))
+    }
+
+    #[test]
+    fn signed_bundle_round_trips() {
+        let publisher = Publisher::generate("ops").unwrap();
+        let mut original = bundle(3);
+        original.csca.push(CscaEntry { name: "csca-in".into(), country: Some("IN".into()), certificate: "MIIB".into() });
+        let signed = publisher.sign(&original).unwrap();
+
+        let key = publisher_key_from_hex(&publisher.public_key_hex()).unwrap();
+        assert_eq!(open(&signed, &[key]).unwrap(), original);
+        assert_eq!(decode_text(&encode_text(&signed)).unwrap(), signed);
+        let frames = to_qr_frames(&signed);
+        assert_eq!(decode_text(&serde_json::to_string(&frames).unwrap()).unwrap(), signed);
+        assert_eq!(decode_text(&frames.join("\n")).unwrap(), signed);
+    }
+
+    #[test]
+    fn unpinned_publisher_or_tampered_signature_is_rejected() {
+        let publisher = Publisher::generate("ops").unwrap();
+        let rogue = Publisher::generate("rogue").unwrap();
+        let mut store = store(&publisher, 0);
+
+        let forged = rogue.sign(&bundle(1)).unwrap();
+        assert!(matches!(store.install(&forged, NOW), Err(ZkpError::SignatureInvalid(_))));
+
+        // CBOR map ka last field signature hai
+        let mut tampered = publisher.sign(&bundle(1)).unwrap();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(matches!(store.install(&tampered, NOW), Err(ZkpError::SignatureInvalid(_))));
+        assert!(store.current().is_none());
+    }
+
+    #[test]
+    fn older_version_is_rejected_after_install() {
+        let publisher = Publisher::generate("ops").unwrap();
+        let mut store = store(&publisher, 0);
+
+        assert_eq!(store.install(&publisher.sign(&bundle(5)).unwrap(), NOW).unwrap().version, 5);
+        assert!(rejected(store.install(&publisher.sign(&bundle(4)).unwrap(), NOW)));
+        assert_eq!(store.current().unwrap().version, 5);
+        assert_eq!(store.install(&publisher.sign(&bundle(6)).unwrap(), NOW).unwrap().version, 6);
+    }
+
+    #[test]
+    fn reinstalling_the_same_bundle_is_a_no_op() {
+        let publisher = Publisher::generate("ops").unwrap();
+        let mut store = store(&publisher, 0);
+        let signed = publisher.sign(&bundle(5)).unwrap();
+
+        store.install(&signed, NOW).unwrap();
+        // Same QR scanned twice
+        assert_eq!(store.install(&signed, NOW).unwrap(), &bundle(5));
+    }
+
+    #[test]
+    fn different_bundle_at_the_same_version_is_rejected() {
+        let publisher = Publisher::generate("ops").unwrap();
+        let mut store = store(&publisher, 0);
+        store.install(&publisher.sign(&bundle(5)).unwrap(), NOW).unwrap();
+
+        let mut other = bundle(5);
+        other.circuit_digests = vec!["ef01".into()];
+        assert!(rejected(store.install(&publisher.sign(&other).unwrap(), NOW)));
+        assert_eq!(store.current().unwrap(), &bundle(5));
+    }
+
+    #[test]
+    fn expired_bundle_is_rejected() {
+        let publisher = Publisher::generate("ops").unwrap();
+        let mut store = store(&publisher, 0);
+
+        let stale = TrustBundle::new(1, NOW - 200, NOW - 1);
+        assert!(rejected(store.install(&publisher.sign(&stale).unwrap(), NOW)));
+        assert!(store.current().is_none());
+
+        store.install(&publisher.sign(&bundle(2)).unwrap(), NOW).unwrap();
+        assert!(store.active(NOW).unwrap().is_some());
+        assert!(matches!(store.active(NOW + 100), Err(ZkpError::ProofRejected(_))));
+    }
+
+    #[test]
+    fn persisted_min_version_is_the_floor_after_restart() {
+        let publisher = Publisher::generate("ops").unwrap();
+        let mut store = store(&publisher, 4);
+
+        assert!(rejected(store.install(&publisher.sign(&bundle(3)).unwrap(), NOW)));
+        // Restart: the bundle that set the persisted version goes back in
+        assert_eq!(store.install(&publisher.sign(&bundle(4)).unwrap(), NOW).unwrap().version, 4);
+    }
+
+    #[test]
+    fn no_publishers_means_nothing_installs() {
+        let publisher = Publisher::generate("ops").unwrap();
+        let mut store = TrustStore::new(&[], 0).unwrap();
+        assert!(matches!(store.install(&publisher.sign(&bundle(1)).unwrap(), NOW), Err(ZkpError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,573 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🪪 W3C VERIFIABLE PRESENTATIONS (partner interop)
+// Hamare proofs (envelope / credential / policy presentation) ko W3C VC Data
+// Model v2 ki Verifiable Presentation me lapet'te hain:
+//   verifiableCredential[0].credentialSubject = disclosed claims
+//   proof = { type: "Plonky2Proof2026", verificationMethod: urn:zkp-app:circuit:<digest>,
+//             circuitDigest, proofFormat, proofValue: base64(inner JSON) }
+// Prover `wrap` karta hai, verifier `report_presentation` se apna result
+// emit karta hai. Parse karte waqt VP ke labels inner proof se match hone
+// chahiye, warna VP jhooth bol sakta hai. Canonical output = JSON-LD ->
+// RDF (jsonld.rs) -> RDFC-1.0 / URDNA2015 N-Quads (rdfc.rs), wahi bytes jo
+// `rdfc` Data Integrity suites hash karte hain.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::BTreeMap;
+
+use base64::{Engine as _, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+use plonky2::plonk::proof::ProofWithPublicInputs;
+
+use crate::credential::{verify_presentation, CredentialPresentation};
+use crate::engine::{verify_envelope, CircuitCache};
+use crate::envelope::{ProofEnvelope, ProofKind};
+use crate::error::{ZkpError, ZkpResult};
+use crate::issuer::now_unix;
+use crate::jsonld;
+use crate::policy::{Policy, PolicyPresentation, PolicyReport};
+use crate::rdfc;
+use crate::schnorr::PublicKey;
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = <C as GenericConfig<D>>::F;
+
+pub const VC_CONTEXT: &str = jsonld::VC_V2_CONTEXT;
+/// Our proof suite name (`proof.type`).
+pub const PROOF_TYPE: &str = "Plonky2Proof2026";
+
+/// Issuer of self-asserted claims (holder statements backed only by the proof).
+pub const HOLDER_ISSUER: &str = "urn:zkp-app:holder";
+/// Issuer of verifier result presentations.
+pub const VERIFIER_ISSUER: &str = "urn:zkp-app:verifier";
+
+/// Inline JSON-LD context for the terms VC v2 does not define. VC v2 only
+/// defines the proof terms inside `DataIntegrityProof`, so ours map them to
+/// the same security vocabulary IRIs.
+fn suite_context() -> Value {
+    json!({
+        "@protected": true,
+        "Plonky2Proof2026": "urn:zkp-app:plonky2#Plonky2Proof2026",
+        "Plonky2CredentialSchema": "urn:zkp-app:plonky2#Plonky2CredentialSchema",
+        "circuitDigest": "urn:zkp-app:plonky2#circuitDigest",
+        "proofFormat": "urn:zkp-app:plonky2#proofFormat",
+        "created": { "@id": "http://purl.org/dc/terms/created", "@type": "http://www.w3.org/2001/XMLSchema#dateTime" },
+        "verificationMethod": { "@id": "https://w3id.org/security#verificationMethod", "@type": "@id" },
+        "proofPurpose": { "@id": "https://w3id.org/security#proofPurpose", "@type": "@vocab" },
+        "proofValue": "https://w3id.org/security#proofValue",
+        "authentication": "https://w3id.org/security#authenticationMethod",
+        "assertionMethod": "https://w3id.org/security#assertionMethod"
+    })
+}
+
+/// `verificationMethod` of a proof: the circuit it verifies against.
+pub fn circuit_urn(digest: &str) -> String {
+    format!("urn:zkp-app:circuit:{}", digest)
+}
+
+/// VC `issuer` for an ecgfp5 issuer key (schnorr.rs).
+pub fn issuer_urn(public_key_hex: &str) -> String {
+    format!("urn:zkp-app:ecgfp5:{}", public_key_hex)
+}
+
+/// `credentialSchema.id` for a credential schema name (percent-encoded, so any
+/// name stays an absolute IRI).
+pub fn schema_urn(schema: &str) -> String {
+    let mut urn = String::from("urn:zkp-app:schema:");
+    for byte in schema.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            urn.push(byte as char);
+        } else {
+            urn.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    urn
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📦 DATA MODEL
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CredentialSchema {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub schema_type: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<Value>,
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+    pub issuer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credential_schema: Option<CredentialSchema>,
+    pub credential_subject: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Plonky2Proof {
+    #[serde(rename = "type")]
+    pub proof_type: String,
+    /// RFC 3339, UTC.
+    pub created: String,
+    pub verification_method: String,
+    pub proof_purpose: String,
+    pub circuit_digest: String,
+    /// `"envelope"`, `"credential"` or `"policy"`: what `proof_value` decodes to.
+    pub proof_format: String,
+    /// Base64 of the embedded proof JSON.
+    pub proof_value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiablePresentation {
+    #[serde(rename = "@context")]
+    pub context: Vec<Value>,
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+    pub verifiable_credential: Vec<DerivedCredential>,
+    pub proof: Plonky2Proof,
+}
+
+/// What `proofValue` carries.
+#[derive(Debug, Clone)]
+pub enum EmbeddedProof {
+    Envelope(ProofEnvelope),
+    Credential(CredentialPresentation),
+    Policy(PolicyPresentation),
+}
+
+impl EmbeddedProof {
+    pub fn format(&self) -> &'static str {
+        match self {
+            EmbeddedProof::Envelope(_) => "envelope",
+            EmbeddedProof::Credential(_) => "credential",
+            EmbeddedProof::Policy(_) => "policy",
+        }
+    }
+
+    pub fn circuit_digest(&self) -> &str {
+        match self {
+            EmbeddedProof::Envelope(e) => &e.circuit_digest,
+            EmbeddedProof::Credential(c) => &c.circuit_digest,
+            EmbeddedProof::Policy(p) => &p.circuit_digest,
+        }
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        match self {
+            EmbeddedProof::Envelope(e) => e.to_json(),
+            EmbeddedProof::Credential(c) => c.to_json(),
+            EmbeddedProof::Policy(p) => p.to_json(),
+        }
+    }
+
+    fn decode(format: &str, json: &str) -> ZkpResult<Self> {
+        Ok(match format {
+            "envelope" => EmbeddedProof::Envelope(ProofEnvelope::from_json(json)?),
+            "credential" => EmbeddedProof::Credential(CredentialPresentation::from_json(json)?),
+            "policy" => EmbeddedProof::Policy(PolicyPresentation::from_json(json)?),
+            other => return Err(ZkpError::InvalidInput(format!("unknown proofFormat {:?}", other))),
+        })
+    }
+
+    /// Detects which of our presentation JSONs `json` is.
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        let value: Value = serde_json::from_str(json)?;
+        let has = |key: &str| value.get(key).is_some();
+        let format = if has("kind") {
+            "envelope"
+        } else if has("disclosed") {
+            "credential"
+        } else if has("policy") {
+            "policy"
+        } else {
+            return Err(ZkpError::InvalidInput("not an envelope, credential or policy presentation".into()));
+        };
+        Self::decode(format, json)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📤 WRAP (prover) / EMIT (verifier)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// What each embedded proof discloses, as (issuer, schema, subject).
+fn disclosed_claims(
+    embedded: &EmbeddedProof,
+    policy: Option<&Policy>,
+) -> ZkpResult<(String, Option<CredentialSchema>, BTreeMap<String, Value>)> {
+    let mut subject = BTreeMap::new();
+    Ok(match embedded {
+        EmbeddedProof::Envelope(envelope) => {
+            // Proof ke andar wale inputs authoritative hain, envelope ki copy nahi
+            subject.insert("proofKind".to_string(), json!(envelope.kind));
+            subject.insert("publicInputs".to_string(), json!(envelope.proof_public_inputs()?));
+            (HOLDER_ISSUER.to_string(), None, subject)
+        }
+        EmbeddedProof::Credential(presentation) => {
+            for (name, value) in &presentation.disclosed {
+                subject.insert(name.clone(), json!(value));
+            }
+            let schema = CredentialSchema { id: schema_urn(&presentation.schema), schema_type: "Plonky2CredentialSchema".into() };
+            (issuer_urn(&presentation.issuer), Some(schema), subject)
+        }
+        EmbeddedProof::Policy(presentation) => {
+            let policy = policy.ok_or_else(|| ZkpError::InvalidInput("wrapping a policy proof needs the policy".into()))?;
+            if policy.name != presentation.policy {
+                return Err(ZkpError::InvalidInput(format!("proof is for policy {:?}, not {:?}", presentation.policy, policy.name)));
+            }
+            let clauses: Vec<&str> = policy.clauses.iter().map(|(text, _)| text.as_str()).collect();
+            subject.insert("policy".to_string(), json!(policy.name));
+            subject.insert("satisfies".to_string(), json!(clauses));
+            (HOLDER_ISSUER.to_string(), None, subject)
+        }
+    })
+}
+
+impl VerifiablePresentation {
+    fn assemble(
+        embedded: &EmbeddedProof,
+        issuer: String,
+        credential_schema: Option<CredentialSchema>,
+        credential_subject: BTreeMap<String, Value>,
+        proof_purpose: &str,
+    ) -> ZkpResult<Self> {
+        let context = vec![json!(VC_CONTEXT), suite_context()];
+        Ok(Self {
+            context: context.clone(),
+            types: vec!["VerifiablePresentation".into()],
+            verifiable_credential: vec![DerivedCredential {
+                context,
+                types: vec!["VerifiableCredential".into()],
+                issuer,
+                credential_schema,
+                credential_subject,
+            }],
+            proof: Plonky2Proof {
+                proof_type: PROOF_TYPE.into(),
+                created: rfc3339(now_unix()),
+                verification_method: circuit_urn(embedded.circuit_digest()),
+                proof_purpose: proof_purpose.into(),
+                circuit_digest: embedded.circuit_digest().to_string(),
+                proof_format: embedded.format().into(),
+                proof_value: general_purpose::STANDARD.encode(embedded.to_json()?),
+            },
+        })
+    }
+
+    /// Holder side. `policy` is only needed for policy presentations.
+    pub fn wrap(embedded: &EmbeddedProof, policy: Option<&Policy>) -> ZkpResult<Self> {
+        let (issuer, schema, subject) = disclosed_claims(embedded, policy)?;
+        Self::assemble(embedded, issuer, schema, subject, "authentication")
+    }
+
+    pub fn from_json(json: &str) -> ZkpResult<Self> {
+        let vp: Self = serde_json::from_str(json)?;
+        vp.embedded()?;
+        Ok(vp)
+    }
+
+    pub fn to_json(&self) -> ZkpResult<String> {
+        serde_json::to_string(self).map_err(|e| ZkpError::Serialization(e.to_string()))
+    }
+
+    /// RDFC-1.0 canonical N-Quads of the VP: stable bytes for hashing / signing,
+    /// independent of key order and whitespace.
+    pub fn to_canonical_nquads(&self) -> ZkpResult<String> {
+        let document = serde_json::to_value(self).map_err(|e| ZkpError::Serialization(e.to_string()))?;
+        rdfc::canonicalize(&jsonld::to_rdf(&document)?)
+    }
+
+    /// Checks the VP shape and that its labels match the embedded proof.
+    /// Says nothing about the proof itself; see `verify_vp` / `verify_with_policy`.
+    pub fn embedded(&self) -> ZkpResult<EmbeddedProof> {
+        if self.context.first().and_then(Value::as_str) != Some(VC_CONTEXT) {
+            return Err(ZkpError::InvalidInput(format!("first @context must be {}", VC_CONTEXT)));
+        }
+        if !self.types.iter().any(|t| t == "VerifiablePresentation") {
+            return Err(ZkpError::InvalidInput("type must include VerifiablePresentation".into()));
+        }
+        if self.proof.proof_type != PROOF_TYPE {
+            return Err(ZkpError::InvalidInput(format!("unsupported proof type {:?}", self.proof.proof_type)));
+        }
+        let [credential] = self.verifiable_credential.as_slice() else {
+            return Err(ZkpError::InvalidInput("expected exactly one verifiableCredential".into()));
+        };
+
+        let json = general_purpose::STANDARD.decode(self.proof.proof_value.trim())?;
+        let json = String::from_utf8(json).map_err(|e| ZkpError::MalformedJson(e.to_string()))?;
+        let embedded = EmbeddedProof::decode(&self.proof.proof_format, &json)?;
+
+        let digest = embedded.circuit_digest();
+        if self.proof.circuit_digest != digest || self.proof.verification_method != circuit_urn(digest) {
+            return Err(ZkpError::ProofRejected("circuitDigest / verificationMethod do not match the embedded proof".into()));
+        }
+        if let EmbeddedProof::Envelope(envelope) = &embedded {
+            if envelope.public_inputs != envelope.proof_public_inputs()? {
+                return Err(ZkpError::ProofRejected("envelope public inputs do not match its proof".into()));
+            }
+        }
+        // Holder claims proof se aate hain; verifier report (`report_presentation`)
+        // apna result batata hai, proof ke claims nahi. Policy subject sirf label hai.
+        let derived = match &embedded {
+            EmbeddedProof::Policy(_) => false,
+            EmbeddedProof::Envelope(_) if credential.issuer == VERIFIER_ISSUER => {
+                if !credential.credential_subject.keys().all(|k| REPORT_CLAIMS.contains(&k.as_str())) {
+                    return Err(ZkpError::ProofRejected("verifier reports carry only policy, verified and clauses".into()));
+                }
+                false
+            }
+            _ => true,
+        };
+        if derived {
+            let (issuer, schema, subject) = disclosed_claims(&embedded, None)?;
+            if credential.issuer != issuer || credential.credential_schema != schema || credential.credential_subject != subject {
+                return Err(ZkpError::ProofRejected("disclosed claims do not match the embedded proof".into()));
+            }
+        }
+        Ok(embedded)
+    }
+}
+
+/// `credentialSubject` keys of a `report_presentation` VP.
+const REPORT_CLAIMS: [&str; 3] = ["policy", "verified", "clauses"];
+
+/// Verifier side: the result of `verify_with_policy` as a VP partners can
+/// re-check (it embeds the holder's proof). `payload` is what was verified.
+pub fn report_presentation(payload: &str, report: &PolicyReport, circuits: &CircuitCache) -> ZkpResult<VerifiablePresentation> {
+    let payload = policy_payload(payload)?;
+    let embedded = match report.circuit.as_str() {
+        "balance" => {
+            // Raw balance proof: envelope bana do taaki digest saath jaaye
+            let bytes = general_purpose::STANDARD.decode(payload.trim())?;
+            let proof: ProofWithPublicInputs<F, C, D> =
+                bincode::deserialize(&bytes).map_err(|e| ZkpError::CorruptProof(e.to_string()))?;
+            EmbeddedProof::Envelope(ProofEnvelope::seal(ProofKind::Balance, &circuits.balance()?.data, &proof)?)
+        }
+        _ => EmbeddedProof::Policy(PolicyPresentation::from_json(&payload)?),
+    };
+
+    let clauses: Vec<Value> = report.clauses.iter().map(|c| json!({ "clause": c.clause, "passed": c.passed })).collect();
+    let mut subject = BTreeMap::new();
+    subject.insert("policy".to_string(), json!(report.policy));
+    subject.insert("verified".to_string(), json!(report.verified));
+    subject.insert("clauses".to_string(), json!(clauses));
+    VerifiablePresentation::assemble(&embedded, VERIFIER_ISSUER.to_string(), None, subject, "assertionMethod")
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📥 VERIFY
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Verifies an envelope or credential VP (policy VPs go through `verify_with_policy`).
+pub fn verify_vp(vp: &VerifiablePresentation, trusted_issuers: &[PublicKey], circuits: &CircuitCache) -> ZkpResult<()> {
+    match vp.embedded()? {
+        EmbeddedProof::Envelope(envelope) => verify_envelope(&envelope, circuits),
+        EmbeddedProof::Credential(presentation) => verify_presentation(&presentation, trusted_issuers, circuits).map(|_| ()),
+        EmbeddedProof::Policy(_) => Err(ZkpError::InvalidInput("policy presentations are verified against a policy".into())),
+    }
+}
+
+/// `verify_with_policy` input: a VP becomes the proof it embeds (policy JSON,
+/// or the raw base64 of a balance envelope); anything else passes through.
+pub fn policy_payload(payload: &str) -> ZkpResult<String> {
+    let trimmed = payload.trim();
+    let is_vp = trimmed.starts_with('{')
+        && serde_json::from_str::<Value>(trimmed).map(|v| v.get("@context").is_some()).unwrap_or(false);
+    if !is_vp {
+        return Ok(trimmed.to_string());
+    }
+    match VerifiablePresentation::from_json(trimmed)?.embedded()? {
+        EmbeddedProof::Policy(presentation) => presentation.to_json(),
+        EmbeddedProof::Envelope(envelope) if envelope.kind == ProofKind::Balance => Ok(envelope.proof),
+        other => Err(ZkpError::InvalidInput(format!("a {} VP cannot be checked against a policy", other.format()))),
+    }
+}
+
+/// `wrap` with JSON in and out (JNI / CLI shape). `policy_text` only for policy proofs.
+pub fn wrap_json(presentation_json: &str, policy_text: Option<&str>) -> ZkpResult<String> {
+    let policy = policy_text.map(Policy::parse).transpose()?;
+    VerifiablePresentation::wrap(&EmbeddedProof::from_json(presentation_json)?, policy.as_ref())?.to_json()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧮 HELPERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Unix seconds -> `2026-01-31T12:00:00Z`.
+fn rfc3339(unix: u64) -> String {
+    let (days, secs) = (unix / 86_400, unix % 86_400);
+    // Howard Hinnant ka civil_from_days
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year, month, day, secs / 3_600, secs % 3_600 / 60, secs % 60
+    )
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::jstring;
+    use log::info;
+
+    use crate::engine::CircuitCache;
+    use crate::jni_bridge::{guard, new_string, read_string};
+    use crate::policy::{verify_with_policy, Policy};
+
+    /// Any presentation JSON in, VP JSON out. `policyJson` may be "" unless it is a policy proof.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Vc_wrap(
+        mut env: JNIEnv,
+        _class: JClass,
+        presentation_json: JString,
+        policy_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let presentation = read_string(env, &presentation_json, "presentation_json")?;
+            let policy = read_string(env, &policy_json, "policy_json")?;
+            let policy = Some(policy.as_str()).filter(|p| !p.trim().is_empty());
+            new_string(env, super::wrap_json(&presentation, policy)?)
+        })
+    }
+
+    /// Verifies like `VerifierActivity.verifyProofFromRust` but returns the result as a VP.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Vc_verifiedPresentation(
+        mut env: JNIEnv,
+        _class: JClass,
+        payload_json: JString,
+        policy_json: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let payload = read_string(env, &payload_json, "payload")?;
+            let policy = Policy::parse(&read_string(env, &policy_json, "policy")?)?;
+            let circuits = CircuitCache::shared();
+
+            let report = verify_with_policy(&payload, &policy, circuits)?;
+            info!("📋 Policy report ready (verified: {})", report.verified);
+            let vp = super::report_presentation(&payload, &report, circuits)?;
+            new_string(env, vp.to_json()?)
+        })
+    }
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_Vc_canonical(
+        mut env: JNIEnv,
+        _class: JClass,
+        vp_json: JString,
+    ) -> jstring {
+        guard(&mut env, |env| {
+            let vp = super::VerifiablePresentation::from_json(&read_string(env, &vp_json, "vp_json")?)?;
+            new_string(env, vp.to_canonical_nquads()?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credential_vp() -> VerifiablePresentation {
+        let mut disclosed = BTreeMap::new();
+        disclosed.insert("age".to_string(), 21);
+        let presentation = CredentialPresentation {
+            issuer: "ab".repeat(80),
+            schema: "student id".into(),
+            disclosed,
+            circuit_digest: "cd".repeat(32),
+            proof: general_purpose::STANDARD.encode(b"not a real proof"),
+        };
+        VerifiablePresentation::wrap(&EmbeddedProof::Credential(presentation), None).unwrap()
+    }
+
+    #[test]
+    fn canonical_nquads_ignore_json_layout() {
+        let vp = credential_vp();
+        let canonical = vp.to_canonical_nquads().unwrap();
+
+        let pretty = serde_json::to_string_pretty(&serde_json::to_value(&vp).unwrap()).unwrap();
+        assert_eq!(VerifiablePresentation::from_json(&pretty).unwrap().to_canonical_nquads().unwrap(), canonical);
+
+        assert!(canonical.contains("<urn:zkp-app:schema:student%20id>"));
+        assert!(canonical.contains("<https://www.w3.org/ns/credentials/issuer-dependent#age> \"21\"^^<http://www.w3.org/2001/XMLSchema#integer> _:c14n"));
+        assert!(canonical.contains(&format!("<https://w3id.org/security#verificationMethod> <{}> _:c14n", circuit_urn(&"cd".repeat(32)))));
+        assert!(canonical.lines().all(|l| l.ends_with(" .")));
+    }
+
+    #[test]
+    fn changed_claims_change_the_canonical_form() {
+        let vp = credential_vp();
+        let mut changed = vp.clone();
+        changed.verifiable_credential[0].credential_subject.insert("age".into(), json!(30));
+        assert_ne!(changed.to_canonical_nquads().unwrap(), vp.to_canonical_nquads().unwrap());
+        assert!(matches!(changed.embedded(), Err(ZkpError::ProofRejected(_))));
+    }
+
+    fn nullifier_envelope(circuits: &CircuitCache) -> ProofEnvelope {
+        let circuit = circuits.nullifier();
+        let proof = circuit.prove("secret", "example.org", "challenge").unwrap();
+        ProofEnvelope::seal(ProofKind::Nullifier, &circuit.data, &proof).unwrap()
+    }
+
+    #[test]
+    fn envelope_claims_come_from_the_proof() {
+        let circuits = CircuitCache::default();
+        let envelope = nullifier_envelope(&circuits);
+        let vp = VerifiablePresentation::wrap(&EmbeddedProof::Envelope(envelope.clone()), None).unwrap();
+        assert_eq!(vp.verifiable_credential[0].credential_subject["publicInputs"], json!(envelope.public_inputs));
+        verify_vp(&VerifiablePresentation::from_json(&vp.to_json().unwrap()).unwrap(), &[], &circuits).unwrap();
+
+        // Subject me proof se alag nullifier
+        let mut claimed = vp.clone();
+        claimed.verifiable_credential[0].credential_subject.insert("publicInputs".into(), json!([1, 2, 3]));
+        assert!(matches!(claimed.embedded(), Err(ZkpError::ProofRejected(_))));
+
+        // Subject aur envelope ki copy dono badli, proof wahi
+        let mut copied = envelope.clone();
+        copied.public_inputs[2] += 1;
+        let mut relabelled = vp.clone();
+        relabelled.proof.proof_value = general_purpose::STANDARD.encode(copied.to_json().unwrap());
+        relabelled.verifiable_credential[0].credential_subject.insert("publicInputs".into(), json!(copied.public_inputs));
+        assert!(matches!(relabelled.embedded(), Err(ZkpError::ProofRejected(_))));
+        assert!(matches!(verify_envelope(&copied, &circuits), Err(ZkpError::ProofRejected(_))));
+
+        // Verifier ke naam se bhi holder claims nahi
+        let mut reissued = vp;
+        reissued.verifiable_credential[0].issuer = VERIFIER_ISSUER.into();
+        assert!(matches!(reissued.embedded(), Err(ZkpError::ProofRejected(_))));
+    }
+
+    #[test]
+    fn schema_urn_is_always_absolute() {
+        assert_eq!(schema_urn("student-id"), "urn:zkp-app:schema:student-id");
+        assert_eq!(schema_urn("a b/ü"), "urn:zkp-app:schema:a%20b%2F%C3%BC");
+    }
+}
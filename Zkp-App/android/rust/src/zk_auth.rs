@@ -1,21 +1,19 @@
-use jni::JNIEnv;
-use jni::objects::{JClass, JString};
-use jni::sys::jstring;
-use std::panic;
-
-use android_logger::Config;
-use log::LevelFilter;
-
-use plonky2::field::types::Field;
+use plonky2::field::types::{Field, PrimeField64};
 use plonky2::plonk::circuit_builder::CircuitBuilder;
-use plonky2::plonk::circuit_data::CircuitConfig;
+use plonky2::plonk::circuit_data::{CircuitConfig, CircuitData};
 use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+use plonky2::plonk::proof::ProofWithPublicInputs;
 use plonky2::hash::poseidon::PoseidonHash;
 use plonky2::plonk::config::Hasher;
+use plonky2::iop::target::Target;
 use plonky2::iop::witness::{PartialWitness, WitnessWrite};
 
 use base64::{Engine as _, engine::general_purpose};
 
+use crate::engine::{verify_envelope, CircuitCache};
+use crate::envelope::{ProofEnvelope, ProofKind};
+use crate::error::{ZkpError, ZkpResult};
+
 const D: usize = 2;
 type C = PoseidonGoldilocksConfig;
 type F = <C as GenericConfig<D>>::F;
@@ -25,52 +23,29 @@ const PI_DOMAIN: usize = 0;
 const PI_CHALLENGE: usize = 1;
 const PI_NULLIFIER: usize = 2;
 
-fn init_logger() {
-    let _ = android_logger::init_once(
-        Config::default()
-            .with_max_level(LevelFilter::Info)
-            .with_tag("RustZKP_Auth"),
-    );
-}
-
 fn string_to_field(input: &str) -> Vec<F> {
     input.bytes().map(F::from_canonical_u8).collect()
 }
 
-// 🦁 CRITICAL FIX: Name changed to match 'com.example.zkpapp.ZkAuth'
-// Yeh naam 'ZkAuth.kt' file ke package aur object name se match karna chahiye.
-#[no_mangle]
-pub extern "system" fn Java_com_example_zkpapp_ZkAuth_generateSecureNullifier(
-    mut env: JNIEnv,
-    _class: JClass,
-    secret_input: JString,
-    domain_input: JString,
-    challenge_input: JString,
-) -> jstring {
-    init_logger();
-
-    // Read JNI strings safely
-    let secret: String = match env.get_string(&secret_input) {
-        Ok(v) => v.into(),
-        Err(_) => return env.new_string("Error: Invalid Secret").unwrap().into_raw(),
-    };
-
-    let domain: String = match env.get_string(&domain_input) {
-        Ok(v) => v.into(),
-        Err(_) => return env.new_string("Error: Invalid Domain").unwrap().into_raw(),
-    };
+/// Maps an arbitrary string (secret / domain / challenge) to one field element.
+pub(crate) fn hash_to_field(input: &str) -> F {
+    PoseidonHash::hash_no_pad(&string_to_field(input)).elements[0]
+}
 
-    let challenge: String = match env.get_string(&challenge_input) {
-        Ok(v) => v.into(),
-        Err(_) => return env.new_string("Error: Invalid Challenge").unwrap().into_raw(),
-    };
+// ═══════════════════════════════════════════════════════════════════════════
+// 🧠 NULLIFIER CIRCUIT
+// nullifier = Poseidon(secret, domain, challenge); domain + challenge public.
+// ═══════════════════════════════════════════════════════════════════════════
 
-    // Panic-safe ZKP computation
-    let result: Result<String, String> = panic::catch_unwind(|| {
-        let secret_f = PoseidonHash::hash_no_pad(&string_to_field(&secret)).elements[0];
-        let domain_f = PoseidonHash::hash_no_pad(&string_to_field(&domain)).elements[0];
-        let challenge_f = PoseidonHash::hash_no_pad(&string_to_field(&challenge)).elements[0];
+pub(crate) struct NullifierCircuit {
+    pub(crate) data: CircuitData<F, C, D>,
+    t_secret: Target,
+    t_domain: Target,
+    t_challenge: Target,
+}
 
+impl NullifierCircuit {
+    pub(crate) fn build() -> Self {
         let config = CircuitConfig::standard_recursion_config();
         let mut builder = CircuitBuilder::<F, D>::new(config);
 
@@ -86,31 +61,152 @@ pub extern "system" fn Java_com_example_zkpapp_ZkAuth_generateSecureNullifier(
         builder.register_public_input(t_challenge);
         builder.register_public_input(hash.elements[0]);
 
-        let data = builder.build::<C>();
+        Self { data: builder.build::<C>(), t_secret, t_domain, t_challenge }
+    }
+
+    pub(crate) fn prove(
+        &self,
+        secret: &str,
+        domain: &str,
+        challenge: &str,
+    ) -> ZkpResult<ProofWithPublicInputs<F, C, D>> {
+        if secret.is_empty() {
+            return Err(ZkpError::InvalidInput("secret must not be empty".into()));
+        }
 
         let mut pw = PartialWitness::new();
-        pw.set_target(t_secret, secret_f);
-        pw.set_target(t_domain, domain_f);
-        pw.set_target(t_challenge, challenge_f);
+        pw.set_target(self.t_secret, hash_to_field(secret));
+        pw.set_target(self.t_domain, hash_to_field(domain));
+        pw.set_target(self.t_challenge, hash_to_field(challenge));
 
-        let proof = data
+        self.data
             .prove(pw)
-            .map_err(|_| "Error: Proving Failed".to_string())?;
+            .map_err(|e| ZkpError::ProvingFailed(e.to_string()))
+    }
+}
 
-        let proof_b64 = general_purpose::STANDARD.encode(
-            bincode::serialize(&proof)
-                .map_err(|_| "Error: Serialize Failed".to_string())?,
-        );
+/// Returns `"<nullifier>|<base64 proof>"`, the format `ZkAuthManager` uploads.
+pub fn prove_nullifier(secret: &str, domain: &str, challenge: &str) -> ZkpResult<String> {
+    let proof = CircuitCache::shared().nullifier().prove(secret, domain, challenge)?;
 
-        // Nullifier is at index 2 (PI_NULLIFIER)
-        let nullifier = proof.public_inputs[PI_NULLIFIER];
+    let proof_b64 = general_purpose::STANDARD.encode(
+        bincode::serialize(&proof)
+            .map_err(|e| ZkpError::Serialization(e.to_string()))?,
+    );
 
-        Ok(format!("{}|{}", nullifier, proof_b64))
+    // Nullifier is at index 2 (PI_NULLIFIER)
+    let nullifier = proof.public_inputs[PI_NULLIFIER];
+
+    Ok(format!("{}|{}", nullifier, proof_b64))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🛂 SERVER-SIDE CHECK (relay)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Public inputs of a verified nullifier proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullifierPublic {
+    pub domain: u64,
+    pub challenge: u64,
+    pub nullifier: u64,
+}
+
+impl NullifierPublic {
+    pub fn commits_to_domain(&self, domain: &str) -> bool {
+        self.domain == hash_to_field(domain).to_canonical_u64()
+    }
+
+    pub fn commits_to_challenge(&self, challenge: &str) -> bool {
+        self.challenge == hash_to_field(challenge).to_canonical_u64()
+    }
+}
+
+/// Verifies an uploaded nullifier proof and returns its public inputs. Accepts
+/// the `"<nullifier>|<base64 proof>"` upload or a nullifier `ProofEnvelope`.
+/// Binding to a domain / challenge is the caller's job (see challenge.rs).
+pub fn open_nullifier_proof(proof_data: &str, circuits: &CircuitCache) -> ZkpResult<NullifierPublic> {
+    let proof_data = proof_data.trim();
+
+    let proof = if proof_data.starts_with('{') {
+        let envelope = ProofEnvelope::from_json(proof_data)?;
+        if envelope.kind != ProofKind::Nullifier {
+            return Err(ZkpError::InvalidInput(format!("expected a nullifier proof, got {:?}", envelope.kind)));
+        }
+        verify_envelope(&envelope, circuits)?;
+        envelope.open()?
+    } else {
+        let (claimed, proof_b64) = proof_data
+            .split_once('|')
+            .ok_or_else(|| ZkpError::InvalidInput("expected \"<nullifier>|<proof>\"".into()))?;
+        let claimed: u64 = claimed
+            .trim()
+            .parse()
+            .map_err(|_| ZkpError::InvalidInput("nullifier is not a number".into()))?;
+
+        let bytes = general_purpose::STANDARD.decode(proof_b64.trim())?;
+        let proof: ProofWithPublicInputs<F, C, D> =
+            bincode::deserialize(&bytes).map_err(|e| ZkpError::CorruptProof(e.to_string()))?;
+
+        circuits
+            .nullifier()
+            .data
+            .verify(proof.clone())
+            .map_err(|e| ZkpError::ProofRejected(format!("{:?}", e)))?;
+
+        // Prefix sirf display ke liye hai, par jhoot nahi bol sakta
+        if proof.public_inputs.get(PI_NULLIFIER).map(|x| x.to_canonical_u64()) != Some(claimed) {
+            return Err(ZkpError::ProofRejected("nullifier prefix does not match proof".into()));
+        }
+        proof
+    };
+
+    let input = |i: usize| {
+        proof
+            .public_inputs
+            .get(i)
+            .map(|x| x.to_canonical_u64())
+            .ok_or_else(|| ZkpError::CorruptProof(format!("public input {} missing", i)))
+    };
+
+    Ok(NullifierPublic {
+        domain: input(PI_DOMAIN)?,
+        challenge: input(PI_CHALLENGE)?,
+        nullifier: input(PI_NULLIFIER)?,
     })
-    .unwrap_or_else(|_| Err("Error: Rust Panic".to_string()));
+}
 
-    match result {
-        Ok(v) => env.new_string(v).unwrap().into_raw(),
-        Err(e) => env.new_string(e).unwrap().into_raw(),
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌉 JNI EXPORTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(feature = "jni")]
+mod jni_exports {
+    use jni::JNIEnv;
+    use jni::objects::{JClass, JString};
+    use jni::sys::jstring;
+
+    use crate::jni_bridge::{guard, new_string, read_string};
+
+    // 🦁 CRITICAL FIX: Name changed to match 'com.example.zkpapp.ZkAuth'
+    // Yeh naam 'ZkAuth.kt' file ke package aur object name se match karna chahiye.
+    #[no_mangle]
+    pub extern "system" fn Java_com_example_zkpapp_ZkAuth_generateSecureNullifier(
+        mut env: JNIEnv,
+        _class: JClass,
+        secret_input: JString,
+        domain_input: JString,
+        challenge_input: JString,
+    ) -> jstring {
+        crate::init_logger();
+
+        guard(&mut env, |env| {
+            let secret = read_string(env, &secret_input, "secret")?;
+            let domain = read_string(env, &domain_input, "domain")?;
+            let challenge = read_string(env, &challenge_input, "challenge")?;
+
+            let output = super::prove_nullifier(&secret, &domain, &challenge)?;
+            new_string(env, output)
+        })
     }
-}
\ No newline at end of file
+}
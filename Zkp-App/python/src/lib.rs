@@ -0,0 +1,296 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🐍 PYTHON BINDINGS (module `zkp_identity`)
+// Notebooks se test proofs banao aur proof logs batch-verify karo. Sab kuch
+// plain `bytes` / `str` / `int` / `dict`: numpy ki zaroorat nahi.
+// Type hints: zkp_identity.pyi
+// ═══════════════════════════════════════════════════════════════════════════
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use zkp_mobile::engine::{EngineConfig, VerificationReport, ZkpEngine};
+use zkp_mobile::envelope::{ProofEnvelope, ProofKind};
+use zkp_mobile::error::ZkpError as CoreError;
+use zkp_mobile::identity_request::IdentityRequest;
+use zkp_mobile::offline_identity;
+use zkp_mobile::passport_security::PassportData;
+use zkp_mobile::proving_jobs::NoProgress;
+use zkp_mobile::qr_stream;
+use zkp_mobile::zk_auth::open_nullifier_proof;
+
+create_exception!(
+    zkp_identity,
+    ZkpError,
+    PyException,
+    "Engine failure. `.code` / `.kind` match the app's ZkpException codes."
+);
+
+fn py_err(e: CoreError) -> PyErr {
+    let err = ZkpError::new_err(e.to_string());
+    Python::with_gil(|py| {
+        let value = err.value_bound(py);
+        let _ = value.setattr("code", e.code());
+        let _ = value.setattr("kind", e.kind());
+    });
+    err
+}
+
+fn utf8(bytes: &[u8], what: &str) -> PyResult<String> {
+    String::from_utf8(bytes.to_vec())
+        .map_err(|_| py_err(CoreError::InvalidEncoding(format!("{} is not UTF-8", what))))
+}
+
+fn report_dict<'py>(py: Python<'py>, report: &VerificationReport) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new_bound(py);
+    dict.set_item("kind", report.kind.as_str())?;
+    dict.set_item("verified", report.verified)?;
+    dict.set_item("reason", report.reason.as_deref())?;
+    dict.set_item("circuit_digest", &report.circuit_digest)?;
+    dict.set_item("public_inputs", &report.public_inputs)?;
+    dict.set_item("elapsed_ms", report.elapsed_ms)?;
+    Ok(dict)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 📦 ENVELOPE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parsed `ProofEnvelope` (read-only).
+#[pyclass(frozen, module = "zkp_identity")]
+#[derive(Clone)]
+struct Envelope {
+    inner: ProofEnvelope,
+}
+
+#[pymethods]
+impl Envelope {
+    #[staticmethod]
+    fn from_json(data: &[u8]) -> PyResult<Self> {
+        let json = utf8(data, "envelope")?;
+        Ok(Self { inner: ProofEnvelope::from_json(&json).map_err(py_err)? })
+    }
+
+    fn to_json<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let json = self.inner.to_json().map_err(py_err)?;
+        Ok(PyBytes::new_bound(py, json.as_bytes()))
+    }
+
+    #[getter]
+    fn version(&self) -> u8 {
+        self.inner.version
+    }
+
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.inner.kind.as_str()
+    }
+
+    #[getter]
+    fn circuit_digest(&self) -> &str {
+        &self.inner.circuit_digest
+    }
+
+    /// Informational copy from the JSON; `Engine.verify` reports the proof's own.
+    #[getter]
+    fn public_inputs(&self) -> Vec<u64> {
+        self.inner.public_inputs.clone()
+    }
+
+    /// Raw bincode proof bytes.
+    #[getter]
+    fn proof<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        use base64::Engine as _;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(&self.inner.proof)
+            .map_err(|e| py_err(e.into()))?;
+        Ok(PyBytes::new_bound(py, &bytes))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Envelope(kind='{}', version={}, circuit_digest='{:.16}…', public_inputs={:?})",
+            self.inner.kind.as_str(),
+            self.inner.version,
+            self.inner.circuit_digest,
+            self.inner.public_inputs
+        )
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🏭 ENGINE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Long-lived prover/verifier with cached circuits (`ZkpEngine`).
+#[pyclass(frozen, module = "zkp_identity")]
+struct Engine {
+    inner: ZkpEngine,
+}
+
+impl Engine {
+    fn prove_request(&self, py: Python<'_>, request: IdentityRequest) -> PyResult<Py<PyBytes>> {
+        let json = py
+            .allow_threads(|| self.inner.prove(&request, &mut NoProgress)?.to_json())
+            .map_err(py_err)?;
+        Ok(PyBytes::new_bound(py, json.as_bytes()).unbind())
+    }
+}
+
+#[pymethods]
+impl Engine {
+    /// `config_json` is `EngineConfig` JSON. Replay tracking is off unless asked
+    /// for: batch logs legitimately contain the same nullifier many times.
+    #[new]
+    #[pyo3(signature = (config_json = ""))]
+    fn new(py: Python<'_>, config_json: &str) -> PyResult<Self> {
+        let config: EngineConfig = if config_json.trim().is_empty() {
+            EngineConfig { track_nullifiers: false, ..EngineConfig::default() }
+        } else {
+            serde_json::from_str(config_json).map_err(|e| py_err(e.into()))?
+        };
+        let inner = py.allow_threads(|| ZkpEngine::new(config)).map_err(py_err)?;
+        Ok(Self { inner })
+    }
+
+    /// Hex digest of this build's circuit for `kind`.
+    fn circuit_digest(&self, py: Python<'_>, kind: &str) -> PyResult<String> {
+        let kind: ProofKind = kind.parse().map_err(py_err)?;
+        py.allow_threads(|| self.inner.circuits().digest(kind)).map_err(py_err)
+    }
+
+    /// Any `IdentityRequest` JSON -> envelope JSON bytes.
+    fn prove(&self, py: Python<'_>, request_json: &str) -> PyResult<Py<PyBytes>> {
+        let request = IdentityRequest::from_json(request_json).map_err(py_err)?;
+        self.prove_request(py, request)
+    }
+
+    fn prove_balance(&self, py: Python<'_>, balance: u64) -> PyResult<Py<PyBytes>> {
+        self.prove_request(py, IdentityRequest::Balance { balance })
+    }
+
+    fn prove_nullifier(&self, py: Python<'_>, secret: String, domain: String, challenge: String) -> PyResult<Py<PyBytes>> {
+        self.prove_request(py, IdentityRequest::Nullifier { secret, domain, challenge })
+    }
+
+    /// `passport_json` = `PassportData` JSON (as produced by `PassportData.toRustJson()`).
+    fn prove_passport(&self, py: Python<'_>, passport_json: &str) -> PyResult<Py<PyBytes>> {
+        let data: PassportData = serde_json::from_str(passport_json).map_err(|e| py_err(e.into()))?;
+        self.prove_request(py, IdentityRequest::Passport(data))
+    }
+
+    /// Envelope JSON bytes -> report dict. A rejected proof is `verified=False`, not an exception.
+    fn verify<'py>(&self, py: Python<'py>, envelope: &[u8]) -> PyResult<Bound<'py, PyDict>> {
+        let envelope = ProofEnvelope::from_json(&utf8(envelope, "envelope")?).map_err(py_err)?;
+        let report = py.allow_threads(|| self.inner.verify(&envelope)).map_err(py_err)?;
+        report_dict(py, &report)
+    }
+
+    /// Batch version of `verify` for log files: unreadable entries become
+    /// `{"verified": False, "error": ..., "code": ...}` instead of raising.
+    fn verify_many<'py>(&self, py: Python<'py>, envelopes: Vec<Vec<u8>>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let results = py.allow_threads(|| {
+            envelopes
+                .iter()
+                .map(|bytes| {
+                    let json = std::str::from_utf8(bytes)
+                        .map_err(|_| CoreError::InvalidEncoding("envelope is not UTF-8".into()))?;
+                    self.inner.verify(&ProofEnvelope::from_json(json)?)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        results
+            .iter()
+            .map(|result| match result {
+                Ok(report) => report_dict(py, report),
+                Err(e) => {
+                    let dict = PyDict::new_bound(py);
+                    dict.set_item("verified", false)?;
+                    dict.set_item("error", e.to_string())?;
+                    dict.set_item("code", e.code())?;
+                    Ok(dict)
+                }
+            })
+            .collect()
+    }
+
+    /// Relay upload (`b"<nullifier>|<base64 proof>"` or a nullifier envelope), optionally
+    /// checking the domain / challenge it must be bound to.
+    #[pyo3(signature = (proof_data, domain = None, challenge = None))]
+    fn verify_nullifier_upload<'py>(
+        &self,
+        py: Python<'py>,
+        proof_data: &[u8],
+        domain: Option<&str>,
+        challenge: Option<&str>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let text = utf8(proof_data, "proof_data")?;
+        let outcome = py.allow_threads(|| open_nullifier_proof(&text, self.inner.circuits()));
+
+        let dict = PyDict::new_bound(py);
+        let public = match outcome {
+            Ok(public) => public,
+            Err(CoreError::ProofRejected(reason)) => {
+                dict.set_item("verified", false)?;
+                dict.set_item("reason", reason)?;
+                return Ok(dict);
+            }
+            Err(e) => return Err(py_err(e)),
+        };
+
+        let reason = if domain.is_some_and(|d| !public.commits_to_domain(d)) {
+            Some("proof was made for another domain")
+        } else if challenge.is_some_and(|c| !public.commits_to_challenge(c)) {
+            Some("proof was made for another challenge")
+        } else {
+            None
+        };
+        dict.set_item("verified", reason.is_none())?;
+        dict.set_item("reason", reason)?;
+        dict.set_item("nullifier", public.nullifier)?;
+        dict.set_item("public_inputs", vec![public.domain, public.challenge, public.nullifier])?;
+        Ok(dict)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// 🔧 FREE FUNCTIONS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Raw base64 balance proof (offline QR payload). `False` = rejected; garbage raises.
+#[pyfunction]
+fn verify_balance_proof(py: Python<'_>, proof_base64: &[u8]) -> PyResult<bool> {
+    let proof = utf8(proof_base64, "proof_base64")?;
+    match py.allow_threads(|| offline_identity::verify_balance_proof(&proof)) {
+        Ok(()) => Ok(true),
+        Err(CoreError::ProofRejected(_)) => Ok(false),
+        Err(e) => Err(py_err(e)),
+    }
+}
+
+/// Payload -> `"i/n|data"` frames, same as the app's QR stream.
+#[pyfunction]
+#[pyo3(signature = (payload, chunk_size = qr_stream::QR_CHUNK_SIZE))]
+fn qr_split(payload: &str, chunk_size: usize) -> Vec<String> {
+    qr_stream::split(payload, chunk_size)
+}
+
+/// Frames (any order, duplicates ok) -> payload.
+#[pyfunction]
+fn qr_join(frames: Vec<String>) -> PyResult<String> {
+    qr_stream::join(&frames).map_err(py_err)
+}
+
+#[pymodule]
+fn zkp_identity(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("ZkpError", m.py().get_type_bound::<ZkpError>())?;
+    m.add("QR_CHUNK_SIZE", qr_stream::QR_CHUNK_SIZE)?;
+    m.add_class::<Engine>()?;
+    m.add_class::<Envelope>()?;
+    m.add_function(wrap_pyfunction!(verify_balance_proof, m)?)?;
+    m.add_function(wrap_pyfunction!(qr_split, m)?)?;
+    m.add_function(wrap_pyfunction!(qr_join, m)?)?;
+    Ok(())
+}
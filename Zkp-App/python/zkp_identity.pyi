@@ -0,0 +1,57 @@
+"""Plonky2 identity proofs: prove, verify, envelopes, QR frames (Rust core)."""
+
+from typing import Any, Dict, List, Optional
+
+QR_CHUNK_SIZE: int
+
+class ZkpError(Exception):
+    """Engine failure. `code` / `kind` match the app's ZkpException codes."""
+
+    code: int
+    kind: str
+
+class Envelope:
+    """Parsed proof envelope (read-only)."""
+
+    @staticmethod
+    def from_json(data: bytes) -> "Envelope": ...
+    def to_json(self) -> bytes: ...
+    @property
+    def version(self) -> int: ...
+    @property
+    def kind(self) -> str: ...
+    @property
+    def circuit_digest(self) -> str: ...
+    @property
+    def public_inputs(self) -> List[int]: ...
+    @property
+    def proof(self) -> bytes: ...
+
+class Engine:
+    """Long-lived prover/verifier. Circuits are built once and cached.
+
+    Without `config_json`, nullifier replay tracking is off so batch logs
+    can be verified more than once.
+    """
+
+    def __init__(self, config_json: str = "") -> None: ...
+    def circuit_digest(self, kind: str) -> str: ...
+    def prove(self, request_json: str) -> bytes: ...
+    def prove_balance(self, balance: int) -> bytes: ...
+    def prove_nullifier(self, secret: str, domain: str, challenge: str) -> bytes: ...
+    def prove_passport(self, passport_json: str) -> bytes: ...
+    def verify(self, envelope: bytes) -> Dict[str, Any]:
+        """`{kind, verified, reason, circuit_digest, public_inputs, elapsed_ms}`."""
+    def verify_many(self, envelopes: List[bytes]) -> List[Dict[str, Any]]:
+        """Never raises per entry: failures are `{verified: False, error, code}`."""
+    def verify_nullifier_upload(
+        self,
+        proof_data: bytes,
+        domain: Optional[str] = None,
+        challenge: Optional[str] = None,
+    ) -> Dict[str, Any]:
+        """`{verified, reason, nullifier?, public_inputs?}` for a relay upload."""
+
+def verify_balance_proof(proof_base64: bytes) -> bool: ...
+def qr_split(payload: str, chunk_size: int = ...) -> List[str]: ...
+def qr_join(frames: List[str]) -> str: ...
@@ -0,0 +1,146 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌐 ZKP VERIFIER (wasm32-unknown-unknown)
+// Relying website proof ko khud browser me verify karta hai, relay ki baat par
+// bharosa kiye bina. Same circuits + digest check jo Android verifier use karta
+// hai (zkp_mobile::engine::verify_envelope); ZkpEngine nahi, kyunki wasm me
+// thread pool aur `Instant` available nahi hain.
+//
+//   import init, { verify, warm } from "./pkg/zkp_verifier_wasm.js";
+//   await init();
+//   const report = verify(bytes, { allowed_kinds: ["nullifier"], expected_domain, expected_challenge });
+// ═══════════════════════════════════════════════════════════════════════════
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use zkp_mobile::engine::{verify_envelope, CircuitCache};
+use zkp_mobile::envelope::{ProofEnvelope, ProofKind};
+use zkp_mobile::error::{ZkpError, ZkpResult};
+use zkp_mobile::zk_auth::open_nullifier_proof;
+
+thread_local! {
+    // Browser single-threaded hai: ek cache poore page ke liye
+    static CIRCUITS: CircuitCache = CircuitCache::default();
+}
+
+/// Second argument of `verify`. Every field is optional.
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+pub struct VerifyPolicy {
+    /// Accepted proof kinds. Empty = any.
+    pub allowed_kinds: Vec<ProofKind>,
+    /// Accepted circuit digests (hex). Empty = whatever this build produces.
+    pub trusted_digests: Vec<String>,
+    /// Nullifier proofs must commit to this domain (the relay's `domain`).
+    pub expected_domain: Option<String>,
+    /// Nullifier proofs must commit to this challenge (the relay's `challenge` token).
+    pub expected_challenge: Option<String>,
+}
+
+/// What `verify` returns. u64 values are strings: JS numbers stop at 2^53.
+#[derive(Serialize, Debug)]
+pub struct BrowserReport {
+    pub kind: ProofKind,
+    pub verified: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub circuit_digest: String,
+    pub public_inputs: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nullifier: Option<String>,
+}
+
+#[wasm_bindgen(start)]
+pub fn start() {
+    console_error_panic_hook::set_once();
+}
+
+fn js_error(e: ZkpError) -> JsError {
+    JsError::new(&e.to_string())
+}
+
+/// Builds a circuit ahead of time (e.g. while the user is still scanning).
+#[wasm_bindgen]
+pub fn warm(kind: &str) -> Result<(), JsError> {
+    let kind: ProofKind = kind.parse().map_err(js_error)?;
+    CIRCUITS.with(|circuits| circuits.warm(kind)).map_err(js_error)
+}
+
+/// Verifies a proof envelope (JSON bytes) or the app's `"<nullifier>|<proof>"`
+/// upload against `policy`. A rejected proof is a report with `verified: false`;
+/// unreadable input throws.
+#[wasm_bindgen]
+pub fn verify(envelope_bytes: &[u8], policy: JsValue) -> Result<JsValue, JsError> {
+    let policy: VerifyPolicy = if policy.is_undefined() || policy.is_null() {
+        VerifyPolicy::default()
+    } else {
+        serde_wasm_bindgen::from_value(policy).map_err(|e| JsError::new(&format!("bad policy: {}", e)))?
+    };
+
+    let report = CIRCUITS.with(|circuits| check(envelope_bytes, &policy, circuits)).map_err(js_error)?;
+    serde_wasm_bindgen::to_value(&report).map_err(|e| JsError::new(&e.to_string()))
+}
+
+fn check(bytes: &[u8], policy: &VerifyPolicy, circuits: &CircuitCache) -> ZkpResult<BrowserReport> {
+    let text = std::str::from_utf8(bytes)
+        .map_err(|_| ZkpError::InvalidEncoding("proof bytes are not UTF-8".into()))?
+        .trim();
+
+    // Legacy upload has no envelope: it can only be a nullifier proof on this build's circuit
+    let envelope = if text.starts_with('{') { Some(ProofEnvelope::from_json(text)?) } else { None };
+    let kind = envelope.as_ref().map_or(ProofKind::Nullifier, |e| e.kind);
+    let circuit_digest = match &envelope {
+        Some(e) => e.circuit_digest.clone(),
+        None => circuits.digest(ProofKind::Nullifier)?,
+    };
+
+    let mut report = BrowserReport {
+        kind,
+        verified: false,
+        reason: None,
+        circuit_digest,
+        public_inputs: Vec::new(),
+        nullifier: None,
+    };
+    let rejected = |mut report: BrowserReport, reason: &str| {
+        report.reason = Some(reason.to_string());
+        Ok(report)
+    };
+
+    if !policy.allowed_kinds.is_empty() && !policy.allowed_kinds.contains(&kind) {
+        return rejected(report, "proof kind not allowed by policy");
+    }
+    if !policy.trusted_digests.is_empty()
+        && !policy.trusted_digests.iter().any(|d| d.eq_ignore_ascii_case(&report.circuit_digest))
+    {
+        return rejected(report, "circuit digest not trusted by policy");
+    }
+
+    if kind == ProofKind::Nullifier {
+        let public = match open_nullifier_proof(text, circuits) {
+            Ok(public) => public,
+            Err(ZkpError::ProofRejected(reason)) => return rejected(report, &reason),
+            Err(e) => return Err(e),
+        };
+        report.public_inputs = [public.domain, public.challenge, public.nullifier].iter().map(u64::to_string).collect();
+        report.nullifier = Some(public.nullifier.to_string());
+
+        if policy.expected_domain.as_deref().is_some_and(|d| !public.commits_to_domain(d)) {
+            return rejected(report, "proof was made for another domain");
+        }
+        if policy.expected_challenge.as_deref().is_some_and(|c| !public.commits_to_challenge(c)) {
+            return rejected(report, "proof was made for another challenge");
+        }
+    } else {
+        let envelope = envelope.expect("non-nullifier kinds only come from envelopes");
+        match verify_envelope(&envelope, circuits) {
+            Ok(()) => {}
+            Err(ZkpError::ProofRejected(reason)) => return rejected(report, &reason),
+            Err(e) => return Err(e),
+        }
+        report.public_inputs = envelope.proof_public_inputs()?.iter().map(u64::to_string).collect();
+    }
+
+    report.verified = true;
+    Ok(report)
+}
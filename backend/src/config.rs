@@ -0,0 +1,87 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ⚙️ RELAY CONFIG (environment variables, sab optional)
+//   RELAY_ADDR              listen address          (0.0.0.0:3000)
+//   RELAY_STORE             session store JSON file (sessions.json)
+//   RELAY_PUBLIC_DIR        static website          (public)
+//   RELAY_SESSION_TTL_SECS  QR / session lifetime   (300)
+//   RELAY_MAX_BODY_BYTES    upload size limit       (524288)
+//   RELAY_MAX_SESSIONS      live sessions cap       (10000)
+//   RELAY_DOMAIN            domain proofs must bind (zkp-relay)
+//   RELAY_CHALLENGE_KEY     hex HMAC key, >= 32 bytes (random per start if unset)
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::env;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// No `Debug`: it holds the challenge key.
+#[derive(Clone)]
+pub struct RelayConfig {
+    pub addr: SocketAddr,
+    pub store_path: PathBuf,
+    pub public_dir: PathBuf,
+    pub session_ttl: Duration,
+    /// A nullifier proof is ~170 KB as base64; 50 MB (old Node limit) was an open door.
+    pub max_body_bytes: usize,
+    pub max_sessions: usize,
+    /// Relying-party domain put into every challenge (the nullifier's PI_DOMAIN).
+    pub domain: String,
+    /// `None` = random key per process; restarts then invalidate open challenges.
+    pub challenge_key: Option<Vec<u8>>,
+}
+
+impl Default for RelayConfig {
+    fn default() -> Self {
+        Self {
+            addr: SocketAddr::from(([0, 0, 0, 0], 3000)),
+            store_path: PathBuf::from("sessions.json"),
+            public_dir: PathBuf::from("public"),
+            session_ttl: Duration::from_secs(300),
+            max_body_bytes: 512 * 1024,
+            max_sessions: 10_000,
+            domain: "zkp-relay".to_string(),
+            challenge_key: None,
+        }
+    }
+}
+
+fn var<T: FromStr>(name: &str) -> Result<Option<T>, String> {
+    match env::var(name) {
+        Ok(raw) => raw.trim().parse().map(Some).map_err(|_| format!("{} has an invalid value: {}", name, raw)),
+        Err(_) => Ok(None),
+    }
+}
+
+impl RelayConfig {
+    pub fn from_env() -> Result<Self, String> {
+        let mut config = Self::default();
+
+        if let Some(addr) = var("RELAY_ADDR")? {
+            config.addr = addr;
+        }
+        if let Some(path) = var("RELAY_STORE")? {
+            config.store_path = path;
+        }
+        if let Some(dir) = var("RELAY_PUBLIC_DIR")? {
+            config.public_dir = dir;
+        }
+        if let Some(secs) = var::<u64>("RELAY_SESSION_TTL_SECS")? {
+            config.session_ttl = Duration::from_secs(secs.max(1));
+        }
+        if let Some(bytes) = var("RELAY_MAX_BODY_BYTES")? {
+            config.max_body_bytes = bytes;
+        }
+        if let Some(max) = var("RELAY_MAX_SESSIONS")? {
+            config.max_sessions = max;
+        }
+        if let Some(domain) = var("RELAY_DOMAIN")? {
+            config.domain = domain;
+        }
+        if let Some(key) = var::<String>("RELAY_CHALLENGE_KEY")? {
+            config.challenge_key = Some(hex::decode(key.trim()).map_err(|_| "RELAY_CHALLENGE_KEY must be hex".to_string())?);
+        }
+        Ok(config)
+    }
+}
@@ -0,0 +1,106 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🦁 ZK RELAY SERVER
+// Website session kholta hai (signed challenge QR), Android app us challenge
+// par bound nullifier proof upload karta hai, relay proof + binding khud verify
+// karta hai (same Plonky2 circuit): "completed" ya reason code ke saath "failed".
+// ═══════════════════════════════════════════════════════════════════════════
+
+mod config;
+mod routes;
+mod store;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post};
+use axum::Router;
+use log::{error, info, warn};
+use rand::RngCore;
+use tower_http::cors::CorsLayer;
+use tower_http::services::ServeDir;
+
+use zkp_mobile::challenge::ChallengeIssuer;
+use zkp_mobile::engine::CircuitCache;
+use zkp_mobile::envelope::ProofKind;
+
+use config::RelayConfig;
+use routes::AppState;
+use store::SessionStore;
+
+const SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
+#[tokio::main]
+async fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    if let Err(e) = run().await {
+        error!("🔥 Relay stopped: {}", e);
+        std::process::exit(1);
+    }
+}
+
+async fn run() -> Result<(), String> {
+    let config = RelayConfig::from_env()?;
+    info!("🦁 ZK Relay Server Starting... domain={} store={}", config.domain, config.store_path.display());
+
+    let key = match &config.challenge_key {
+        Some(key) => key.clone(),
+        None => {
+            warn!("⚠️ RELAY_CHALLENGE_KEY not set: using a random key, open QR codes die on restart");
+            let mut key = vec![0u8; 32];
+            rand::thread_rng().fill_bytes(&mut key);
+            key
+        }
+    };
+    let challenges =
+        ChallengeIssuer::new(&key, config.domain.clone(), config.session_ttl).map_err(|e| e.to_string())?;
+
+    let store = SessionStore::open(config.store_path.clone(), config.max_sessions)
+        .map_err(|e| format!("session store {}: {}", config.store_path.display(), e))?;
+
+    // Circuit build slow hai: pehli upload se pehle hi kar lo
+    let start = Instant::now();
+    let circuits = CircuitCache::default();
+    circuits.warm(ProofKind::Nullifier).map_err(|e| e.to_string())?;
+    info!("🧠 Nullifier circuit ready in {:.2?}", start.elapsed());
+
+    let state = Arc::new(AppState { config: config.clone(), store, circuits, challenges });
+    tokio::spawn(sweep_expired(state.clone()));
+
+    let app = Router::new()
+        .route("/api/start-session", get(routes::start_session))
+        .route("/api/upload-proof", post(routes::upload_proof))
+        .route("/api/poll-status/:session_id", get(routes::poll_status))
+        .fallback_service(ServeDir::new(&config.public_dir))
+        .layer(DefaultBodyLimit::max(config.max_body_bytes))
+        .layer(CorsLayer::permissive())
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(config.addr)
+        .await
+        .map_err(|e| format!("bind {}: {}", config.addr, e))?;
+    info!("🚀 Relay Server running on http://{}", config.addr);
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+            info!("👋 Shutting down");
+        })
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn sweep_expired(state: Arc<AppState>) {
+    let mut tick = tokio::time::interval(SWEEP_INTERVAL);
+    loop {
+        tick.tick().await;
+        let state = state.clone();
+        match tokio::task::spawn_blocking(move || state.store.sweep()).await {
+            Ok(Ok(0)) => {}
+            Ok(Ok(removed)) => info!("🧹 Expired {} sessions", removed),
+            Ok(Err(e)) => warn!("⚠️ Sweep could not persist: {}", e),
+            Err(e) => warn!("⚠️ Sweep task failed: {}", e),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🌐 API ROUTES (same paths & JSON as the old Node relay)
+//   GET  /api/start-session            -> { session_id, challenge, domain, expires_in }
+//   POST /api/upload-proof             <- { session_id, proof_data }  (Android RelayApi)
+//   GET  /api/poll-status/:session_id  -> { status, proof, nullifier, reason, expires_in }
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use log::{error, info, warn};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use zkp_mobile::challenge::{ChallengeIssuer, LoginOutcome};
+use zkp_mobile::engine::CircuitCache;
+use zkp_mobile::error::ZkpError;
+
+use crate::config::RelayConfig;
+use crate::store::{self, Session, SessionStore, Status, StoreError};
+
+pub struct AppState {
+    pub config: RelayConfig,
+    pub store: SessionStore,
+    pub circuits: CircuitCache,
+    pub challenges: ChallengeIssuer,
+}
+
+type Shared = State<Arc<AppState>>;
+
+/// `{ "error": ..., "code"/"reason": ... }` with an HTTP status. `ZkAuthManager.mapError` keys off the status.
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+    code: Option<i32>,
+    reason: Option<&'static str>,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self { status, message: message.into(), code: None, reason: None }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut body = json!({ "error": self.message });
+        if let Some(code) = self.code {
+            body["code"] = json!(code);
+        }
+        if let Some(reason) = self.reason {
+            body["reason"] = json!(reason);
+        }
+        (self.status, Json(body)).into_response()
+    }
+}
+
+impl From<StoreError> for ApiError {
+    fn from(e: StoreError) -> Self {
+        match e {
+            StoreError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "Session Expired or Invalid"),
+            StoreError::AlreadySettled => ApiError::new(StatusCode::CONFLICT, "Session already used"),
+            StoreError::Full => ApiError::new(StatusCode::SERVICE_UNAVAILABLE, "Too many open sessions"),
+            StoreError::Io(e) => {
+                error!("🔥 Session store write failed: {}", e);
+                ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, "Session store unavailable")
+            }
+        }
+    }
+}
+
+impl From<ZkpError> for ApiError {
+    fn from(e: ZkpError) -> Self {
+        let status = match e {
+            ZkpError::ProofRejected(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ZkpError::InvalidInput(_)
+            | ZkpError::InvalidEncoding(_)
+            | ZkpError::MalformedJson(_)
+            | ZkpError::CorruptProof(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        Self { status, message: e.to_string(), code: Some(e.code()), reason: None }
+    }
+}
+
+/// Store I/O and proof verification are blocking; keep them off the async workers.
+async fn blocking<T, F>(work: F) -> Result<T, ApiError>
+where
+    F: FnOnce() -> Result<T, ApiError> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(work)
+        .await
+        .map_err(|e| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, format!("worker failed: {}", e)))?
+}
+
+// 1. START SESSION: website QR me `challenge` token dikhata hai
+pub async fn start_session(State(state): Shared) -> Result<Json<Value>, ApiError> {
+    let (id, session) = blocking(move || {
+        let id = uuid::Uuid::new_v4().to_string();
+        let (token, claims) = state.challenges.issue(&id, store::now())?;
+
+        let session = Session::pending(token, claims.domain, claims.expires_at);
+        state.store.insert(id.clone(), session.clone())?;
+        Ok((id, session))
+    })
+    .await?;
+
+    info!("🆕 Session Created: {}", id);
+    Ok(Json(json!({
+        "session_id": id,
+        "challenge": session.challenge,
+        "domain": session.domain,
+        "expires_in": session.expires_in(),
+    })))
+}
+
+#[derive(Deserialize)]
+pub struct UploadProof {
+    session_id: String,
+    proof_data: String,
+}
+
+// 2. UPLOAD PROOF (Android app): proof ko session ke challenge + domain se match karo
+pub async fn upload_proof(State(state): Shared, Json(upload): Json<UploadProof>) -> Result<Json<Value>, ApiError> {
+    let nullifier = blocking(move || {
+        let id = &upload.session_id;
+        let session = state.store.get(id).ok_or(StoreError::NotFound)?;
+        if session.status != Status::Pending {
+            return Err(StoreError::AlreadySettled.into());
+        }
+
+        let outcome =
+            state.challenges.verify_login(&upload.proof_data, &session.challenge, id, &state.circuits, store::now())?;
+
+        match outcome {
+            LoginOutcome::Accepted { nullifier, .. } => {
+                state.store.complete(id, upload.proof_data, nullifier)?;
+                info!("✅ Proof Verified for: {}", id);
+                Ok(nullifier)
+            }
+            LoginOutcome::Rejected { reason, detail } => {
+                warn!("⛔ Proof rejected for {}: {} ({})", id, reason.as_str(), detail);
+                state.store.fail(id, reason.as_str())?;
+                Err(ApiError {
+                    status: StatusCode::UNPROCESSABLE_ENTITY,
+                    message: detail,
+                    code: None,
+                    reason: Some(reason.as_str()),
+                })
+            }
+        }
+    })
+    .await?;
+
+    Ok(Json(json!({ "success": true, "nullifier": nullifier.to_string() })))
+}
+
+// 3. CHECK STATUS (website polls)
+pub async fn poll_status(State(state): Shared, Path(session_id): Path<String>) -> Result<Json<Value>, ApiError> {
+    let session = state
+        .store
+        .get(&session_id)
+        .ok_or_else(|| ApiError::new(StatusCode::NOT_FOUND, "Session Not Found"))?;
+
+    Ok(Json(json!({
+        "status": session.status,
+        "proof": session.proof,
+        "nullifier": session.nullifier,
+        "reason": session.reason,
+        "expires_in": session.expires_in(),
+    })))
+}
@@ -0,0 +1,173 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// 🗄️ SESSION STORE
+// In-memory map + JSON file on disk (har change ke baad atomic rewrite), taaki
+// relay restart hone par pending logins gum na hon. Expired sessions load par
+// aur periodic sweep me hat jaate hain.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Pending,
+    Completed,
+    /// A rejected upload; `reason` holds the code.
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Session {
+    pub status: Status,
+    /// Signed challenge token shown in the QR (see zkp_mobile::challenge).
+    #[serde(default)]
+    pub challenge: String,
+    #[serde(default)]
+    pub domain: String,
+    /// The uploaded `proof_data`, as the website expects it.
+    pub proof: Option<String>,
+    pub nullifier: Option<String>,
+    /// Reject reason code when `Failed`.
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Unix seconds.
+    pub expires_at: u64,
+}
+
+impl Session {
+    pub fn pending(challenge: String, domain: String, expires_at: u64) -> Self {
+        Self { status: Status::Pending, challenge, domain, proof: None, nullifier: None, reason: None, expires_at }
+    }
+
+    pub fn expires_in(&self) -> u64 {
+        self.expires_at.saturating_sub(now())
+    }
+
+    fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    NotFound,
+    AlreadySettled,
+    Full,
+    Io(io::Error),
+}
+
+impl From<io::Error> for StoreError {
+    fn from(e: io::Error) -> Self {
+        StoreError::Io(e)
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+pub struct SessionStore {
+    path: PathBuf,
+    max_sessions: usize,
+    sessions: Mutex<HashMap<String, Session>>,
+}
+
+impl SessionStore {
+    /// Loads `path` if it exists. A corrupt file is logged and replaced, not fatal.
+    pub fn open(path: PathBuf, max_sessions: usize) -> io::Result<Self> {
+        let mut sessions: HashMap<String, Session> = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                warn!("⚠️ Ignoring unreadable session store {}: {}", path.display(), e);
+                HashMap::new()
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        let now = now();
+        sessions.retain(|_, s| !s.is_expired(now));
+
+        Ok(Self { path, max_sessions, sessions: Mutex::new(sessions) })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Session>> {
+        self.sessions.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
+    /// Write to a temp file, then rename: crash ke beech file half-written nahi hogi.
+    fn persist(&self, sessions: &HashMap<String, Session>) -> io::Result<()> {
+        let json = serde_json::to_vec(sessions)?;
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, &self.path)
+    }
+
+    pub fn insert(&self, id: String, session: Session) -> Result<(), StoreError> {
+        let mut sessions = self.lock();
+        if sessions.len() >= self.max_sessions {
+            return Err(StoreError::Full);
+        }
+        sessions.insert(id, session);
+        self.persist(&sessions)?;
+        Ok(())
+    }
+
+    /// Live (unexpired) session.
+    pub fn get(&self, id: &str) -> Option<Session> {
+        let now = now();
+        self.lock().get(id).filter(|s| !s.is_expired(now)).cloned()
+    }
+
+    /// Pending -> Completed / Failed. Only the first upload decides.
+    fn settle(&self, id: &str, update: impl FnOnce(&mut Session)) -> Result<(), StoreError> {
+        let now = now();
+        let mut sessions = self.lock();
+
+        let session = sessions.get_mut(id).filter(|s| !s.is_expired(now)).ok_or(StoreError::NotFound)?;
+        if session.status != Status::Pending {
+            return Err(StoreError::AlreadySettled);
+        }
+        update(session);
+
+        self.persist(&sessions)?;
+        Ok(())
+    }
+
+    pub fn complete(&self, id: &str, proof: String, nullifier: u64) -> Result<(), StoreError> {
+        self.settle(id, |session| {
+            session.status = Status::Completed;
+            session.proof = Some(proof);
+            session.nullifier = Some(nullifier.to_string());
+        })
+    }
+
+    /// Settles a rejected upload. Website naya session (naya QR) khol ke retry karti hai.
+    pub fn fail(&self, id: &str, reason: &str) -> Result<(), StoreError> {
+        self.settle(id, |session| {
+            session.status = Status::Failed;
+            session.reason = Some(reason.to_string());
+        })
+    }
+
+    /// Drops expired sessions; returns how many.
+    pub fn sweep(&self) -> io::Result<usize> {
+        let now = now();
+        let mut sessions = self.lock();
+        let before = sessions.len();
+        sessions.retain(|_, s| !s.is_expired(now));
+
+        let removed = before - sessions.len();
+        if removed > 0 {
+            self.persist(&sessions)?;
+        }
+        Ok(removed)
+    }
+}